@@ -0,0 +1,51 @@
+use aoc_common::{print_answers, Part, Solution};
+
+type Mass = i64;
+
+fn compute_fuel_simple(mass: Mass) -> Mass {
+    mass / 3 - 2
+}
+
+fn compute_fuel_recursive(mass: Mass) -> Mass {
+    let fuel_mass = compute_fuel_simple(mass);
+    if fuel_mass <= 0 {
+        0
+    } else {
+        fuel_mass + compute_fuel_recursive(fuel_mass)
+    }
+}
+
+fn solve(masses: &[Mass], compute_fuel: fn(Mass) -> Mass) -> Mass {
+    masses.iter().copied().map(compute_fuel).sum()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Mass>;
+    type Answer1 = Mass;
+    type Answer2 = Mass;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content
+            .lines()
+            .map(&str::parse::<Mass>)
+            .map(Result::unwrap)
+            .collect()
+    }
+
+    fn part_1(masses: &Self::Input<'_>) -> Self::Answer1 {
+        solve(masses, compute_fuel_simple)
+    }
+
+    fn part_2(masses: &Self::Input<'_>) -> Self::Answer2 {
+        solve(masses, compute_fuel_recursive)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
+
+#[cfg(test)]
+mod tests {}
@@ -1,6 +1,7 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
-use std::{collections::HashSet, env};
-use std::{fs, ops::Rem};
+use std::collections::HashSet;
+use std::ops::Rem;
 
 type Location = (isize, isize);
 type Map = HashSet<Location>;
@@ -45,7 +46,7 @@ fn visible_rank(map: &Map, lhs: &Location, rhs: &Location) -> usize {
     (1..)
         .map(|i| (lhs.0 + i * increment.0, lhs.1 + i * increment.1))
         .take_while(|location| location != rhs)
-        .filter(|location| map.contains(&location))
+        .filter(|location| map.contains(location))
         .count()
 }
 
@@ -73,15 +74,12 @@ fn count_visible(map: &Map, station: &Location) -> usize {
         .count()
 }
 
-fn solve_part_1(content: &str) {
-    let map = parse_map(content);
-    let visible_count = map
+fn solve_part_1(map: &Map) -> usize {
+    map
         .iter()
-        .map(|location| count_visible(&map, location))
+        .map(|location| count_visible(map, location))
         .max()
-        .unwrap();
-
-    println!("Part 1: {}", visible_count)
+        .unwrap()
 }
 
 fn angle(from: &Location, to: &Location) -> f64 {
@@ -89,11 +87,10 @@ fn angle(from: &Location, to: &Location) -> f64 {
         - ((to.1 - from.1) as f64).atan2((to.0 - from.0) as f64)
 }
 
-fn solve_part_2(content: &str) {
-    let map = parse_map(content);
+fn solve_part_2(map: &Map) -> isize {
     let station = map
         .iter()
-        .max_by_key(|location| count_visible(&map, location))
+        .max_by_key(|location| count_visible(map, location))
         .unwrap();
 
     let a = map
@@ -101,7 +98,7 @@ fn solve_part_2(content: &str) {
         .filter(|asteroid| station != *asteroid)
         .map(|asteroid| {
             (
-                visible_rank(&map, station, asteroid),
+                visible_rank(map, station, asteroid),
                 (angle(station, asteroid) * 1000000000f64) as i64,
                 asteroid,
             )
@@ -111,17 +108,31 @@ fn solve_part_2(content: &str) {
         .collect_vec();
 
     let asteroid_200 = a[199];
-    println!("Part 2: {}", asteroid_200.1 * 100 + asteroid_200.0)
+    asteroid_200.1 * 100 + asteroid_200.0
 }
 
-fn main() {
-    let args = env::args().collect_vec();
-    let filename = args.get(1).map(|s| s.as_ref()).unwrap_or("./res/input.txt");
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Map;
+    type Answer1 = usize;
+    type Answer2 = isize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_map(content)
+    }
 
-    let content = fs::read_to_string(filename).unwrap();
+    fn part_1(map: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(map)
+    }
+
+    fn part_2(map: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(map)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -131,7 +142,7 @@ mod tests {
     fn angle_test() {
         let a = |v: Location, e: f64| {
             println!("{:?} {:?}", v, angle(&(0, 0), &v));
-            assert!((angle(&(0, 0), &v) - e).abs() < std::f64::EPSILON);
+            assert!((angle(&(0, 0), &v) - e).abs() < f64::EPSILON);
         };
 
         a((-1, 0), 0f64);
@@ -0,0 +1,125 @@
+use crate::robot::{Position, Positions, Robot};
+use std::path::Path;
+
+type Color = [u8; 3];
+
+const UNPAINTED: Color = [40, 40, 40];
+const BLACK: Color = [0, 0, 0];
+const WHITE: Color = [255, 255, 255];
+const ROBOT: Color = [220, 40, 40];
+const AHEAD: Color = [255, 160, 0];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    Pbm,
+    Pgm,
+    Ppm,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()? {
+            "pbm" => Some(Format::Pbm),
+            "pgm" => Some(Format::Pgm),
+            "ppm" => Some(Format::Ppm),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Bounds {
+    pub min: Position,
+    pub max: Position,
+}
+
+impl Bounds {
+    pub fn of(positions: &Positions) -> Bounds {
+        let rows = positions.iter().map(|p| p.0);
+        let cols = positions.iter().map(|p| p.1);
+        Bounds {
+            min: (
+                rows.clone().min().unwrap_or(0),
+                cols.clone().min().unwrap_or(0),
+            ),
+            max: (rows.max().unwrap_or(0), cols.max().unwrap_or(0)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.1 - self.min.1 + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.0 - self.min.0 + 1) as usize
+    }
+}
+
+fn color(robot: &Robot, position: Position, show_robot: bool) -> Color {
+    if show_robot && position == robot.position {
+        ROBOT
+    } else if show_robot && position == robot.ahead() {
+        AHEAD
+    } else if robot.white.contains(&position) {
+        WHITE
+    } else if robot.painted.contains(&position) {
+        BLACK
+    } else {
+        UNPAINTED
+    }
+}
+
+fn luminance(color: Color) -> u8 {
+    let [r, g, b] = color.map(u32::from);
+    ((r * 299 + g * 587 + b * 114) / 1000) as u8
+}
+
+pub fn render(
+    robot: &Robot,
+    bounds: Bounds,
+    format: Format,
+    scale: usize,
+    show_robot: bool,
+) -> Vec<u8> {
+    let (width, height) = (bounds.width() * scale, bounds.height() * scale);
+    let magic = match format {
+        Format::Pbm => "P4",
+        Format::Pgm => "P5",
+        Format::Ppm => "P6",
+    };
+    let mut image = format!("{}\n{} {}\n", magic, width, height).into_bytes();
+    if format != Format::Pbm {
+        image.extend(b"255\n");
+    }
+
+    for row in 0..height {
+        let position_row = bounds.min.0 + (row / scale) as isize;
+        let colors = (0..width)
+            .map(|col| {
+                let position_col = bounds.min.1 + (col / scale) as isize;
+                color(robot, (position_row, position_col), show_robot)
+            })
+            .collect::<Vec<_>>();
+
+        match format {
+            Format::Pbm => {
+                for chunk in colors.chunks(8) {
+                    let byte = chunk.iter().enumerate().fold(
+                        0,
+                        |byte, (bit, color)| {
+                            let black = luminance(*color) < 128;
+                            byte | (black as u8) << (7 - bit)
+                        },
+                    );
+                    image.push(byte);
+                }
+            }
+            Format::Pgm => {
+                image.extend(colors.iter().map(|color| luminance(*color)))
+            }
+            Format::Ppm => image.extend(colors.iter().flatten()),
+        }
+    }
+
+    image
+}
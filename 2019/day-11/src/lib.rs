@@ -0,0 +1,199 @@
+mod image;
+mod robot;
+
+use aoc_common::{print_answers, Part, Solution};
+use image::{render, Bounds, Format};
+use intcode::Program;
+use itertools::Itertools;
+use robot::{Robot, Turn};
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+const FRAME_DELAY: Duration = Duration::from_millis(30);
+
+fn paint(program: &Program, start_on_white: bool) -> Robot {
+    let mut robot = Robot::new(program.clone(), start_on_white);
+    robot.run(|_| {});
+    robot
+}
+
+fn solve_part_1(program: &Program) -> usize {
+    paint(program, false).painted.len()
+}
+
+fn draw(robot: &Robot, bounds: Bounds, show_robot: bool) -> String {
+    (bounds.min.0..=bounds.max.0)
+        .map(|row| {
+            (bounds.min.1..=bounds.max.1)
+                .map(|col| match (row, col) {
+                    position if show_robot && position == robot.position => {
+                        robot.heading()
+                    }
+                    position if robot.white.contains(&position) => '\u{2588}',
+                    _ => ' ',
+                })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+fn paint_registration(program: &Program) -> String {
+    let robot = paint(program, true);
+    draw(&robot, Bounds::of(&robot.white), false)
+}
+
+fn solve_part_2(program: &Program) -> String {
+    let image = paint_registration(program);
+    match ocr::recognize(&image) {
+        Ok(letters) => letters,
+        Err(error) => format!("{}\n{}", image, error),
+    }
+}
+
+fn animate(
+    program: &Program,
+    start_on_white: bool,
+    frames: Option<&String>,
+    scale: usize,
+) {
+    let bounds = Bounds::of(&paint(program, start_on_white).visited);
+    if let Some(directory) = frames {
+        fs::create_dir_all(directory).unwrap();
+    }
+
+    let mut robot = Robot::new(program.clone(), start_on_white);
+    robot.run(|robot| {
+        let instruction = robot.last.unwrap();
+        let brush = if instruction.white { "white" } else { "black" };
+        let turn = match instruction.turn {
+            Turn::Left => "left",
+            Turn::Right => "right",
+        };
+
+        match frames {
+            Some(directory) => {
+                let path = Path::new(directory)
+                    .join(format!("frame-{:05}.ppm", robot.steps));
+                let frame = render(robot, bounds, Format::Ppm, scale, true);
+                fs::write(path, frame).unwrap();
+            }
+            None => {
+                println!(
+                    "\x1b[2J\x1b[H{}\nstep {}: painted {:?} {}, turned {}",
+                    draw(robot, bounds, true),
+                    robot.steps,
+                    instruction.position,
+                    brush,
+                    turn
+                );
+                thread::sleep(FRAME_DELAY);
+            }
+        }
+    });
+
+    println!(
+        "{} steps, {} panels painted",
+        robot.steps,
+        robot.painted.len()
+    );
+}
+
+fn export(
+    program: &Program,
+    start_on_white: bool,
+    filename: &str,
+    scale: usize,
+) {
+    let format = Format::from_path(Path::new(filename))
+        .expect("Export file must end in .pbm, .pgm or .ppm");
+
+    let robot = paint(program, start_on_white);
+    let bounds = Bounds::of(&robot.painted);
+    fs::write(filename, render(&robot, bounds, format, scale, false)).unwrap();
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Program;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.parse().unwrap()
+    }
+
+    fn part_1(program: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(program)
+    }
+
+    fn part_2(program: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(program)
+    }
+}
+
+pub fn run(part: Part, input: &str, args: &[String]) {
+    let flag_value = |flag: &str| {
+        args.iter()
+            .position(|arg| arg == flag)
+            .and_then(|index| args.get(index + 1))
+    };
+
+    let start_on_white = part != Part::One;
+    let scale = flag_value("--scale")
+        .map_or(1, |scale| scale.parse().expect("Invalid scale"));
+    let frames = flag_value("--frames");
+
+    if let Some(export_filename) = flag_value("--export") {
+        export(&Puzzle::parse(input), start_on_white, export_filename, scale);
+        return;
+    }
+
+    if frames.is_some() || args.iter().any(|arg| arg == "--animate") {
+        animate(&Puzzle::parse(input), start_on_white, frames, scale);
+        return;
+    }
+
+    print_answers::<Puzzle>(input, part);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_2() {
+        let content = include_str!("../res/input.txt");
+        let image = paint_registration(&Puzzle::parse(content));
+        assert_eq!(ocr::recognize(&image), Ok("BLULZJLZ".to_string()));
+    }
+
+    #[test]
+    fn test_export() {
+        let content = include_str!("../res/input.txt");
+        let robot = paint(&Puzzle::parse(content), true);
+        let bounds = Bounds::of(&robot.painted);
+
+        let ppm = render(&robot, bounds, Format::Ppm, 3, false);
+        let header = format!(
+            "P6\n{} {}\n255\n",
+            bounds.width() * 3,
+            bounds.height() * 3
+        );
+        assert!(ppm.starts_with(header.as_bytes()));
+        assert_eq!(
+            ppm.len(),
+            header.len() + bounds.width() * bounds.height() * 27
+        );
+
+        let pbm = render(&robot, bounds, Format::Pbm, 1, false);
+        let row_bytes = bounds.width().div_ceil(8);
+        assert_eq!(
+            pbm.len(),
+            format!("P4\n{} {}\n", bounds.width(), bounds.height()).len()
+                + row_bytes * bounds.height()
+        );
+    }
+}
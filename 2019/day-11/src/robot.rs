@@ -0,0 +1,106 @@
+use intcode::{run_outputs, Int, Program};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+pub type Position = (isize, isize);
+pub type Positions = HashSet<Position>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Instruction {
+    pub position: Position,
+    pub white: bool,
+    pub turn: Turn,
+}
+
+pub struct Robot {
+    program: Program,
+    pub position: Position,
+    pub direction: Position,
+    pub white: Positions,
+    pub painted: Positions,
+    pub visited: Positions,
+    pub steps: usize,
+    pub last: Option<Instruction>,
+}
+
+impl Robot {
+    pub fn new(program: Program, start_on_white: bool) -> Robot {
+        let position = (0, 0);
+        let mut white = HashSet::new();
+        if start_on_white {
+            white.insert(position);
+        }
+
+        Robot {
+            program,
+            position,
+            direction: (-1, 0),
+            white,
+            painted: HashSet::new(),
+            visited: Some(position).into_iter().collect(),
+            steps: 0,
+            last: None,
+        }
+    }
+
+    pub fn ahead(&self) -> Position {
+        (
+            self.position.0 + self.direction.0,
+            self.position.1 + self.direction.1,
+        )
+    }
+
+    pub fn heading(&self) -> char {
+        match self.direction {
+            (-1, 0) => '^',
+            (1, 0) => 'v',
+            (0, -1) => '<',
+            _ => '>',
+        }
+    }
+
+    pub fn step(&mut self) -> Option<Instruction> {
+        let position = self.position;
+        self.program.input(self.white.contains(&position) as Int);
+
+        run_outputs(&mut self.program);
+        let (color, turn) = self.program.outputs.drain(..).collect_tuple()?;
+
+        let white = color != 0;
+        self.painted.insert(position);
+        if white {
+            self.white.insert(position);
+        } else {
+            self.white.remove(&position);
+        }
+
+        let turn = if turn == 0 { Turn::Left } else { Turn::Right };
+        self.direction = match turn {
+            Turn::Left => (-self.direction.1, self.direction.0),
+            Turn::Right => (self.direction.1, -self.direction.0),
+        };
+        self.position = self.ahead();
+        self.visited.insert(self.position);
+        self.steps += 1;
+
+        let instruction = Instruction {
+            position,
+            white,
+            turn,
+        };
+        self.last = Some(instruction);
+        Some(instruction)
+    }
+
+    pub fn run(&mut self, mut on_step: impl FnMut(&Robot)) {
+        while self.step().is_some() {
+            on_step(self);
+        }
+    }
+}
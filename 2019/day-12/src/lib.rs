@@ -1,14 +1,15 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::{FoldWhile, Itertools};
 use regex::Regex;
 use std::{
     collections::HashSet,
-    env,
     iter::FromIterator,
     ops::{Add, Div, Mul, Rem},
 };
-use std::{fs, iter::Sum};
+use std::iter::Sum;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
+#[derive(Default)]
 struct Vector(isize, isize, isize);
 
 struct VectorIntoIterator {
@@ -22,7 +23,7 @@ struct VectorIterator<'a> {
 }
 
 impl Vector {
-    fn iter(&self) -> VectorIterator {
+    fn iter(&self) -> VectorIterator<'_> {
         VectorIterator {
             vector: self,
             index: 0,
@@ -34,11 +35,6 @@ impl Vector {
     }
 }
 
-impl Default for Vector {
-    fn default() -> Self {
-        Vector(0, 0, 0)
-    }
-}
 
 impl Add for Vector {
     type Output = Vector;
@@ -58,7 +54,7 @@ impl Add for &Vector {
 
 impl Sum for Vector {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.fold1(|lhs, rhs| lhs.add(rhs)).unwrap_or_default()
+        iter.reduce(|lhs, rhs| lhs.add(rhs)).unwrap_or_default()
     }
 }
 
@@ -115,8 +111,8 @@ impl Iterator for VectorIntoIterator {
     }
 }
 
-#[derive(Debug)]
-struct Planet {
+#[derive(Clone, Debug)]
+pub struct Planet {
     position: Vector,
     velocity: Vector,
 }
@@ -172,12 +168,10 @@ fn step(planets: Vec<Planet>) -> Vec<Planet> {
         .collect_vec()
 }
 
-fn solve_part_1(content: &str) {
-    let start_planets = parse_planets(content);
+fn solve_part_1(start_planets: &[Planet]) -> isize {
     let final_planets =
-        (0..1000).fold(start_planets, |planets, _| step(planets));
-    let energy: isize = final_planets.iter().map(Planet::energy).sum();
-    println!("Part 1: {}", energy);
+        (0..1000).fold(start_planets.to_vec(), |planets, _| step(planets));
+    final_planets.iter().map(Planet::energy).sum()
 }
 
 fn gcd<T>(lhs: T, rhs: T) -> T
@@ -218,11 +212,9 @@ where
     }
 }
 
-fn solve_part_2(content: &str) {
-    let start_planets = parse_planets(content);
-
+fn solve_part_2(start_planets: &[Planet]) -> usize {
     let mut sets = vec![HashSet::new(); 3];
-    iterate(start_planets, |planets| {
+    iterate(start_planets.to_vec(), |planets| {
         let planets = step(planets);
 
         let get_parts = |f: fn(&Vector) -> isize| {
@@ -250,16 +242,29 @@ fn solve_part_2(content: &str) {
         }
     });
 
-    let cycle_len = sets.into_iter().map(|s| s.len()).fold1(lcm).unwrap();
-    println!("Part 2: {}", cycle_len)
+    sets.into_iter().map(|s| s.len()).reduce(lcm).unwrap()
 }
 
-fn main() {
-    let args = env::args().collect_vec();
-    let filename = args.get(1).map(|s| s.as_ref()).unwrap_or("./res/input.txt");
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Planet>;
+    type Answer1 = isize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_planets(content)
+    }
+
+    fn part_1(planets: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(planets)
+    }
 
-    let content = fs::read_to_string(filename).unwrap();
+    fn part_2(planets: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(planets)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
@@ -0,0 +1,175 @@
+use intcode::{run_outputs, Int, Program, ProgramState};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Object {
+    Empty,
+    Wall,
+    Block,
+    Paddle,
+    Ball,
+}
+
+impl From<Int> for Object {
+    fn from(i: Int) -> Self {
+        match i {
+            0 => Object::Empty,
+            1 => Object::Wall,
+            2 => Object::Block,
+            3 => Object::Paddle,
+            4 => Object::Ball,
+            _ => panic!(),
+        }
+    }
+}
+
+impl From<Object> for char {
+    fn from(object: Object) -> Self {
+        match object {
+            Object::Empty => ' ',
+            Object::Wall => '#',
+            Object::Block => '=',
+            Object::Paddle => '-',
+            Object::Ball => 'o',
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Screen {
+    pub tiles: HashMap<(Int, Int), Object>,
+    pub score: Int,
+}
+
+impl Screen {
+    pub fn update(&mut self, outputs: impl Iterator<Item = Int>) {
+        for (col, row, id) in outputs.tuples() {
+            if col == -1 && row == 0 {
+                self.score = id;
+            } else {
+                self.tiles.insert((col, row), id.into());
+            }
+        }
+    }
+
+    pub fn count(&self, object: Object) -> usize {
+        self.tiles.values().filter(|tile| **tile == object).count()
+    }
+
+    pub fn find(&self, object: Object) -> Option<(Int, Int)> {
+        self.tiles
+            .iter()
+            .find(|(_, tile)| **tile == object)
+            .map(|(position, _)| *position)
+    }
+}
+
+impl Display for Screen {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let max_col = self.tiles.keys().map(|(col, _)| *col).max();
+        let max_row = self.tiles.keys().map(|(_, row)| *row).max();
+
+        if let (Some(max_col), Some(max_row)) = (max_col, max_row) {
+            for row in 0..=max_row {
+                let line = (0..=max_col)
+                    .map(|col| self.tiles.get(&(col, row)))
+                    .map(|tile| char::from(*tile.unwrap_or(&Object::Empty)))
+                    .collect::<String>();
+                writeln!(f, "{}", line)?;
+            }
+        }
+
+        write!(f, "Score: {}", self.score)
+    }
+}
+
+pub trait Controller {
+    fn joystick(&mut self, screen: &Screen) -> Option<Int>;
+}
+
+pub struct Autopilot;
+
+impl Controller for Autopilot {
+    fn joystick(&mut self, screen: &Screen) -> Option<Int> {
+        let ball = screen.find(Object::Ball)?;
+        let paddle = screen.find(Object::Paddle)?;
+        Some((ball.0 - paddle.0).signum())
+    }
+}
+
+pub struct Replay<I>(pub I);
+
+impl<I: Iterator<Item = Int>> Controller for Replay<I> {
+    fn joystick(&mut self, _screen: &Screen) -> Option<Int> {
+        self.0.next()
+    }
+}
+
+pub struct Cabinet {
+    pub program: Program,
+    pub screen: Screen,
+    pub recording: Vec<Int>,
+}
+
+impl Cabinet {
+    pub fn new(mut program: Program, free_play: bool) -> Cabinet {
+        if free_play {
+            program.memory[0] = 2;
+        }
+
+        Cabinet {
+            program,
+            screen: Screen::default(),
+            recording: vec![],
+        }
+    }
+
+    pub fn frame(&mut self) -> ProgramState {
+        let state = run_outputs(&mut self.program);
+        self.screen.update(self.program.outputs.drain(..));
+        state
+    }
+
+    pub fn tilt(&mut self, joystick: Int) {
+        self.recording.push(joystick);
+        self.program.input(joystick);
+    }
+
+    pub fn play<C: Controller>(
+        &mut self,
+        controller: &mut C,
+        mut on_frame: impl FnMut(&Screen),
+    ) -> Int {
+        while self.frame() == ProgramState::Input {
+            on_frame(&self.screen);
+            match controller.joystick(&self.screen) {
+                Some(joystick) => self.tilt(joystick),
+                None => break,
+            }
+        }
+
+        on_frame(&self.screen);
+        self.screen.score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay() {
+        let content = include_str!("../res/input.txt");
+        let program: Program = content.parse().unwrap();
+
+        let mut cabinet = Cabinet::new(program.clone(), true);
+        let score = cabinet.play(&mut Autopilot, |_| {});
+        assert_eq!(cabinet.screen.count(Object::Block), 0);
+
+        let recording = cabinet.recording.into_iter();
+        let mut replayed = Cabinet::new(program, true);
+        assert_eq!(replayed.play(&mut Replay(recording), |_| {}), score);
+    }
+}
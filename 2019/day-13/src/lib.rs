@@ -0,0 +1,192 @@
+mod arcade;
+
+use aoc_common::{print_answers, Part, Solution};
+use arcade::{Autopilot, Cabinet, Controller, Object, Replay, Screen};
+use intcode::{Int, Program};
+use itertools::Itertools;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+const FRAME_DELAY: Duration = Duration::from_millis(15);
+const KEYS: &str = "[a] left  [s] stay  [d] right  [p] autopilot  [q] quit";
+
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        None
+    }
+}
+
+struct RawMode {
+    saved: Option<String>,
+}
+
+impl RawMode {
+    fn enable() -> RawMode {
+        let saved = stty(&["-g"]).filter(|_| {
+            stty(&["-icanon", "-echo", "min", "0", "time", "0"]).is_some()
+        });
+        RawMode { saved }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.saved.is_some()
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        if let Some(saved) = &self.saved {
+            stty(&[saved]);
+        }
+    }
+}
+
+struct Keyboard {
+    autopilot: bool,
+    raw_mode: RawMode,
+}
+
+impl Keyboard {
+    fn read_key(&self) -> Option<u8> {
+        let mut key = [0];
+        match io::stdin().read(&mut key) {
+            Ok(1) => Some(key[0]),
+            Ok(_) if self.raw_mode.is_enabled() => None,
+            _ => Some(b'q'),
+        }
+    }
+
+    fn show_keys(&self) {
+        let mode = if self.autopilot {
+            "autopilot"
+        } else {
+            "manual"
+        };
+        print!("\r{} ({})  ", KEYS, mode);
+        io::stdout().flush().unwrap();
+    }
+}
+
+impl Controller for Keyboard {
+    fn joystick(&mut self, screen: &Screen) -> Option<Int> {
+        self.show_keys();
+        loop {
+            if self.autopilot && !self.raw_mode.is_enabled() {
+                thread::sleep(FRAME_DELAY);
+                return Autopilot.joystick(screen);
+            }
+
+            match self.read_key() {
+                Some(b'q') => return None,
+                Some(b'p') => {
+                    self.autopilot = !self.autopilot;
+                    self.show_keys();
+                }
+                Some(b'a') | Some(b'h') if !self.autopilot => return Some(-1),
+                Some(b's') | Some(b'j') | Some(b' ') if !self.autopilot => {
+                    return Some(0)
+                }
+                Some(b'd') | Some(b'l') if !self.autopilot => return Some(1),
+                Some(_) => {}
+                None if self.autopilot => {
+                    thread::sleep(FRAME_DELAY);
+                    return Autopilot.joystick(screen);
+                }
+                None => thread::sleep(FRAME_DELAY),
+            }
+        }
+    }
+}
+
+fn render(screen: &Screen) {
+    println!("\x1b[2J\x1b[H{}", screen);
+}
+
+fn play(content: &str, args: &[String]) {
+    let flag_value = |flag: &str| {
+        args.iter()
+            .position(|arg| arg == flag)
+            .and_then(|index| args.get(index + 1))
+    };
+
+    let program: Program = content.parse().unwrap();
+    let mut cabinet = Cabinet::new(program, true);
+
+    let score = if let Some(filename) = flag_value("--replay") {
+        let recording = fs::read_to_string(filename).unwrap();
+        let joysticks = recording
+            .trim()
+            .split(',')
+            .filter(|joystick| !joystick.is_empty())
+            .map(|joystick| joystick.parse().unwrap())
+            .collect_vec();
+        cabinet.play(&mut Replay(joysticks.into_iter()), render)
+    } else {
+        let mut keyboard = Keyboard {
+            autopilot: args.iter().any(|arg| arg == "--autopilot"),
+            raw_mode: RawMode::enable(),
+        };
+        cabinet.play(&mut keyboard, render)
+    };
+
+    let blocks = cabinet.screen.count(Object::Block);
+    println!("Final score: {} ({} blocks left)", score, blocks);
+
+    if let Some(filename) = flag_value("--record") {
+        fs::write(filename, cabinet.recording.iter().join(",")).unwrap();
+    }
+}
+
+fn solve_part_1(program: &Program) -> usize {
+    let mut cabinet = Cabinet::new(program.clone(), false);
+    cabinet.frame();
+
+    cabinet.screen.count(Object::Block)
+}
+
+fn solve_part_2(program: &Program) -> Int {
+    let mut cabinet = Cabinet::new(program.clone(), true);
+    cabinet.play(&mut Autopilot, |_| {})
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Program;
+    type Answer1 = usize;
+    type Answer2 = Int;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.parse().unwrap()
+    }
+
+    fn part_1(program: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(program)
+    }
+
+    fn part_2(program: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(program)
+    }
+}
+
+pub fn run(part: Part, input: &str, args: &[String]) {
+    let modes = ["--play", "--autopilot", "--replay"];
+    if args.iter().any(|arg| modes.contains(&arg.as_str())) {
+        play(input, args);
+        return;
+    }
+
+    print_answers::<Puzzle>(input, part);
+}
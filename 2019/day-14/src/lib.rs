@@ -1,19 +1,17 @@
-#![feature(str_split_once)]
-
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
-use std::{collections::HashMap, fs};
-use std::{env, str::FromStr};
+use std::collections::HashMap;
 
 type Chemical<'a> = &'a str;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct RecipePart<'a> {
     quantity: usize,
     chemical: Chemical<'a>,
 }
 
-#[derive(Debug)]
-struct Recipe<'a> {
+#[derive(Clone, Debug)]
+pub struct Recipe<'a> {
     inputs: Vec<RecipePart<'a>>,
     output: RecipePart<'a>,
 }
@@ -22,7 +20,7 @@ type Book<'a> = HashMap<Chemical<'a>, Recipe<'a>>;
 
 type QuantityMap<'a> = HashMap<Chemical<'a>, usize>;
 
-fn parse_recipe_part(s: &str) -> RecipePart {
+fn parse_recipe_part(s: &str) -> RecipePart<'_> {
     let (quantity_str, chemical) = s.trim().split_once(" ").unwrap();
     let quantity = quantity_str.parse().unwrap();
     RecipePart { quantity, chemical }
@@ -60,7 +58,7 @@ fn produce_to_storage<'a>(
 
     let quantity_delta = quantity_to_have - quantity_stored;
 
-    let count_reaction = |p, q| (p + q - 1) / q;
+    let count_reaction = |p: usize, q: usize| p.div_ceil(q);
     let number_reaction =
         count_reaction(quantity_delta, recipe.output.quantity);
 
@@ -101,7 +99,7 @@ fn produce_to_storage<'a>(
     true
 }
 
-fn parse_recipe_book(content: &str) -> Book {
+fn parse_recipe_book(content: &str) -> Book<'_> {
     content
         .lines()
         .map(|line| {
@@ -119,8 +117,8 @@ fn parse_recipe_book(content: &str) -> Book {
         .collect()
 }
 
-fn solve_part_1(content: &str) {
-    let mut book = parse_recipe_book(content);
+fn solve_part_1(book: &Book) -> usize {
+    let mut book = book.clone();
 
     book.insert(
         "ORE",
@@ -138,12 +136,10 @@ fn solve_part_1(content: &str) {
 
     check_storage(&book, &mut produced, &mut storage, 1, "FUEL");
 
-    println!("Part 1: {}", produced["ORE"]);
+    produced["ORE"]
 }
 
-fn solve_part_2(content: &str) {
-    let book = parse_recipe_book(content);
-
+fn solve_part_2(book: &Book) -> usize {
     let mut min = 0;
     let mut max = 1000000000000;
 
@@ -158,7 +154,7 @@ fn solve_part_2(content: &str) {
         }
 
         let produced =
-            produce_to_storage(&book, &mut produced, &mut storage, mid, "FUEL");
+            produce_to_storage(book, &mut produced, &mut storage, mid, "FUEL");
         if produced {
             min = mid;
         } else {
@@ -166,15 +162,29 @@ fn solve_part_2(content: &str) {
         }
     }
 
-    println!("Part 2: {}", min);
+    min
 }
 
-fn main() {
-    let args = env::args().collect_vec();
-    let filename = args.get(1).map(|s| s.as_ref()).unwrap_or("./res/input.txt");
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Book<'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_recipe_book(content)
+    }
 
-    let content = fs::read_to_string(filename).unwrap();
+    fn part_1(book: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(book)
+    }
+
+    fn part_2(book: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(book)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
@@ -0,0 +1,385 @@
+use intcode::{try_run, Int, Program, ProgramState};
+use itertools::Itertools;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::ops::Add;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Position {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Position {
+    pub const fn new(row: isize, col: isize) -> Position {
+        Position { row, col }
+    }
+
+    pub fn neighbors(self) -> impl Iterator<Item = (Direction, Position)> {
+        Direction::ALL
+            .iter()
+            .map(move |direction| (*direction, self + *direction))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    North,
+    South,
+    West,
+    East,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ];
+
+    pub fn command(self) -> Int {
+        match self {
+            Direction::North => 1,
+            Direction::South => 2,
+            Direction::West => 3,
+            Direction::East => 4,
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+            Direction::East => Direction::West,
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Direction::North => "north",
+            Direction::South => "south",
+            Direction::West => "west",
+            Direction::East => "east",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Add<Direction> for Position {
+    type Output = Position;
+
+    fn add(self, direction: Direction) -> Self::Output {
+        match direction {
+            Direction::North => Position::new(self.row - 1, self.col),
+            Direction::South => Position::new(self.row + 1, self.col),
+            Direction::West => Position::new(self.row, self.col - 1),
+            Direction::East => Position::new(self.row, self.col + 1),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Tile {
+    Wall,
+    Empty,
+    Oxygen,
+}
+
+impl From<Int> for Tile {
+    fn from(status: Int) -> Self {
+        match status {
+            0 => Tile::Wall,
+            1 => Tile::Empty,
+            2 => Tile::Oxygen,
+            _ => panic!("Unknown droid status {}", status),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Grid {
+    pub tiles: HashMap<Position, Tile>,
+    pub robot: Position,
+}
+
+impl Grid {
+    pub fn new() -> Grid {
+        let mut grid = Grid::default();
+        grid.tiles.insert(Position::default(), Tile::Empty);
+        grid
+    }
+
+    pub fn is_open(&self, position: Position) -> bool {
+        matches!(self.tiles.get(&position), Some(Tile::Empty | Tile::Oxygen))
+    }
+
+    pub fn find(&self, tile: Tile) -> Option<Position> {
+        self.tiles
+            .iter()
+            .find(|(_, t)| **t == tile)
+            .map(|(position, _)| *position)
+    }
+
+    pub fn distances(&self, from: Position) -> HashMap<Position, usize> {
+        let mut distances: HashMap<_, _> =
+            Some((from, 0)).into_iter().collect();
+        let mut queue: VecDeque<_> = Some(from).into_iter().collect();
+
+        while let Some(current) = queue.pop_front() {
+            let distance = distances[&current];
+            for (_, neighbor) in current.neighbors() {
+                if self.is_open(neighbor) && !distances.contains_key(&neighbor)
+                {
+                    distances.insert(neighbor, distance + 1);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        distances
+    }
+
+    pub fn route(
+        &self,
+        from: Position,
+        mut goal: impl FnMut(Position) -> bool,
+    ) -> Option<(Position, Vec<Direction>)> {
+        let mut parents = HashMap::new();
+        parents.insert(from, None);
+        let mut queue: VecDeque<_> = Some(from).into_iter().collect();
+
+        while let Some(current) = queue.pop_front() {
+            if goal(current) {
+                let mut route = vec![];
+                let mut position = current;
+                while let Some(Some(direction)) = parents.get(&position) {
+                    route.push(*direction);
+                    position = position + Direction::opposite(*direction);
+                }
+                route.reverse();
+                return Some((current, route));
+            }
+
+            if current != from && !self.is_open(current) {
+                continue;
+            }
+
+            for (direction, neighbor) in current.neighbors() {
+                if !parents.contains_key(&neighbor)
+                    && self.tiles.get(&neighbor) != Some(&Tile::Wall)
+                {
+                    parents.insert(neighbor, Some(direction));
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        None
+    }
+
+    pub fn spread_oxygen(&mut self, mut on_minute: impl FnMut(&Grid)) -> usize {
+        let mut front = self
+            .tiles
+            .iter()
+            .filter(|(_, tile)| **tile == Tile::Oxygen)
+            .map(|(position, _)| *position)
+            .collect_vec();
+
+        let mut minutes = 0;
+        loop {
+            let next = front
+                .iter()
+                .flat_map(|position| position.neighbors())
+                .map(|(_, neighbor)| neighbor)
+                .filter(|neighbor| {
+                    self.tiles.get(neighbor) == Some(&Tile::Empty)
+                })
+                .unique()
+                .collect_vec();
+
+            if next.is_empty() {
+                return minutes;
+            }
+
+            for position in &next {
+                self.tiles.insert(*position, Tile::Oxygen);
+            }
+            minutes += 1;
+            on_minute(self);
+            front = next;
+        }
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (min_row, max_row) = self
+            .tiles
+            .keys()
+            .map(|p| p.row)
+            .minmax()
+            .into_option()
+            .unwrap_or_default();
+        let (min_col, max_col) = self
+            .tiles
+            .keys()
+            .map(|p| p.col)
+            .minmax()
+            .into_option()
+            .unwrap_or_default();
+
+        for row in min_row..=max_row {
+            let line = (min_col..=max_col)
+                .map(|col| {
+                    let position = Position::new(row, col);
+                    match self.tiles.get(&position) {
+                        Some(_) if position == self.robot => 'X',
+                        Some(_) if position == Position::default() => 'S',
+                        Some(Tile::Wall) => '#',
+                        Some(Tile::Empty) => '.',
+                        Some(Tile::Oxygen) => 'O',
+                        None => ' ',
+                    }
+                })
+                .collect::<String>();
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Grid {
+    type Err = char;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tiles = HashMap::new();
+        let (mut start, mut robot) = (None, None);
+
+        for (row, line) in s.lines().enumerate() {
+            for (col, chr) in line.chars().enumerate() {
+                let position = Position::new(row as isize, col as isize);
+                let tile = match chr {
+                    ' ' => continue,
+                    '#' => Tile::Wall,
+                    '.' => Tile::Empty,
+                    'O' => Tile::Oxygen,
+                    'S' => {
+                        start = Some(position);
+                        Tile::Empty
+                    }
+                    'X' => {
+                        robot = Some(position);
+                        Tile::Empty
+                    }
+                    _ => return Err(chr),
+                };
+                tiles.insert(position, tile);
+            }
+        }
+
+        let origin = start.or(robot).unwrap_or_default();
+        let robot = robot.unwrap_or(origin);
+        let shift = |position: Position| {
+            Position::new(position.row - origin.row, position.col - origin.col)
+        };
+
+        Ok(Grid {
+            tiles: tiles
+                .into_iter()
+                .map(|(position, tile)| (shift(position), tile))
+                .collect(),
+            robot: shift(robot),
+        })
+    }
+}
+
+pub struct Explorer {
+    pub program: Program,
+    pub grid: Grid,
+    pub moves: usize,
+}
+
+impl Explorer {
+    pub fn new(program: Program) -> Explorer {
+        Explorer {
+            program,
+            grid: Grid::new(),
+            moves: 0,
+        }
+    }
+
+    pub fn step(&mut self, direction: Direction) -> Tile {
+        self.program.input(direction.command());
+        match try_run(&mut self.program) {
+            Ok(ProgramState::Output) => {}
+            Ok(state) => panic!("Droid stopped answering ({:?})", state),
+            Err(error) => panic!("Droid crashed: {}", error),
+        }
+
+        let tile = Tile::from(self.program.output().unwrap());
+        let target = self.grid.robot + direction;
+        self.grid.tiles.insert(target, tile);
+        if tile != Tile::Wall {
+            self.grid.robot = target;
+        }
+        self.moves += 1;
+
+        tile
+    }
+
+    pub fn explore(&mut self, mut on_discovery: impl FnMut(&Grid)) {
+        loop {
+            let grid = &self.grid;
+            let unknown = |position| !grid.tiles.contains_key(&position);
+            let route = match grid.route(grid.robot, unknown) {
+                Some((_, route)) => route,
+                None => return,
+            };
+
+            for direction in route {
+                self.step(direction);
+            }
+            on_discovery(&self.grid);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOM: &str = "
+ ##
+#..##
+#.#..#
+#.O.#
+ ###
+";
+
+    #[test]
+    fn test_grid() {
+        let mut grid: Grid = ROOM.trim_start_matches('\n').parse().unwrap();
+        assert_eq!(grid.to_string(), ROOM.trim_start_matches('\n'));
+
+        let oxygen = grid.find(Tile::Oxygen).unwrap();
+        let (_, route) =
+            grid.route(oxygen, |p| p == Position::new(2, 4)).unwrap();
+        assert_eq!(
+            route,
+            vec![Direction::East, Direction::North, Direction::East]
+        );
+        assert_eq!(grid.distances(oxygen).values().max(), Some(&4));
+
+        let mut frames = vec![];
+        let minutes = grid.spread_oxygen(|grid| frames.push(grid.to_string()));
+        assert_eq!(minutes, 4);
+        assert_eq!(frames.len(), 4);
+        assert!(frames[0].contains("#OOO#"));
+    }
+}
@@ -0,0 +1,100 @@
+mod explorer;
+
+use aoc_common::{Part, Solution};
+use explorer::{Explorer, Grid, Position, Tile};
+use intcode::Program;
+use itertools::Itertools;
+use std::fs;
+use std::thread;
+use std::time::Duration;
+
+const EXPLORE_DELAY: Duration = Duration::from_millis(5);
+const OXYGEN_DELAY: Duration = Duration::from_millis(50);
+
+fn render(grid: &Grid, delay: Duration) {
+    println!("\x1b[2J\x1b[H{}", grid);
+    thread::sleep(delay);
+}
+
+fn explore(content: &str, live: bool) -> Grid {
+    let program: Program = content.parse().unwrap();
+    let mut explorer = Explorer::new(program);
+
+    if live {
+        explorer.explore(|grid| render(grid, EXPLORE_DELAY));
+        println!("Explored in {} moves", explorer.moves);
+    } else {
+        explorer.explore(|_| {});
+    }
+
+    explorer.grid
+}
+
+fn print_route(grid: &Grid) {
+    let oxygen = grid.find(Tile::Oxygen).unwrap();
+    let (_, route) = grid.route(Position::default(), |p| p == oxygen).unwrap();
+
+    println!("Route ({} moves):", route.len());
+    for (count, direction) in route.iter().dedup_with_count() {
+        println!("{} {}", direction, count);
+    }
+}
+
+fn solve_part_1(grid: &Grid) -> usize {
+    let oxygen = grid.find(Tile::Oxygen).unwrap();
+    grid.distances(Position::default())[&oxygen]
+}
+
+fn solve_part_2(grid: &Grid, animate: bool) -> usize {
+    let mut grid = grid.clone();
+    if animate {
+        grid.spread_oxygen(|grid| render(grid, OXYGEN_DELAY))
+    } else {
+        grid.spread_oxygen(|_| {})
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        explore(content, false)
+    }
+
+    fn part_1(grid: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(grid)
+    }
+
+    fn part_2(grid: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(grid, false)
+    }
+}
+
+pub fn run(part: Part, input: &str, args: &[String]) {
+    let has_flag = |flag: &str| args.iter().any(|arg| arg == flag);
+    let export_filename = args
+        .iter()
+        .position(|arg| arg == "--export")
+        .and_then(|index| args.get(index + 1));
+
+    let grid = explore(input, has_flag("--live"));
+
+    if let Some(export_filename) = export_filename {
+        fs::write(export_filename, grid.to_string()).unwrap();
+    }
+
+    if has_flag("--route") {
+        print_route(&grid);
+    }
+
+    if part.includes(1) {
+        println!("Part 1: {}", Puzzle::part_1(&grid));
+    }
+    if part.includes(2) {
+        println!("Part 2: {}", solve_part_2(&grid, has_flag("--oxygen")));
+    }
+}
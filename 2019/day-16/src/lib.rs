@@ -1,9 +1,5 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
-use std::{
-    env,
-    iter::{once, successors},
-};
-use std::{fs, iter::Map};
 
 type Digit = i8;
 type Number = Vec<Digit>;
@@ -152,29 +148,27 @@ fn step_phase(number: NumberSlice) -> Number {
         .collect_vec()
 }
 
-fn solve_part_1(content: &str) {
-    let mut number = parse_number(content);
+fn solve_part_1(number: NumberSlice) -> String {
+    let mut number = number.to_vec();
 
     for _ in 0..100 {
         number = step_phase(&number);
     }
 
-    let first_digits = number
+    number
         .into_iter()
         .take(8)
-        .map(|digit| (digit as u8))
+        .map(|digit| digit as u8 )
         .map(|digit| digit + b'0')
         .map(|digit| digit as char)
-        .collect::<String>();
-
-    println!("Part 1: {}", first_digits)
+        .collect::<String>()
 }
 
-fn solve_part_2(content: &str) {
-    let mut number = parse_number(content);
+fn solve_part_2(number: NumberSlice) -> String {
     let number_len = number.len();
-    number = number
-        .into_iter()
+    let mut number = number
+        .iter()
+        .copied()
         .cycle()
         .take(10000 * number_len)
         .collect_vec();
@@ -206,25 +200,37 @@ fn solve_part_2(content: &str) {
             .collect_vec();
     }
 
-    let first_digits = number
+    number
         .into_iter()
         .take(8)
-        .map(|digit| (digit as u8))
+        .map(|digit| digit as u8 )
         .map(|digit| digit + b'0')
         .map(|digit| digit as char)
-        .collect::<String>();
-
-    println!("Part 2: {}", first_digits)
+        .collect::<String>()
 }
 
-fn main() {
-    let args = env::args().collect_vec();
-    let filename = args.get(1).map(|s| s.as_ref()).unwrap_or("./res/input.txt");
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Number;
+    type Answer1 = String;
+    type Answer2 = String;
 
-    let content = fs::read_to_string(filename).unwrap();
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_number(content)
+    }
+
+    fn part_1(number: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(number)
+    }
+
+    fn part_2(number: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(number)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
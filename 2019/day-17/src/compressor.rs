@@ -0,0 +1,307 @@
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    Left,
+    Right,
+    Forward(usize),
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Left => write!(f, "L"),
+            Instruction::Right => write!(f, "R"),
+            Instruction::Forward(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+pub type Routine = Vec<Instruction>;
+pub type RoutineSlice<'a> = &'a [Instruction];
+
+pub fn routine_to_string(routine: RoutineSlice) -> String {
+    routine.iter().join(",")
+}
+
+pub fn parse_routine(s: &str) -> Option<Routine> {
+    s.trim()
+        .split(',')
+        .map(|word| match word.trim() {
+            "L" => Some(Instruction::Left),
+            "R" => Some(Instruction::Right),
+            n => n.parse().ok().map(Instruction::Forward),
+        })
+        .collect()
+}
+
+pub fn coalesce_routine(routine: Routine) -> Routine {
+    routine
+        .into_iter()
+        .coalesce(|l, r| match (l, r) {
+            (Instruction::Forward(ln), Instruction::Forward(rn)) => {
+                Ok(Instruction::Forward(ln + rn))
+            }
+            (_, _) => Err((l, r)),
+        })
+        .collect_vec()
+}
+
+fn function_name(index: usize) -> char {
+    (b'A' + index as u8) as char
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Compression {
+    pub main: Vec<usize>,
+    pub functions: Vec<Routine>,
+}
+
+impl Compression {
+    pub fn main_string(&self) -> String {
+        self.main
+            .iter()
+            .map(|index| function_name(*index))
+            .join(",")
+    }
+
+    pub fn function_strings(&self) -> Vec<String> {
+        self.functions
+            .iter()
+            .map(|function| routine_to_string(function))
+            .collect()
+    }
+
+    fn rank(&self) -> (usize, usize) {
+        let length = self.function_strings().iter().map(String::len).sum();
+        (self.main.len(), length)
+    }
+}
+
+impl Display for Compression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Main: {}", self.main_string())?;
+        for (index, function) in self.function_strings().iter().enumerate() {
+            write!(f, "\n{}: {}", function_name(index), function)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CompressionError {
+    EmptyRoutine,
+    NoFunctions,
+    InstructionTooLong(Instruction),
+    MainTooLong { calls: usize, limit: usize },
+    Uncovered { covered: usize, total: usize },
+}
+
+impl Display for CompressionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompressionError::EmptyRoutine => write!(f, "the routine is empty"),
+            CompressionError::NoFunctions => {
+                write!(f, "at least one function is needed")
+            }
+            CompressionError::InstructionTooLong(instruction) => write!(
+                f,
+                "instruction {} alone does not fit in a line",
+                instruction
+            ),
+            CompressionError::MainTooLong { calls, limit } => write!(
+                f,
+                "the shortest main routine needs {} calls, {} fit in a line",
+                calls, limit
+            ),
+            CompressionError::Uncovered { covered, total } => write!(
+                f,
+                "the functions cover at most {} of {} instructions",
+                covered, total
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CompressionError {}
+
+pub struct Compressor {
+    pub functions: usize,
+    pub budget: usize,
+}
+
+struct Search<'a> {
+    routine: RoutineSlice<'a>,
+    functions: usize,
+    budget: usize,
+    max_calls: usize,
+    main: Vec<usize>,
+    defined: Vec<RoutineSlice<'a>>,
+    covered: usize,
+    compressions: Vec<Compression>,
+}
+
+impl<'a> Search<'a> {
+    fn fits(&self, function: RoutineSlice) -> bool {
+        routine_to_string(function).len() <= self.budget
+    }
+
+    fn search(&mut self, start: usize) {
+        self.covered = self.covered.max(start);
+        if start == self.routine.len() {
+            self.compressions.push(Compression {
+                main: self.main.clone(),
+                functions: self.defined.iter().map(|f| f.to_vec()).collect(),
+            });
+            return;
+        }
+
+        if self.main.len() == self.max_calls {
+            return;
+        }
+
+        let rest = &self.routine[start..];
+        for index in 0..self.defined.len() {
+            let function = self.defined[index];
+            if rest.starts_with(function) {
+                self.main.push(index);
+                self.search(start + function.len());
+                self.main.pop();
+            }
+        }
+
+        if self.defined.len() == self.functions {
+            return;
+        }
+
+        for end in 1..=rest.len() {
+            let function = &rest[..end];
+            if !self.fits(function) {
+                break;
+            }
+            if self.defined.contains(&function) {
+                continue;
+            }
+
+            self.main.push(self.defined.len());
+            self.defined.push(function);
+            self.search(start + end);
+            self.defined.pop();
+            self.main.pop();
+        }
+    }
+}
+
+impl Compressor {
+    pub fn new(functions: usize, budget: usize) -> Compressor {
+        Compressor { functions, budget }
+    }
+
+    fn search<'a>(
+        &self,
+        routine: RoutineSlice<'a>,
+        max_calls: usize,
+    ) -> Search<'a> {
+        let mut search = Search {
+            routine,
+            functions: self.functions,
+            budget: self.budget,
+            max_calls,
+            main: vec![],
+            defined: vec![],
+            covered: 0,
+            compressions: vec![],
+        };
+        search.search(0);
+        search
+    }
+
+    pub fn compress(
+        &self,
+        routine: RoutineSlice,
+    ) -> Result<Vec<Compression>, CompressionError> {
+        if routine.is_empty() {
+            return Err(CompressionError::EmptyRoutine);
+        }
+        if self.functions == 0 {
+            return Err(CompressionError::NoFunctions);
+        }
+        if let Some(instruction) = routine
+            .iter()
+            .find(|instruction| instruction.to_string().len() > self.budget)
+        {
+            return Err(CompressionError::InstructionTooLong(*instruction));
+        }
+
+        let limit = self.budget.div_ceil(2);
+        let mut compressions = self.search(routine, limit).compressions;
+        if !compressions.is_empty() {
+            compressions.sort_by_key(Compression::rank);
+            return Ok(compressions);
+        }
+
+        let unlimited = self.search(routine, routine.len());
+        match unlimited.compressions.iter().map(|c| c.main.len()).min() {
+            Some(calls) => Err(CompressionError::MainTooLong { calls, limit }),
+            None => Err(CompressionError::Uncovered {
+                covered: unlimited.covered,
+                total: routine.len(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2";
+
+    #[test]
+    fn test_compress() {
+        let routine = parse_routine(EXAMPLE).unwrap();
+        let compressions = Compressor::new(3, 20).compress(&routine).unwrap();
+
+        let expected = Compression {
+            main: vec![0, 1, 2, 1, 0, 2],
+            functions: ["R,8,R,8", "R,4,R,4,R,8", "L,6,L,2"]
+                .iter()
+                .map(|function| parse_routine(function).unwrap())
+                .collect(),
+        };
+        assert!(compressions.contains(&expected));
+        assert_eq!(expected.main_string(), "A,B,C,B,A,C");
+        assert!(compressions
+            .windows(2)
+            .all(|pair| pair[0].rank() <= pair[1].rank()));
+    }
+
+    #[test]
+    fn test_errors() {
+        let routine = parse_routine(EXAMPLE).unwrap();
+        assert_eq!(
+            Compressor::new(3, 20).compress(&[]),
+            Err(CompressionError::EmptyRoutine)
+        );
+        assert_eq!(
+            Compressor::new(3, 1).compress(&parse_routine("R,12").unwrap()),
+            Err(CompressionError::InstructionTooLong(Instruction::Forward(
+                12
+            )))
+        );
+        assert_eq!(
+            Compressor::new(2, 3)
+                .compress(&parse_routine("R,8,R,8,R,8").unwrap()),
+            Err(CompressionError::MainTooLong { calls: 3, limit: 2 })
+        );
+        assert_eq!(
+            Compressor::new(1, 20).compress(&routine),
+            Err(CompressionError::Uncovered {
+                covered: 10,
+                total: routine.len()
+            })
+        );
+    }
+}
@@ -0,0 +1,329 @@
+mod compressor;
+
+use aoc_common::{print_answers, Part, Solution};
+use compressor::{
+    coalesce_routine, parse_routine, routine_to_string, Compressor,
+    Instruction, Routine, RoutineSlice,
+};
+use intcode::{Ascii, Int, Program};
+use itertools::Itertools;
+use std::iter::{once, repeat_with};
+use std::ops::Add;
+
+const FUNCTIONS: usize = 3;
+const LINE_BUDGET: usize = 20;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Tile {
+    Scaffold,
+    Empty,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn left(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    fn right(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::Right => Direction::Down,
+        }
+    }
+}
+
+impl From<u8> for Direction {
+    fn from(byte: u8) -> Self {
+        match byte {
+            b'^' => Direction::Up,
+            b'v' => Direction::Down,
+            b'<' => Direction::Left,
+            b'>' => Direction::Right,
+            _ => panic!(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Position {
+    row: isize,
+    col: isize,
+}
+
+impl Position {
+    fn new(row: isize, col: isize) -> Position {
+        Position { row, col }
+    }
+}
+
+impl From<Direction> for Position {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Position::new(-1, 0),
+            Direction::Down => Position::new(1, 0),
+            Direction::Left => Position::new(0, -1),
+            Direction::Right => Position::new(0, 1),
+        }
+    }
+}
+
+impl Add<Position> for Position {
+    type Output = Position;
+
+    fn add(self, rhs: Position) -> Self::Output {
+        Position::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    tiles: Vec<Vec<Tile>>,
+}
+
+impl Grid {
+    fn height(&self) -> usize {
+        self.tiles.len()
+    }
+
+    fn width(&self) -> usize {
+        self.tiles.first().unwrap().len()
+    }
+
+    fn tiles(&self) -> impl Iterator<Item = (usize, usize, Tile)> + '_ {
+        self.tiles.iter().enumerate().flat_map(|(row, line)| {
+            line.iter()
+                .enumerate()
+                .map(move |(col, tile)| (row, col, *tile))
+        })
+    }
+
+    fn neighbors(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = Tile> + '_ {
+        let irow = row as isize;
+        let icol = col as isize;
+        once((-1, 0))
+            .chain(once((1, 0)))
+            .chain(once((0, -1)))
+            .chain(once((0, 1)))
+            .filter(move |(delta_row, delta_col)| {
+                irow >= -*delta_row && icol >= -*delta_col
+            })
+            .map(move |(delta_row, delta_col)| {
+                ((irow + delta_row), (icol + delta_col))
+            })
+            .filter_map(move |(neighbor_row, neighbor_col)| {
+                self.tiles
+                    .get(neighbor_row as usize)
+                    .and_then(|line| line.get(neighbor_col as usize))
+                    .copied()
+            })
+    }
+
+    fn is_scaffold(&self, position: &Position) -> bool {
+        position.row >= 0
+            && position.col >= 0
+            && position.row < self.height() as isize
+            && position.col < self.width() as isize
+            && self
+                .tiles
+                .get(position.row as usize)
+                .and_then(|line| line.get(position.col as usize))
+                .map(|tile| *tile == Tile::Scaffold)
+                .unwrap_or(false)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Robot {
+    position: Position,
+    direction: Direction,
+}
+
+fn parse_grid(program: &Program) -> (Grid, Robot) {
+    let text = Ascii::from(program.clone()).read_all();
+
+    let mut position = None;
+    let mut direction = None;
+
+    let tiles = text
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(row, line)| {
+            line.bytes()
+                .enumerate()
+                .map(|(col, byte)| match byte {
+                    b'#' => Tile::Scaffold,
+                    b'.' => Tile::Empty,
+                    byte => {
+                        position =
+                            Some(Position::new(row as isize, col as isize));
+                        direction = Some(byte.into());
+                        Tile::Scaffold
+                    }
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+
+    let grid = Grid { tiles };
+
+    let robot = Robot {
+        position: position.unwrap(),
+        direction: direction.unwrap(),
+    };
+
+    (grid, robot)
+}
+
+fn solve_part_1(grid: &Grid) -> usize {
+    grid
+        .tiles()
+        .filter(|(_, _, tile)| *tile == Tile::Scaffold)
+        .filter(|(row, col, _)| {
+            grid.neighbors(*row, *col)
+                .filter(|neighbor| *neighbor == Tile::Scaffold)
+                .count()
+                >= 3
+        })
+        .map(|(row, col, _)| row * col)
+        .sum()
+}
+
+fn find_routine(grid: &Grid, mut robot: Robot) -> Routine {
+    let find_next_instruction = || -> Option<Instruction> {
+        let mut next_instruction = None;
+
+        let possibilities = [
+            (robot.direction, Instruction::Forward(1)),
+            (robot.direction.left(), Instruction::Left),
+            (robot.direction.right(), Instruction::Right),
+        ];
+
+        for &(next_direction, instruction) in &possibilities {
+            let delta: Position = (next_direction).into();
+            let next_position: Position = robot.position + delta;
+            if grid.is_scaffold(&next_position) {
+                if next_direction == robot.direction {
+                    robot.position = next_position;
+                } else {
+                    robot.direction = next_direction;
+                }
+                next_instruction = Some(instruction);
+                break;
+            }
+        }
+
+        next_instruction
+    };
+
+    repeat_with(find_next_instruction)
+        .while_some()
+        .collect_vec()
+}
+
+fn print_compressions(routine: RoutineSlice, compressor: &Compressor) {
+    println!("Routine: {}", routine_to_string(routine));
+    match compressor.compress(routine) {
+        Ok(compressions) => {
+            println!("{} decompositions", compressions.len());
+            for compression in compressions {
+                println!("\n{}", compression);
+            }
+        }
+        Err(error) => println!("No decomposition: {}", error),
+    }
+}
+
+fn solve_part_2(program: &Program, grid: &Grid, robot: &Robot) -> Int {
+    let routine = coalesce_routine(find_routine(grid, robot.clone()));
+    let compression = Compressor::new(FUNCTIONS, LINE_BUDGET)
+        .compress(&routine)
+        .unwrap_or_else(|error| panic!("Cannot compress routine: {}", error))
+        .remove(0);
+
+    let mut program = program.clone();
+    program.memory[0] = 2;
+
+    let mut ascii = Ascii::from(program);
+    ascii.send_line(&compression.main_string());
+    let function_strings = compression.function_strings();
+    for index in 0..FUNCTIONS {
+        ascii.send_line(function_strings.get(index).map_or("", |f| f));
+    }
+    ascii.send_line("n");
+    ascii.read_all();
+
+    *ascii.values.last().unwrap()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (Program, Grid, Robot);
+    type Answer1 = usize;
+    type Answer2 = Int;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        let program: Program = content.parse().unwrap();
+        let (grid, robot) = parse_grid(&program);
+        (program, grid, robot)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(&input.1)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(&input.0, &input.1, &input.2)
+    }
+}
+
+pub fn run(part: Part, input: &str, args: &[String]) {
+    let flag_value = |flag: &str| {
+        args.iter()
+            .position(|arg| arg == flag)
+            .and_then(|index| args.get(index + 1))
+    };
+
+    let functions = flag_value("--functions").map_or(FUNCTIONS, |value| {
+        value.parse().expect("Invalid function count")
+    });
+    let budget = flag_value("--budget").map_or(LINE_BUDGET, |value| {
+        value.parse().expect("Invalid line budget")
+    });
+    let compressor = Compressor::new(functions, budget);
+
+    if let Some(routine) = flag_value("--routine") {
+        let routine = parse_routine(routine).expect("Invalid routine");
+        print_compressions(&routine, &compressor);
+        return;
+    }
+
+    if args.iter().any(|arg| arg == "--list") {
+        let (_, grid, robot) = Puzzle::parse(input);
+        let routine = coalesce_routine(find_routine(&grid, robot));
+        print_compressions(&routine, &compressor);
+        return;
+    }
+
+    print_answers::<Puzzle>(input, part);
+}
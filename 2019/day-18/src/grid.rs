@@ -41,6 +41,7 @@ impl From<char> for Tile {
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl Into<char> for &Tile {
     fn into(self) -> char {
         match self {
@@ -78,7 +79,7 @@ impl Position {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Grid {
     pub starts: Vec<Position>,
     pub keys: Vec<Position>,
@@ -101,7 +102,7 @@ impl Grid {
     pub fn neighbors<'a>(
         &'a self,
         pos: &'a Position,
-    ) -> impl Iterator<Item = (Position, &Tile)> + 'a {
+    ) -> impl Iterator<Item = (Position, &'a Tile)> + 'a {
         pos.neighbors().filter_map(move |n| {
             self.tiles
                 .get(n.row)
@@ -112,7 +113,7 @@ impl Grid {
 
     pub fn split_four(mut self) -> Grid {
         let start = self.starts.first().unwrap();
-            self.tiles[start.row][start.col] = Tile::Wall;
+        self.tiles[start.row][start.col] = Tile::Wall;
         for neighbor in start.neighbors() {
             self.tiles[neighbor.row][neighbor.col] = Tile::Wall;
         }
@@ -123,7 +124,7 @@ impl Grid {
             Position::new(start.row + 1, start.col + 1),
         ];
         let chrs = vec!['@', '$', '%', '&'];
-        for (new_start, start_chr) in new_starts.iter().zip(chrs.into_iter()) {
+        for (new_start, start_chr) in new_starts.iter().zip(chrs) {
             self.tiles[new_start.row][new_start.col] = Tile::Start(start_chr);
         }
         Grid {
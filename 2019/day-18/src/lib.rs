@@ -1,15 +1,13 @@
 mod grid;
 
+use aoc_common::{print_answers, Part, Solution};
 use grid::{Grid, Position, Tile};
-use itertools::Itertools;
 use std::{
     cmp::Ordering,
     collections::{BTreeSet, BinaryHeap, HashSet},
-    fs,
 };
 use std::{
     collections::{HashMap, VecDeque},
-    env,
     iter::once,
 };
 
@@ -48,7 +46,7 @@ fn compute_key_distances(grid: &Grid, start_pos: &Position) -> KeyDistances {
 fn compute_graph(grid: &Grid) -> Graph {
     let mut graph = Graph::new();
 
-    let start_distances = compute_key_distances(grid, &grid.start());
+    let start_distances = compute_key_distances(grid, grid.start());
     graph.insert('@', start_distances);
 
     for (pos, tile) in grid.iter_tiles() {
@@ -134,7 +132,7 @@ fn iter(grid: &Grid, graph: &Graph, starts: Vec<char>) -> Option<usize> {
 
                 let distances_entry = distances
                     .entry((n_robots.clone(), n_keys.clone()))
-                    .or_insert(usize::max_value());
+                    .or_insert(usize::MAX);
 
                 if n_distance < *distances_entry {
                     *distances_entry = n_distance;
@@ -219,26 +217,37 @@ fn find_next_nodes(graph: &Graph, start: char, keys: &KeySet) -> KeyDistances {
     new_keys.into_iter().map(|k| (k, distances[&k])).collect()
 }
 
-fn solve_part_1(content: &str) {
-    let grid = content.parse::<Grid>().unwrap();
-    let graph = compute_graph(&grid);
-    let distance = iter(&grid, &graph, vec!['@']).unwrap();
-    println!("Part 1: {}", distance);
+fn solve_part_1(grid: &Grid) -> usize {
+    let graph = compute_graph(grid);
+    iter(grid, &graph, vec!['@']).unwrap()
 }
 
-fn solve_part_2(content: &str) {
-    let grid = content.parse::<Grid>().unwrap().split_four();
+fn solve_part_2(grid: &Grid) -> usize {
+    let grid = grid.clone().split_four();
     let graph = compute_graph(&grid);
-    let distance = iter(&grid, &graph, vec!['@', '$', '%', '&']).unwrap();
-    println!("Part 2: {}", distance);
+    iter(&grid, &graph, vec!['@', '$', '%', '&']).unwrap()
 }
 
-fn main() {
-    let args = env::args().collect_vec();
-    let filename = args.get(1).map(|s| s.as_ref()).unwrap_or("./res/input.txt");
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.parse().unwrap()
+    }
+
+    fn part_1(grid: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(grid)
+    }
 
-    let content = fs::read_to_string(filename).unwrap();
+    fn part_2(grid: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(grid)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
@@ -0,0 +1,192 @@
+use intcode::{Engine, Int};
+use std::collections::HashMap;
+
+const EMPTY_ROW_SCAN: Int = 10;
+const MAX_ROW: Int = 100_000;
+
+pub type Position = (Int, Int);
+pub type Edges = Option<(Int, Int)>;
+
+pub struct Beam {
+    engine: Engine,
+    cache: HashMap<Position, bool>,
+    rows: Vec<Edges>,
+    pub queries: usize,
+}
+
+impl Beam {
+    pub fn new(engine: Engine) -> Beam {
+        Beam {
+            engine,
+            cache: HashMap::new(),
+            rows: vec![],
+            queries: 0,
+        }
+    }
+
+    pub fn query(&mut self, position: Position) -> bool {
+        if let Some(pulled) = self.cache.get(&position) {
+            return *pulled;
+        }
+
+        let mut engine = self.engine.clone();
+        engine.input(position.0);
+        engine.input(position.1);
+        engine.run();
+        let pulled = engine.output().unwrap() != 0;
+
+        self.queries += 1;
+        self.cache.insert(position, pulled);
+        pulled
+    }
+
+    fn hint(&self) -> (Int, Int) {
+        self.rows
+            .iter()
+            .rev()
+            .find_map(|edges| *edges)
+            .unwrap_or((0, 0))
+    }
+
+    fn track_row(&mut self, y: Int) -> Edges {
+        let (hint_start, hint_end) = self.hint();
+        let scan_end = hint_end.max(hint_start) + EMPTY_ROW_SCAN * (y + 1);
+
+        let start = (hint_start..=scan_end).find(|x| self.query((*x, y)))?;
+        let mut end = start.max(hint_end);
+        if !self.query((end, y)) {
+            end = start;
+        }
+        while self.query((end + 1, y)) {
+            end += 1;
+        }
+
+        Some((start, end))
+    }
+
+    pub fn edges(&mut self, y: Int) -> Edges {
+        while self.rows.len() as Int <= y {
+            let edges = self.track_row(self.rows.len() as Int);
+            self.rows.push(edges);
+        }
+
+        self.rows[y as usize]
+    }
+
+    pub fn contains(&mut self, position: Position) -> bool {
+        match self.edges(position.1) {
+            Some((start, end)) => start <= position.0 && position.0 <= end,
+            None => false,
+        }
+    }
+
+    pub fn find_rectangle(
+        &mut self,
+        width: Int,
+        height: Int,
+    ) -> Option<Position> {
+        for bottom in height - 1..MAX_ROW {
+            let top = bottom + 1 - height;
+            let (left, _) = match self.edges(bottom) {
+                Some(edges) => edges,
+                None => continue,
+            };
+
+            match self.edges(top) {
+                Some((start, end))
+                    if start <= left && left + width - 1 <= end =>
+                {
+                    return Some((left, top))
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    pub fn find_square(&mut self, size: Int) -> Option<Position> {
+        self.find_rectangle(size, size)
+    }
+
+    fn cell(
+        &mut self,
+        position: Position,
+        fit: Option<(Position, Position)>,
+    ) -> u8 {
+        let inside_fit = fit.is_some_and(|((x, y), (width, height))| {
+            (x..x + width).contains(&position.0)
+                && (y..y + height).contains(&position.1)
+        });
+
+        match (inside_fit, self.contains(position)) {
+            (true, _) => 2,
+            (false, true) => 1,
+            (false, false) => 0,
+        }
+    }
+
+    pub fn render_text(
+        &mut self,
+        width: Int,
+        height: Int,
+        fit: Option<(Position, Position)>,
+    ) -> String {
+        let mut text = String::new();
+        for y in 0..height {
+            for x in 0..width {
+                text.push(match self.cell((x, y), fit) {
+                    2 => 'O',
+                    1 => '#',
+                    _ => '.',
+                });
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    pub fn render_pgm(
+        &mut self,
+        width: Int,
+        height: Int,
+        fit: Option<(Position, Position)>,
+    ) -> String {
+        let mut pgm = format!("P2\n{} {}\n2\n", width, height);
+        for y in 0..height {
+            let row = (0..width)
+                .map(|x| self.cell((x, y), fit).to_string())
+                .collect::<Vec<_>>();
+            pgm.push_str(&row.join(" "));
+            pgm.push('\n');
+        }
+        pgm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use intcode::Program;
+
+    #[test]
+    fn test_edges() {
+        let content = include_str!("../res/input.txt");
+        let program: Program = content.parse().unwrap();
+        let mut beam = Beam::new(Engine::from(program.clone()));
+        let mut scanned = Beam::new(Engine::from(program));
+
+        for y in 0..50 {
+            for x in 0..50 {
+                assert_eq!(beam.contains((x, y)), scanned.query((x, y)));
+            }
+        }
+        assert!(beam.queries < scanned.queries);
+
+        let (x, y) = beam.find_rectangle(8, 4).unwrap();
+        assert!(beam.contains((x, y + 3)) && beam.contains((x + 7, y)));
+        assert!(
+            !beam.contains((x + 7, y - 1)) || !beam.contains((x - 1, y + 4))
+        );
+    }
+}
@@ -0,0 +1,117 @@
+mod beam;
+
+use aoc_common::{print_answers, Part, Solution};
+use beam::Beam;
+use intcode::{Engine, Int, Program};
+use itertools::Itertools;
+use std::fs;
+
+const EXPORT_MARGIN: Int = 10;
+
+fn beam(program: &Program) -> Beam {
+    Beam::new(Engine::from(program.clone()))
+}
+
+fn solve_part_1(program: &Program) -> usize {
+    let mut beam = beam(program);
+
+    let size = 50;
+
+    (0..size)
+        .cartesian_product(0..size)
+        .filter(|pos| beam.query(*pos))
+        .count()
+}
+
+fn solve_part_2(program: &Program) -> Int {
+    let (x, y) = beam(program).find_square(100).unwrap();
+    x * 10000 + y
+}
+
+fn parse_dimensions(value: &str) -> (Int, Int) {
+    let (width, height) = match value.split_once('x') {
+        Some(dimensions) => dimensions,
+        None => (value, value),
+    };
+
+    let parse = |value: &str| value.parse().expect("Invalid dimensions");
+    (parse(width), parse(height))
+}
+
+fn fit(program: &Program, dimensions: (Int, Int), export: Option<&String>) {
+    let mut beam = beam(program);
+    let (width, height) = dimensions;
+
+    let position = beam.find_rectangle(width, height);
+    match position {
+        Some((x, y)) => {
+            println!(
+                "{}x{} fits at {},{}: {}",
+                width,
+                height,
+                x,
+                y,
+                x * 10000 + y
+            )
+        }
+        None => println!("{}x{} does not fit in the beam", width, height),
+    }
+
+    if let Some(filename) = export {
+        let fit = position.map(|position| (position, dimensions));
+        let (image_width, image_height) = match position {
+            Some((x, y)) => {
+                (x + width + EXPORT_MARGIN, y + height + EXPORT_MARGIN)
+            }
+            None => (50, 50),
+        };
+
+        let image = if filename.ends_with(".pgm") {
+            beam.render_pgm(image_width, image_height, fit)
+        } else {
+            beam.render_text(image_width, image_height, fit)
+        };
+        fs::write(filename, image).unwrap();
+    }
+
+    println!("{} beam queries", beam.queries);
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Program;
+    type Answer1 = usize;
+    type Answer2 = Int;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.parse().unwrap()
+    }
+
+    fn part_1(program: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(program)
+    }
+
+    fn part_2(program: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(program)
+    }
+}
+
+pub fn run(part: Part, input: &str, args: &[String]) {
+    let flag_value = |flag: &str| {
+        args.iter()
+            .position(|arg| arg == flag)
+            .and_then(|index| args.get(index + 1))
+    };
+
+    let dimensions = flag_value("--size").or_else(|| flag_value("--rect"));
+    let export = flag_value("--export");
+    if dimensions.is_some() || export.is_some() {
+        let dimensions =
+            dimensions.map_or((100, 100), |value| parse_dimensions(value));
+        fit(&Puzzle::parse(input), dimensions, export);
+        return;
+    }
+
+    print_answers::<Puzzle>(input, part);
+}
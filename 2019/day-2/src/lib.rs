@@ -0,0 +1,43 @@
+use aoc_common::{print_answers, Part, Solution};
+use intcode::{Int, Program};
+
+fn run_with(program: &Program, noun: Int, verb: Int) -> Int {
+    let mut program = program.clone();
+
+    program.memory[1] = noun;
+    program.memory[2] = verb;
+    intcode::run(&mut program);
+
+    program.memory[0]
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Program;
+    type Answer1 = Int;
+    type Answer2 = Int;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.parse().unwrap()
+    }
+
+    fn part_1(program: &Self::Input<'_>) -> Self::Answer1 {
+        run_with(program, 12, 2)
+    }
+
+    fn part_2(program: &Self::Input<'_>) -> Self::Answer2 {
+        (0..=99)
+            .flat_map(|noun| (0..=99).map(move |verb| (noun, verb)))
+            .find(|&(noun, verb)| run_with(program, noun, verb) == 19690720)
+            .map(|(noun, verb)| 100 * noun + verb)
+            .unwrap()
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
+
+#[cfg(test)]
+mod tests {}
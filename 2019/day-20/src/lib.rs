@@ -1,14 +1,14 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 use std::{
     collections::{BinaryHeap, VecDeque},
-    fs,
     iter::once,
 };
 use std::{
     collections::{HashMap, HashSet},
     fmt,
 };
-use std::{env, fmt::Display};
+use std::fmt::Display;
 
 type Position = (usize, usize);
 type Portal = (Circle, String);
@@ -20,7 +20,7 @@ enum Tile {
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-enum Circle {
+pub enum Circle {
     Outer,
     Inner,
 }
@@ -89,6 +89,7 @@ impl Display for Donut {
     }
 }
 
+#[allow(clippy::needless_range_loop)]
 fn parse(content: &str) -> Donut {
     let get_dimensions = |lines: &[Vec<u8>]| {
         lines
@@ -373,26 +374,26 @@ fn find_path(
     None
 }
 
-fn solve_part_1(content: &str) {
-    let donut = parse(content);
-    let graph = make_graph(&donut);
-    let steps = find_path(&graph, get_next_state).unwrap();
-    println!("Part 1: {}", steps);
-}
+pub struct Puzzle;
 
-fn solve_part_2(content: &str) {
-    let donut = parse(content);
-    let graph = make_graph(&donut);
-    let steps = find_path(&graph, get_next_state_with_level).unwrap();
-    println!("Part 2: {}", steps);
-}
+impl Solution for Puzzle {
+    type Input<'a> = Graph;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-fn main() {
-    let args = env::args().collect_vec();
-    let filename = args.get(1).map(|s| s.as_ref()).unwrap_or("./res/input.txt");
+    fn parse(content: &str) -> Self::Input<'_> {
+        make_graph(&parse(content))
+    }
 
-    let content = fs::read_to_string(filename).unwrap();
+    fn part_1(graph: &Self::Input<'_>) -> Self::Answer1 {
+        find_path(graph, get_next_state).unwrap()
+    }
+
+    fn part_2(graph: &Self::Input<'_>) -> Self::Answer2 {
+        find_path(graph, get_next_state_with_level).unwrap()
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
@@ -0,0 +1,70 @@
+mod springscript;
+
+use aoc_common::{print_answers, Part, Solution};
+use intcode::{Ascii, Int, Program};
+use springscript::{parse_hull, simulate, synthesize, Hull, Mode, Script};
+
+fn get_damage(
+    program: &Program,
+    script: &Script,
+    mode: Mode,
+) -> Result<Int, Hull> {
+    let mut ascii = Ascii::from(program.clone());
+    ascii.send(&script.to_input(mode));
+    let output = ascii.read_all();
+
+    match ascii.values.last() {
+        Some(damage) => Ok(*damage),
+        None => Err(parse_hull(&output).unwrap()),
+    }
+}
+
+fn solve(program: &Program, mode: Mode) -> Int {
+    let mut hulls = vec![];
+    let mut last_fall = String::new();
+
+    loop {
+        let script = synthesize(mode, &hulls).unwrap_or_else(|| {
+            panic!(
+                "No springscript program survives {} hulls, last fall:\n{}",
+                hulls.len(),
+                last_fall
+            )
+        });
+
+        match get_damage(program, &script, mode) {
+            Ok(damage) => return damage,
+            Err(hull) => {
+                let jumps = |sensors| script.jumps(sensors);
+                let fall = simulate(jumps, &hull, mode)
+                    .expect_err("Simulated droid survived a fatal hull");
+                last_fall = format!("{}\n{}", script.to_input(mode), fall);
+                hulls.push(hull);
+            }
+        }
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Program;
+    type Answer1 = Int;
+    type Answer2 = Int;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.parse().unwrap()
+    }
+
+    fn part_1(program: &Self::Input<'_>) -> Self::Answer1 {
+        solve(program, Mode::Walk)
+    }
+
+    fn part_2(program: &Self::Input<'_>) -> Self::Answer2 {
+        solve(program, Mode::Run)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
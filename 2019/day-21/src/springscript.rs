@@ -0,0 +1,509 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+pub const MAX_INSTRUCTIONS: usize = 15;
+const MAX_TEMPORARY_COST: usize = 6;
+const JUMP_LENGTH: usize = 4;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    Walk,
+    Run,
+}
+
+impl Mode {
+    pub fn sensors(self) -> usize {
+        match self {
+            Mode::Walk => 4,
+            Mode::Run => 9,
+        }
+    }
+
+    pub fn command(self) -> &'static str {
+        match self {
+            Mode::Walk => "WALK",
+            Mode::Run => "RUN",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operation {
+    And,
+    Or,
+    Not,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Register {
+    Sensor(usize),
+    Temporary,
+    Jump,
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Register::Sensor(index) => {
+                write!(f, "{}", (b'A' + *index as u8) as char)
+            }
+            Register::Temporary => write!(f, "T"),
+            Register::Jump => write!(f, "J"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Instruction {
+    pub operation: Operation,
+    pub x: Register,
+    pub y: Register,
+}
+
+impl Instruction {
+    fn new(operation: Operation, x: Register, y: Register) -> Instruction {
+        Instruction { operation, x, y }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let operation = match self.operation {
+            Operation::And => "AND",
+            Operation::Or => "OR",
+            Operation::Not => "NOT",
+        };
+        write!(f, "{} {} {}", operation, self.x, self.y)
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Script {
+    pub instructions: Vec<Instruction>,
+}
+
+impl Script {
+    pub fn jumps(&self, sensors: u16) -> bool {
+        let mut temporary = false;
+        let mut jump = false;
+
+        for instruction in &self.instructions {
+            let x = match instruction.x {
+                Register::Sensor(index) => sensors & (1 << index) != 0,
+                Register::Temporary => temporary,
+                Register::Jump => jump,
+            };
+            let y = match instruction.y {
+                Register::Temporary => &mut temporary,
+                Register::Jump => &mut jump,
+                Register::Sensor(_) => continue,
+            };
+
+            *y = match instruction.operation {
+                Operation::And => x && *y,
+                Operation::Or => x || *y,
+                Operation::Not => !x,
+            };
+        }
+
+        jump
+    }
+
+    pub fn to_input(&self, mode: Mode) -> String {
+        format!("{}{}\n", self, mode.command())
+    }
+}
+
+impl Display for Script {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for instruction in &self.instructions {
+            writeln!(f, "{}", instruction)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub type Hull = Vec<bool>;
+
+pub fn parse_hull(text: &str) -> Option<Hull> {
+    text.lines()
+        .find(|line| {
+            line.contains('#') && line.chars().all(|c| c == '#' || c == '.')
+        })
+        .map(|line| line.chars().map(|c| c == '#').collect())
+}
+
+fn sensors(hull: &[bool], position: usize, count: usize) -> u16 {
+    (0..count)
+        .filter(|index| hull.get(position + index + 1).copied().unwrap_or(true))
+        .fold(0, |sensors, index| sensors | 1 << index)
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Fall {
+    pub hull: Hull,
+    pub position: usize,
+}
+
+impl Display for Fall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Droid fell at {}:", self.position)?;
+        for (position, ground) in self.hull.iter().enumerate() {
+            let tile = match (position == self.position, ground) {
+                (true, _) => '@',
+                (false, true) => '#',
+                (false, false) => '.',
+            };
+            write!(f, "{}", tile)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn simulate<F: Fn(u16) -> bool>(
+    jumps: F,
+    hull: &[bool],
+    mode: Mode,
+) -> Result<(), Fall> {
+    let mut position = 0;
+    while position < hull.len() {
+        if !hull[position] {
+            return Err(Fall {
+                hull: hull.to_vec(),
+                position,
+            });
+        }
+
+        if jumps(sensors(hull, position, mode.sensors())) {
+            position += JUMP_LENGTH;
+        } else {
+            position += 1;
+        }
+    }
+
+    Ok(())
+}
+
+type Truth = Vec<u64>;
+
+fn truth_from(values: impl Iterator<Item = bool>) -> Truth {
+    let mut truth = vec![];
+    for (index, value) in values.enumerate() {
+        if index % 64 == 0 {
+            truth.push(0);
+        }
+        if value {
+            truth[index / 64] |= 1 << (index % 64);
+        }
+    }
+    truth
+}
+
+fn negate(truth: &[u64], mask: &[u64]) -> Truth {
+    truth
+        .iter()
+        .zip(mask)
+        .map(|(word, mask)| !word & mask)
+        .collect()
+}
+
+fn truth_value(truth: &[u64], index: usize) -> bool {
+    truth[index / 64] & (1 << (index % 64)) != 0
+}
+
+#[derive(Clone, Debug)]
+enum Effect {
+    Negate,
+    Combine(Operation, Truth),
+    Replace(Truth),
+}
+
+#[derive(Clone, Debug)]
+struct Edge {
+    cost: usize,
+    instructions: Vec<Instruction>,
+    effect: Effect,
+}
+
+impl Edge {
+    fn apply(&self, truth: &[u64], mask: &[u64]) -> Truth {
+        match &self.effect {
+            Effect::Negate => negate(truth, mask),
+            Effect::Combine(operation, other) => truth
+                .iter()
+                .zip(other)
+                .map(|(word, other)| match operation {
+                    Operation::And => word & other,
+                    _ => word | other,
+                })
+                .collect(),
+            Effect::Replace(other) => other.clone(),
+        }
+    }
+}
+
+struct Node {
+    truth: Truth,
+    cost: usize,
+    parent: usize,
+    edge: usize,
+}
+
+struct Search {
+    nodes: Vec<Node>,
+    goal: Option<usize>,
+}
+
+impl Search {
+    fn run(
+        edges: &[Edge],
+        mask: &[u64],
+        clean_root: bool,
+        max_cost: usize,
+        mut is_goal: impl FnMut(&Truth) -> bool,
+    ) -> Search {
+        let root = Node {
+            truth: vec![0; mask.len()],
+            cost: 0,
+            parent: 0,
+            edge: usize::MAX,
+        };
+        let mut search = Search {
+            nodes: vec![root],
+            goal: None,
+        };
+        let mut levels = vec![vec![0]];
+        let mut seen = HashSet::new();
+        seen.insert(search.nodes[0].truth.clone());
+
+        if is_goal(&search.nodes[0].truth) {
+            search.goal = Some(0);
+            return search;
+        }
+
+        for cost in 1..=max_cost {
+            let mut level = vec![];
+
+            for (edge_index, edge) in edges.iter().enumerate() {
+                if edge.cost > cost {
+                    continue;
+                }
+
+                let replace = matches!(edge.effect, Effect::Replace(_));
+                for &parent in &levels[cost - edge.cost] {
+                    let from_root = parent == 0;
+                    if (replace && !from_root)
+                        || (!replace && from_root && !clean_root)
+                    {
+                        continue;
+                    }
+
+                    let truth = edge.apply(&search.nodes[parent].truth, mask);
+                    if seen.contains(&truth) {
+                        continue;
+                    }
+
+                    seen.insert(truth.clone());
+                    let goal = is_goal(&truth);
+                    level.push(search.nodes.len());
+                    search.nodes.push(Node {
+                        truth,
+                        cost,
+                        parent,
+                        edge: edge_index,
+                    });
+
+                    if goal {
+                        search.goal = Some(search.nodes.len() - 1);
+                        return search;
+                    }
+                }
+            }
+
+            levels.push(level);
+        }
+
+        search
+    }
+
+    fn instructions(
+        &self,
+        edges: &[Edge],
+        mut index: usize,
+    ) -> Vec<Instruction> {
+        let mut path = vec![];
+        while index != 0 {
+            let node = &self.nodes[index];
+            path.push(&edges[node.edge].instructions);
+            index = node.parent;
+        }
+
+        path.into_iter().rev().flatten().copied().collect()
+    }
+}
+
+fn register_edges(
+    literals: &[(Register, Truth)],
+    register: Register,
+) -> Vec<Edge> {
+    let mut edges = vec![Edge {
+        cost: 1,
+        instructions: vec![Instruction::new(
+            Operation::Not,
+            register,
+            register,
+        )],
+        effect: Effect::Negate,
+    }];
+
+    for (sensor, truth) in literals {
+        for operation in [Operation::And, Operation::Or] {
+            edges.push(Edge {
+                cost: 1,
+                instructions: vec![Instruction::new(
+                    operation, *sensor, register,
+                )],
+                effect: Effect::Combine(operation, truth.clone()),
+            });
+        }
+    }
+
+    edges
+}
+
+pub fn synthesize(mode: Mode, hulls: &[Hull]) -> Option<Script> {
+    let mut states = vec![];
+    let mut state_indices = HashMap::new();
+    for hull in hulls {
+        for position in 0..hull.len() {
+            let state = sensors(hull, position, mode.sensors());
+            state_indices.entry(state).or_insert_with(|| {
+                states.push(state);
+                states.len() - 1
+            });
+        }
+    }
+
+    let mask = truth_from(states.iter().map(|_| true));
+    let literals = (0..mode.sensors())
+        .map(|sensor| {
+            let values = states.iter().map(|state| state & (1 << sensor) != 0);
+            (Register::Sensor(sensor), truth_from(values))
+        })
+        .collect::<Vec<_>>();
+
+    let mut temporary_edges = register_edges(&literals, Register::Temporary);
+    let mut jump_edges = register_edges(&literals, Register::Jump);
+    for (sensor, truth) in &literals {
+        let not =
+            |register| Instruction::new(Operation::Not, *sensor, register);
+        let negated = negate(truth, &mask);
+        let flip = Instruction::new(
+            Operation::Not,
+            Register::Temporary,
+            Register::Temporary,
+        );
+
+        temporary_edges.push(Edge {
+            cost: 1,
+            instructions: vec![not(Register::Temporary)],
+            effect: Effect::Replace(negated.clone()),
+        });
+        temporary_edges.push(Edge {
+            cost: 2,
+            instructions: vec![not(Register::Temporary), flip],
+            effect: Effect::Replace(truth.clone()),
+        });
+        jump_edges.push(Edge {
+            cost: 1,
+            instructions: vec![not(Register::Jump)],
+            effect: Effect::Replace(negated),
+        });
+    }
+
+    let temporaries =
+        Search::run(&temporary_edges, &mask, false, MAX_TEMPORARY_COST, |_| {
+            false
+        });
+    for (index, node) in temporaries.nodes.iter().enumerate().skip(1) {
+        let mut instructions =
+            temporaries.instructions(&temporary_edges, index);
+        for operation in [Operation::And, Operation::Or] {
+            instructions.push(Instruction::new(
+                operation,
+                Register::Temporary,
+                Register::Jump,
+            ));
+            jump_edges.push(Edge {
+                cost: node.cost + 1,
+                instructions: instructions.clone(),
+                effect: Effect::Combine(operation, node.truth.clone()),
+            });
+            instructions.pop();
+        }
+    }
+
+    let survives = |truth: &Truth| {
+        let jumps = |state| truth_value(truth, state_indices[&state]);
+        hulls.iter().all(|hull| simulate(jumps, hull, mode).is_ok())
+    };
+
+    let search =
+        Search::run(&jump_edges, &mask, true, MAX_INSTRUCTIONS, survives);
+    search.goal.map(|goal| Script {
+        instructions: search.instructions(&jump_edges, goal),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hull(pattern: &str) -> Hull {
+        parse_hull(pattern).unwrap()
+    }
+
+    #[test]
+    fn test_simulate() {
+        let script = Script {
+            instructions: vec![Instruction::new(
+                Operation::Not,
+                Register::Sensor(0),
+                Register::Jump,
+            )],
+        };
+        let jumps = |sensors| script.jumps(sensors);
+
+        assert!(simulate(jumps, &hull("#####.###########"), Mode::Walk).is_ok());
+        assert_eq!(
+            simulate(jumps, &hull("#####..#.########"), Mode::Walk),
+            Err(Fall {
+                hull: hull("#####..#.########"),
+                position: 8
+            })
+        );
+    }
+
+    #[test]
+    fn test_synthesize() {
+        let hulls = vec![
+            hull("#####.###########"),
+            hull("#####..#.########"),
+            hull("#####...#########"),
+            hull("#####.#..########"),
+        ];
+
+        let script = synthesize(Mode::Walk, &hulls).unwrap();
+        assert!(script.instructions.len() <= MAX_INSTRUCTIONS);
+        for hull in &hulls {
+            assert!(simulate(
+                |sensors| script.jumps(sensors),
+                hull,
+                Mode::Walk
+            )
+            .is_ok());
+        }
+    }
+}
@@ -1,15 +1,14 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::{Itertools};
 use std::{
     collections::{HashMap},
-    env,
     num::ParseIntError,
     str::FromStr,
 };
-use std::{fs};
 use Technique::{Cut, Increment, New};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
-enum Technique {
+pub enum Technique {
     New,
     Cut(isize),
     Increment(usize),
@@ -66,7 +65,7 @@ impl View {
             }
             View::Rotate { inner, n } => {
                 let inner_index = if *n < 0 {
-                    (index + (len - n.abs() as usize % len)) % len
+                    (index + (len - n.unsigned_abs() % len)) % len
                 } else {
                     (index + *n as usize) % len
                 };
@@ -90,7 +89,7 @@ impl View {
 fn safe_mul(a: usize, b: usize, m: usize) -> usize {
     if let Some(r) = a.checked_mul(b) {
         r % m
-    } else if b % 2 == 0 {
+    } else if b.is_multiple_of(2) {
         safe_mul(safe_mul(a, b / 2, m), 2, m)
     } else {
         (safe_mul(safe_mul(a, b / 2, m), 2, m) + a) % m
@@ -120,9 +119,9 @@ fn reorganize(techniques: Vec<Technique>, size: usize) -> Vec<Technique> {
             }
             (Cut(c), Increment(i)) => {
                 let c = if c < 0 {
-                    size - c.abs() as usize
+                    size - c.unsigned_abs()
                 } else {
-                    c.abs() as usize
+                    c.unsigned_abs()
                 };
                 reorganized_techniques.push(Increment(i));
                 reorganized_techniques.push(Cut(safe_mul(i, c, size) as isize));
@@ -159,7 +158,7 @@ fn compress_one(techniques: Vec<Technique>, size: usize) -> Vec<Technique> {
                 (_, _) => Err((Some(lhs), Some(rhs))),
             },
         })
-        .filter_map(|o| o)
+        .flatten()
         .collect_vec()
 }
 
@@ -195,9 +194,9 @@ fn compress_repeat(
     } else {
         let r_cycles = cycles / 2;
         let l_cycles = cycles - r_cycles;
-        let left = compress_repeat(&techniques, size, l_cycles, cache);
-        let right = compress_repeat(&techniques, size, r_cycles, cache);
-        let next = left.into_iter().chain(right.into_iter()).collect_vec();
+        let left = compress_repeat(techniques, size, l_cycles, cache);
+        let right = compress_repeat(techniques, size, r_cycles, cache);
+        let next = left.into_iter().chain(right).collect_vec();
         compress_max(next, size)
     };
 
@@ -235,30 +234,20 @@ fn techniques_to_view(techniques: Vec<Technique>, size: usize) -> View {
     })
 }
 
-fn solve_part_1(content: &str) {
-    let techniques: Vec<Technique> = content
-        .lines()
-        .map(|line| line.parse().unwrap())
-        .collect_vec();
-
+fn solve_part_1(techniques: &[Technique]) -> usize {
     let size = 10007;
-    let techniques = compress_max(techniques, size);
+    let techniques = compress_max(techniques.to_vec(), size);
     let view = techniques_to_view(techniques, size);
 
     let position = view.iter().position(|card| card == 2019).unwrap();
-    println!("Part 1: {}", position);
+    position
 }
 
-fn solve_part_2(content: &str) {
-    let techniques: Vec<Technique> = content
-        .lines()
-        .map(|line| line.parse().unwrap())
-        .collect_vec();
-
+fn solve_part_2(techniques: &[Technique]) -> Card {
     let size = 119315717514047;
     let cycles = 101741582076661;
     let compressed_techniques = compress_repeat(
-        &compress_max(techniques, size),
+        &compress_max(techniques.to_vec(), size),
         size,
         cycles,
         &mut HashMap::new(),
@@ -266,15 +255,32 @@ fn solve_part_2(content: &str) {
 
     let view = techniques_to_view(compressed_techniques, size);
 
-    println!("Part 2: {}", view.get(2020).unwrap());
+    view.get(2020).unwrap()
 }
 
-fn main() {
-    let args = env::args().collect_vec();
-    let filename = args.get(1).map(|s| s.as_ref()).unwrap_or("./res/input.txt");
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Technique>;
+    type Answer1 = usize;
+    type Answer2 = Card;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content
+            .lines()
+            .map(|line| line.parse().unwrap())
+            .collect_vec()
+    }
 
-    let content = fs::read_to_string(filename).unwrap();
+    fn part_1(techniques: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(techniques)
+    }
+
+    fn part_2(techniques: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(techniques)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
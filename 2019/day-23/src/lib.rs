@@ -0,0 +1,72 @@
+use aoc_common::{print_answers, Part, Solution};
+use intcode::{
+    CaptureNat, Int, Network, Program, QuietRounds, Topology, WakeNat,
+};
+use std::fs;
+
+const COMPUTERS: usize = 50;
+const NAT_ADDRESS: Int = 255;
+
+fn network(program: &Program) -> Network {
+    Network::new(program, Topology::new(COMPUTERS))
+}
+
+fn solve_part_1(program: &Program) -> Int {
+    let mut network = network(program);
+    let mut nat = CaptureNat::new(NAT_ADDRESS);
+    network.run(&mut nat, &mut QuietRounds::new(1)).unwrap();
+
+    nat.packet.unwrap().values[1]
+}
+
+fn solve_part_2(program: &Program, log_filename: Option<&String>) -> Int {
+    let mut network = network(program);
+    let mut nat = WakeNat::new(NAT_ADDRESS, 0);
+    network.run(&mut nat, &mut QuietRounds::new(1)).unwrap();
+
+    if let Some(log_filename) = log_filename {
+        fs::write(log_filename, network.log.to_string()).unwrap();
+    }
+
+    nat.repeated.unwrap().values[1]
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Program;
+    type Answer1 = Int;
+    type Answer2 = Int;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.parse().unwrap()
+    }
+
+    fn part_1(program: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(program)
+    }
+
+    fn part_2(program: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(program, None)
+    }
+}
+
+pub fn run(part: Part, input: &str, args: &[String]) {
+    let log_filename = args
+        .iter()
+        .position(|arg| arg == "--log")
+        .and_then(|index| args.get(index + 1));
+
+    if log_filename.is_none() {
+        print_answers::<Puzzle>(input, part);
+        return;
+    }
+
+    let program = Puzzle::parse(input);
+    if part.includes(1) {
+        println!("Part 1: {}", solve_part_1(&program));
+    }
+    if part.includes(2) {
+        println!("Part 2: {}", solve_part_2(&program, log_filename));
+    }
+}
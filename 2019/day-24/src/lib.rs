@@ -1,16 +1,16 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::FoldWhile::{Continue, Done};
 use itertools::Itertools;
 use std::{
     collections::HashSet,
-    env,
     hash::Hash,
-    iter::{once, successors},
+    iter::once,
     str::FromStr,
 };
-use std::{fmt::Display, fs};
+use std::fmt::Display;
 
 #[derive(Clone, Debug)]
-struct Eris {
+pub struct Eris {
     grids: Vec<u32>,
     size: usize,
 }
@@ -86,7 +86,7 @@ impl Eris {
     {
         (0..25)
             .map(|shift| 1 << shift)
-            .zip(i.into_iter())
+            .zip(i)
             .map(|(mask, infested)| if infested { mask } else { 0 })
             .fold(0, |g, m| g | m)
     }
@@ -123,7 +123,7 @@ impl Eris {
     }
 
     fn neighbors(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
-        once(if index % self.size == 0 {
+        once(if index.is_multiple_of(self.size) {
             None
         } else {
             index.checked_sub(1)
@@ -135,7 +135,7 @@ impl Eris {
         }))
         .chain(once(index.checked_sub(self.size)))
         .chain(once(index.checked_add(self.size)))
-        .filter_map(|o| o)
+        .flatten()
         .filter(move |neighbor| *neighbor < self.len())
     }
 
@@ -147,10 +147,10 @@ impl Eris {
 
         if index < self.size {
             neighbors.push((0, 7));
-        } else if 20 <= index && index < 25 {
+        } else if (20..25).contains(&index) {
             neighbors.push((0, 17));
         }
-        if index % 5 == 0 {
+        if index.is_multiple_of(5) {
             neighbors.push((0, 11));
         } else if index % 5 == 4 {
             neighbors.push((0, 13));
@@ -183,7 +183,7 @@ impl Eris {
 
             match self.get(0, current).unwrap() {
                 true => count == 1,
-                false => 1 <= count && count <= 2,
+                false => (1..=2).contains(&count),
             }
         });
 
@@ -208,7 +208,7 @@ impl Eris {
 
                 match Eris::grid_get(middle, current).unwrap() {
                     true => count == 1,
-                    false => 1 <= count && count <= 2,
+                    false => (1..=2).contains(&count),
                 }
             });
 
@@ -237,12 +237,11 @@ impl Eris {
     }
 }
 
-fn solve_part_1(content: &str) {
-    let eris: Eris = content.parse().unwrap();
+fn solve_part_1(eris: &Eris) -> u32 {
     let set: HashSet<Eris> = once(eris.clone()).collect();
 
     let (eris, _) = (0..)
-        .fold_while((eris, set), |(e, mut s), _| {
+        .fold_while((eris.clone(), set), |(e, mut s), _| {
             let n = e.step();
             if s.contains(&n) {
                 Done((n, s))
@@ -253,35 +252,45 @@ fn solve_part_1(content: &str) {
         })
         .into_inner();
 
-    println!("Part 1: {}", eris.rating());
+    eris.rating()
 }
 
-fn solve_part_2(content: &str) {
-    let eris: Eris = content.parse().unwrap();
-
-    let eris = (0..200).fold(eris, |e, _| {
+fn solve_part_2(eris: &Eris) -> usize {
+    let eris = (0..200).fold(eris.clone(), |e, _| {
         e.recursive_step()
     });
 
     let eris_ref = &eris;
 
-    let count = (0..eris.grids.len())
+    (0..eris.grids.len())
         .flat_map(|grid_index| {
             (0..25).map(move |index| eris_ref.get(grid_index, index))
         })
-        .filter_map(|o| o)
+        .flatten()
         .filter(|b| *b)
-        .count();
-
-    println!("Part 2: {}", count);
+        .count()
 }
 
-fn main() {
-    let args = env::args().collect_vec();
-    let filename = args.get(1).map(|s| s.as_ref()).unwrap_or("./res/input.txt");
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Eris;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.parse().unwrap()
+    }
 
-    let content = fs::read_to_string(filename).unwrap();
+    fn part_1(eris: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(eris)
+    }
+
+    fn part_2(eris: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(eris)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
@@ -0,0 +1,209 @@
+mod room;
+
+use aoc_common::{print_answers, NoPuzzle, Part, Solution};
+use intcode::{Ascii, Program, ProgramState};
+use itertools::Itertools;
+use room::{parse_rooms, Room};
+use std::collections::HashMap;
+
+const STEP_BUDGET: usize = 10_000_000;
+const CHECKPOINT: &str = "Security Checkpoint";
+const FLOOR: &str = "Pressure-Sensitive Floor";
+
+fn send(ascii: &mut Ascii, request: &str) -> (ProgramState, String) {
+    ascii.send_line(request);
+    let response = ascii.read_all();
+    (ascii.state, response)
+}
+
+fn opposite(direction: &str) -> &'static str {
+    match direction {
+        "north" => "south",
+        "south" => "north",
+        "east" => "west",
+        "west" => "east",
+        _ => panic!("Unknown direction {}", direction),
+    }
+}
+
+#[derive(Default)]
+struct Checkpoint {
+    path: Vec<String>,
+    floor_direction: String,
+}
+
+struct Explorer {
+    program: Ascii,
+    map: HashMap<String, HashMap<String, String>>,
+    items: Vec<String>,
+    traps: Vec<String>,
+    checkpoint: Checkpoint,
+}
+
+impl Explorer {
+    fn new(program: Program) -> Explorer {
+        let mut program = Ascii::from(program);
+        program.step_limit = Some(STEP_BUDGET);
+
+        Explorer {
+            program,
+            map: HashMap::new(),
+            items: vec![],
+            traps: vec![],
+            checkpoint: Checkpoint::default(),
+        }
+    }
+
+    fn start(&mut self) -> Room {
+        let response = self.program.read_all();
+        parse_rooms(&response).pop().unwrap()
+    }
+
+    fn is_safe(&self, item: &str, room: &Room) -> bool {
+        let mut program = self.program.clone();
+        let (state, _) = send(&mut program, &format!("take {}", item));
+        if state != ProgramState::Input {
+            return false;
+        }
+
+        let (state, response) = send(&mut program, &room.doors[0]);
+        state == ProgramState::Input && !parse_rooms(&response).is_empty()
+    }
+
+    fn explore(&mut self, room: &Room, path: &mut Vec<String>) {
+        self.map.entry(room.name.clone()).or_default();
+
+        for item in &room.items {
+            if self.is_safe(item, room) {
+                send(&mut self.program, &format!("take {}", item));
+                self.items.push(item.clone());
+            } else {
+                self.traps.push(item.clone());
+            }
+        }
+
+        if room.name == CHECKPOINT {
+            self.checkpoint.path = path.clone();
+        }
+
+        for door in &room.doors {
+            if self.map[&room.name].contains_key(door) {
+                continue;
+            }
+
+            let (_, response) = send(&mut self.program, door);
+            let next = parse_rooms(&response).pop().unwrap();
+
+            if next.name == room.name {
+                self.checkpoint.floor_direction = door.clone();
+                self.connect(&room.name, door, FLOOR);
+                continue;
+            }
+
+            let visited = self.map.contains_key(&next.name);
+            self.connect(&room.name, door, &next.name);
+            self.connect(&next.name, opposite(door), &room.name);
+
+            if !visited {
+                path.push(door.clone());
+                self.explore(&next, path);
+                path.pop();
+            }
+
+            send(&mut self.program, opposite(door));
+        }
+    }
+
+    fn connect(&mut self, from: &str, direction: &str, to: &str) {
+        self.map
+            .entry(from.to_string())
+            .or_default()
+            .insert(direction.to_string(), to.to_string());
+    }
+
+    fn move_checkpoint(&mut self) {
+        for direction in &self.checkpoint.path {
+            send(&mut self.program, direction);
+        }
+    }
+
+    fn find_code(&mut self) -> Option<usize> {
+        for item in &self.items {
+            send(&mut self.program, &format!("drop {}", item));
+        }
+
+        let mut carried = 0usize;
+        let mut too_light: Vec<usize> = vec![];
+        let mut too_heavy: Vec<usize> = vec![];
+
+        let masks = (0..1usize << self.items.len())
+            .sorted_by_key(|mask| (mask.count_ones(), *mask));
+
+        for mask in masks {
+            let lighter = too_light.iter().any(|light| mask & !light == 0);
+            let heavier = too_heavy.iter().any(|heavy| mask & heavy == *heavy);
+            if lighter || heavier {
+                continue;
+            }
+
+            for (index, item) in self.items.iter().enumerate() {
+                let bit = 1 << index;
+                if mask & bit != 0 && carried & bit == 0 {
+                    send(&mut self.program, &format!("take {}", item));
+                } else if mask & bit == 0 && carried & bit != 0 {
+                    send(&mut self.program, &format!("drop {}", item));
+                }
+            }
+            carried = mask;
+
+            let floor_direction = &self.checkpoint.floor_direction;
+            let (_, response) = send(&mut self.program, floor_direction);
+            if response.contains("Analysis complete!") {
+                let s = "by typing ";
+                let start = response.find(s).unwrap() + s.len();
+                let end = start + response[start..].find(' ').unwrap();
+                return response[start..end].parse().ok();
+            } else if response.contains("heavier than the detected") {
+                too_light.push(mask);
+            } else if response.contains("lighter than the detected") {
+                too_heavy.push(mask);
+            }
+        }
+
+        None
+    }
+}
+
+fn solve(program: &Program) -> usize {
+    let mut explorer = Explorer::new(program.clone());
+
+    let start = explorer.start();
+    explorer.explore(&start, &mut vec![]);
+    explorer.move_checkpoint();
+
+    explorer.find_code().unwrap()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Program;
+    type Answer1 = usize;
+    type Answer2 = NoPuzzle;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.parse().unwrap()
+    }
+
+    fn part_1(program: &Self::Input<'_>) -> Self::Answer1 {
+        solve(program)
+    }
+
+    fn part_2(_program: &Self::Input<'_>) -> Self::Answer2 {
+        NoPuzzle
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
@@ -0,0 +1,66 @@
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Room {
+    pub name: String,
+    pub doors: Vec<String>,
+    pub items: Vec<String>,
+}
+
+enum Section {
+    None,
+    Doors,
+    Items,
+}
+
+pub fn parse_rooms(text: &str) -> Vec<Room> {
+    let mut rooms: Vec<Room> = vec![];
+    let mut section = Section::None;
+
+    for line in text.lines().map(str::trim) {
+        if line.starts_with("== ") && line.ends_with(" ==") {
+            let name = line.trim_matches(|c| c == '=' || c == ' ');
+            rooms.push(Room {
+                name: name.to_string(),
+                ..Room::default()
+            });
+            section = Section::None;
+        } else if line == "Doors here lead:" {
+            section = Section::Doors;
+        } else if line == "Items here:" {
+            section = Section::Items;
+        } else if let (Some(entry), Some(room)) =
+            (line.strip_prefix("- "), rooms.last_mut())
+        {
+            match section {
+                Section::Doors => room.doors.push(entry.to_string()),
+                Section::Items => room.items.push(entry.to_string()),
+                Section::None => {}
+            }
+        } else {
+            section = Section::None;
+        }
+    }
+
+    rooms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rooms() {
+        let text = "\n\n\n== Hull Breach ==\nYou got in through a hole.\n\n\
+                    Doors here lead:\n- north\n- east\n\n\
+                    Items here:\n- mug\n\nCommand?\n";
+
+        let rooms = parse_rooms(text);
+        assert_eq!(
+            rooms,
+            vec![Room {
+                name: "Hull Breach".to_string(),
+                doors: vec!["north".to_string(), "east".to_string()],
+                items: vec!["mug".to_string()],
+            }]
+        );
+    }
+}
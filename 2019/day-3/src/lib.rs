@@ -1,10 +1,10 @@
-use std::env;
-use std::{collections::HashSet, fs, ops::Add};
+use std::{collections::HashSet, ops::Add};
 
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-struct Point(isize, isize);
+pub struct Point(isize, isize);
 
 type Set = HashSet<Point>;
 
@@ -50,53 +50,51 @@ fn parse_content(
     content.lines().map(crate::parse_path)
 }
 
-fn solve_part_1(content: &str) {
-    let set_vec = parse_content(content)
-        .map(|v| v.collect::<Set>())
-        .collect_vec();
-
-    let min_distance = set_vec[0]
-        .intersection(&set_vec[1])
-        .map(Point::distance)
-        .min()
-        .unwrap();
-
-    println!("Part 1: {}", min_distance)
-}
-
 fn count_steps(path: &[Point], point: &Point) -> usize {
     path.iter().position(|p| p == point).unwrap() + 1
 }
 
-fn solve_part_2(content: &str) {
-    let path_vec = parse_content(content)
-        .map(|i| i.collect_vec())
-        .collect_vec();
-
-    let set_vec = path_vec
+fn intersections(paths: &[Vec<Point>]) -> Set {
+    let set_vec = paths
         .iter()
         .map(|v| v.iter().copied().collect::<Set>())
         .collect_vec();
 
-    let min_steps = set_vec[0]
-        .intersection(&set_vec[1])
-        .map(|point| {
-            count_steps(&path_vec[0], point) + count_steps(&path_vec[1], point)
-        })
-        .min()
-        .unwrap();
-
-    println!("Part 2: {}", min_steps)
+    set_vec[0].intersection(&set_vec[1]).copied().collect()
 }
 
-fn main() {
-    let args = env::args().collect_vec();
-    let filename = args.get(1).map(|s| s.as_ref()).unwrap_or("./res/input.txt");
+pub struct Puzzle;
 
-    let content = fs::read_to_string(filename).unwrap();
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Vec<Point>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_content(content).map(|i| i.collect_vec()).collect_vec()
+    }
+
+    fn part_1(paths: &Self::Input<'_>) -> Self::Answer1 {
+        intersections(paths)
+            .iter()
+            .map(Point::distance)
+            .min()
+            .unwrap()
+    }
+
+    fn part_2(paths: &Self::Input<'_>) -> Self::Answer2 {
+        intersections(paths)
+            .iter()
+            .map(|point| {
+                count_steps(&paths[0], point) + count_steps(&paths[1], point)
+            })
+            .min()
+            .unwrap()
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
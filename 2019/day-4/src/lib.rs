@@ -1,6 +1,4 @@
-use std::env;
-use std::fs;
-
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
 type Password = u64;
@@ -74,7 +72,6 @@ fn parse_range(content: &str) -> (Password, Password) {
 fn is_password_valid_part_1(password: &Password) -> bool {
     let digit_vec = (0..6)
         .rev()
-        .into_iter()
         .map(|power| (10 as Password).pow(power))
         .map(|unit| password / unit % 10)
         .collect_vec();
@@ -85,7 +82,6 @@ fn is_password_valid_part_1(password: &Password) -> bool {
 fn is_password_valid_part_2(password: &Password) -> bool {
     let digit_vec = (0..6)
         .rev()
-        .into_iter()
         .map(|power| (10 as Password).pow(power))
         .map(|unit| password / unit % 10)
         .collect_vec();
@@ -93,33 +89,35 @@ fn is_password_valid_part_2(password: &Password) -> bool {
         && digit_vec.iter().tuple_windows().all(|(a, b)| a <= b)
 }
 
-fn solve(content: &str, is_password_valid: fn(&Password) -> bool) -> usize {
-    let range = parse_range(content);
-
-    (range.0..=range.1)
-        .into_iter()
-        .filter(is_password_valid)
-        .count()
+fn solve(
+    range: &(Password, Password),
+    is_password_valid: fn(&Password) -> bool,
+) -> usize {
+    (range.0..=range.1).filter(is_password_valid).count()
 }
 
-fn solve_part_1(content: &str) {
-    let valid_password_count = solve(content, is_password_valid_part_1);
-    println!("Part 1: {}", valid_password_count)
-}
+pub struct Puzzle;
 
-fn solve_part_2(content: &str) {
-    let valid_password_count = solve(content, is_password_valid_part_2);
-    println!("Part 2: {}", valid_password_count)
-}
+impl Solution for Puzzle {
+    type Input<'a> = (Password, Password);
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-fn main() {
-    let args = env::args().collect_vec();
-    let filename = args.get(1).map(|s| s.as_ref()).unwrap_or("./res/input.txt");
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_range(content)
+    }
 
-    let content = fs::read_to_string(filename).unwrap();
+    fn part_1(range: &Self::Input<'_>) -> Self::Answer1 {
+        solve(range, is_password_valid_part_1)
+    }
+
+    fn part_2(range: &Self::Input<'_>) -> Self::Answer2 {
+        solve(range, is_password_valid_part_2)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
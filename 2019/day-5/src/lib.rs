@@ -0,0 +1,36 @@
+use aoc_common::{print_answers, Part, Solution};
+use intcode::{run_outputs, Int, Program};
+
+fn diagnose(program: &Program, system: Int) -> Int {
+    let mut program = program.clone();
+    program.input(system);
+    run_outputs(&mut program);
+    *program.outputs.back().unwrap()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Program;
+    type Answer1 = Int;
+    type Answer2 = Int;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.parse().unwrap()
+    }
+
+    fn part_1(program: &Self::Input<'_>) -> Self::Answer1 {
+        diagnose(program, 1)
+    }
+
+    fn part_2(program: &Self::Input<'_>) -> Self::Answer2 {
+        diagnose(program, 5)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
+
+#[cfg(test)]
+mod tests {}
@@ -1,11 +1,9 @@
 use std::{
-    collections::{HashMap, HashSet, VecDeque},
-    env,
+    collections::HashMap,
     fmt::Debug,
-    str::FromStr,
 };
-use std::{fs, num::ParseIntError};
 
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
 type Graph<'a, 'b> = HashMap<&'a str, Vec<&'b str>>;
@@ -27,34 +25,24 @@ where
     f(results, node)
 }
 
-fn solve_part_1(content: &str) {
-    let graph = content
-        .lines()
-        .map(|line| line.split(')').collect_tuple().unwrap())
-        .into_group_map();
-
+fn solve_part_1(graph: &Graph) -> usize {
     type Pair = (usize, usize);
 
     let (orbit_count, _) =
-        fold_graph(&graph, "COM", |results: Vec<Pair>, _| {
+        fold_graph(graph, "COM", |results: Vec<Pair>, _| {
             results
                 .into_iter()
                 .fold((0, 1), |a, r| (a.0 + r.0 + r.1, a.1 + r.1))
         });
 
-    println!("Part 1: {}", orbit_count)
+    orbit_count
 }
 
-fn solve_part_2(content: &str) {
-    let graph = content
-        .lines()
-        .map(|line| line.split(')').collect_tuple().unwrap())
-        .into_group_map();
-
+fn solve_part_2(graph: &Graph) -> usize {
     type Pair = (Option<usize>, Option<usize>);
 
     let transfer_counts =
-        fold_graph(&graph, "COM", |results: Vec<Pair>, node: &str| match node {
+        fold_graph(graph, "COM", |results: Vec<Pair>, node: &str| match node {
             "YOU" => (Some(0), None),
             "SAN" => (None, Some(0)),
             _ => {
@@ -70,20 +58,34 @@ fn solve_part_2(content: &str) {
             }
         });
 
-    let transfer_count =
-        transfer_counts.0.unwrap() + transfer_counts.1.unwrap();
-
-    println!("Part 2: {}", transfer_count)
+    transfer_counts.0.unwrap() + transfer_counts.1.unwrap()
 }
 
-fn main() {
-    let args = env::args().collect_vec();
-    let filename = args.get(1).map(|s| s.as_ref()).unwrap_or("./res/input.txt");
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Graph<'a, 'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let content = fs::read_to_string(filename).unwrap();
+    fn parse(content: &str) -> Self::Input<'_> {
+        content
+            .lines()
+            .map(|line| line.split(')').collect_tuple().unwrap())
+            .into_group_map()
+    }
+
+    fn part_1(graph: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(graph)
+    }
+
+    fn part_2(graph: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(graph)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
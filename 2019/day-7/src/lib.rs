@@ -0,0 +1,62 @@
+use aoc_common::{print_answers, Part, Solution};
+use intcode::{run_connected, Int, Machine, Pipe, Program};
+use itertools::Itertools;
+use std::ops::Range;
+
+fn compute_signal(start_program: &Program, phases: Vec<Int>) -> Int {
+    let pipes = phases
+        .iter()
+        .map(|phase| {
+            let pipe = Pipe::new();
+            pipe.push(*phase);
+            pipe
+        })
+        .collect_vec();
+    pipes[0].push(0);
+
+    let mut amplifiers = (0..phases.len())
+        .map(|index| {
+            let source = pipes[index].clone();
+            let sink = pipes[(index + 1) % pipes.len()].clone();
+            Machine::new(start_program.clone(), source, sink)
+        })
+        .collect_vec();
+    run_connected(&mut amplifiers).unwrap();
+
+    pipes[0].pop().unwrap()
+}
+
+fn solve(start_program: &Program, range: Range<Int>) -> Int {
+    range
+        .permutations(5)
+        .map(|phases| compute_signal(start_program, phases))
+        .max()
+        .unwrap()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Program;
+    type Answer1 = Int;
+    type Answer2 = Int;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.parse().unwrap()
+    }
+
+    fn part_1(program: &Self::Input<'_>) -> Self::Answer1 {
+        solve(program, 0..5)
+    }
+
+    fn part_2(program: &Self::Input<'_>) -> Self::Answer2 {
+        solve(program, 5..10)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
+
+#[cfg(test)]
+mod tests {}
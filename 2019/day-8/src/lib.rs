@@ -0,0 +1,86 @@
+use aoc_common::{print_answers, Part, Solution};
+use itertools::Itertools;
+
+type Pixel = char;
+type Layer = Vec<Pixel>;
+
+fn parse_layers(content: &str, width: usize, height: usize) -> Vec<Layer> {
+    content
+        .trim()
+        .chars()
+        .chunks(width * height)
+        .into_iter()
+        .map(Itertools::collect_vec)
+        .collect_vec()
+}
+
+fn solve_part_1(layers: &[Layer]) -> usize {
+    layers
+        .iter()
+        .map(|layer| layer.iter().counts())
+        .min_by_key(|counts| counts[&'0'])
+        .map(|counts| counts[&'1'] * counts[&'2'])
+        .unwrap()
+}
+
+fn render(layers: &[Layer], width: usize, height: usize) -> String {
+    let size = width * height;
+    (0..size)
+        .map(|index| {
+            layers
+                .iter()
+                .map(|layer| layer[index])
+                .find(|pixel| *pixel != '2')
+                .unwrap()
+        })
+        .map(|pixel| if pixel == '1' { '\u{2588}' } else { ' ' })
+        .chunks(width)
+        .into_iter()
+        .map(|chunk| chunk.collect::<String>())
+        .join("\n")
+}
+
+fn solve_part_2(layers: &[Layer]) -> String {
+    let image = render(layers, 25, 6);
+    match ocr::recognize(&image) {
+        Ok(letters) => letters,
+        Err(error) => format!("{}\n{}", image, error),
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Layer>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_layers(content, 25, 6)
+    }
+
+    fn part_1(layers: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(layers)
+    }
+
+    fn part_2(layers: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(layers)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_2() {
+        let content = include_str!("../res/input.txt");
+        let layers = parse_layers(content, 25, 6);
+        let image = render(&layers, 25, 6);
+        assert_eq!(ocr::recognize(&image), Ok("CFLUL".to_string()));
+    }
+}
@@ -0,0 +1,33 @@
+use aoc_common::{print_answers, Part, Solution};
+use intcode::{Int, Program};
+
+fn boost(program: &Program, mode: Int) -> Int {
+    let mut program = program.clone();
+    program.input(mode);
+    intcode::run(&mut program);
+    program.output().unwrap()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Program;
+    type Answer1 = Int;
+    type Answer2 = Int;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.parse().unwrap()
+    }
+
+    fn part_1(program: &Self::Input<'_>) -> Self::Answer1 {
+        boost(program, 1)
+    }
+
+    fn part_2(program: &Self::Input<'_>) -> Self::Answer2 {
+        boost(program, 2)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
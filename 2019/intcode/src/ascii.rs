@@ -0,0 +1,192 @@
+use crate::{try_step, Int, Program, ProgramError, ProgramState};
+use std::io::{self, BufRead, Write};
+
+pub fn is_ascii(value: Int) -> bool {
+    (0..128).contains(&value)
+}
+
+#[derive(Clone, Debug)]
+pub struct Ascii {
+    pub program: Program,
+    pub values: Vec<Int>,
+    pub state: ProgramState,
+    pub error: Option<ProgramError>,
+    pub step_limit: Option<usize>,
+    steps: usize,
+}
+
+impl From<Program> for Ascii {
+    fn from(program: Program) -> Self {
+        Ascii {
+            program,
+            values: vec![],
+            state: ProgramState::Running,
+            error: None,
+            step_limit: None,
+            steps: 0,
+        }
+    }
+}
+
+impl Ascii {
+    pub fn send(&mut self, text: &str) {
+        self.program.inputs.extend(text.bytes().map(Int::from));
+    }
+
+    pub fn send_line(&mut self, line: &str) {
+        self.send(line);
+        self.program.input(b'\n'.into());
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        loop {
+            if let Some(value) = self.program.outputs.pop_front() {
+                if is_ascii(value) {
+                    return Some(value as u8 as char);
+                }
+
+                self.values.push(value);
+                continue;
+            }
+
+            if self.step_limit.is_some_and(|limit| self.steps >= limit) {
+                self.state = ProgramState::Running;
+                return None;
+            }
+
+            self.steps += 1;
+            self.state = match try_step(&mut self.program) {
+                Ok(state) => state,
+                Err(error) => {
+                    self.error = Some(error);
+                    ProgramState::Stop
+                }
+            };
+
+            if let ProgramState::Input | ProgramState::Stop = self.state {
+                return None;
+            }
+        }
+    }
+
+    pub fn read_line(&mut self) -> Option<String> {
+        self.steps = 0;
+        let mut line = String::new();
+        while let Some(chr) = self.next_char() {
+            if chr == '\n' {
+                return Some(line);
+            }
+            line.push(chr);
+        }
+
+        Some(line).filter(|line| !line.is_empty())
+    }
+
+    pub fn read_until(&mut self, prompt: &str) -> String {
+        self.steps = 0;
+        let mut text = String::new();
+        while let Some(chr) = self.next_char() {
+            text.push(chr);
+            if text.ends_with(prompt) {
+                break;
+            }
+        }
+
+        text
+    }
+
+    pub fn read_all(&mut self) -> String {
+        self.steps = 0;
+        let mut text = String::new();
+        while let Some(chr) = self.next_char() {
+            text.push(chr);
+        }
+
+        text
+    }
+
+    pub fn interact<R: BufRead, W: Write>(
+        &mut self,
+        mut input: R,
+        mut output: W,
+    ) -> io::Result<ProgramState> {
+        loop {
+            let text = self.read_all();
+            write!(output, "{}", text)?;
+            for value in self.values.drain(..) {
+                writeln!(output, "{}", value)?;
+            }
+            output.flush()?;
+
+            if self.state != ProgramState::Input {
+                return Ok(self.state);
+            }
+
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                return Ok(self.state);
+            }
+            self.send_line(line.trim_end_matches(&['\r', '\n'][..]));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assemble;
+
+    const GREETER: &str = "
+              arb #text
+        print: jz  $0, #read
+              out $0
+              arb #1
+              jnz #1, #print
+        read: in  @value
+              eq  @value, #10, @done
+              jnz @done, #end
+              out @value
+              jnz #1, #read
+        end:  out #10
+              out #1000
+              hlt
+        value: data 0
+        done: data 0
+        text: data 72, 105, 10, 62, 32, 0
+    ";
+
+    fn greeter() -> Ascii {
+        Ascii::from(Program::from(assemble(GREETER).unwrap()))
+    }
+
+    #[test]
+    fn test_read() {
+        let mut ascii = greeter();
+        assert_eq!(ascii.read_line(), Some("Hi".to_string()));
+        assert_eq!(ascii.read_until("> "), "> ");
+        assert_eq!(ascii.read_all(), "");
+        assert_eq!(ascii.state, ProgramState::Input);
+
+        ascii.send_line("abc");
+        assert_eq!(ascii.read_all(), "abc\n");
+        assert_eq!(ascii.state, ProgramState::Stop);
+        assert_eq!(ascii.values, vec![1000]);
+    }
+
+    #[test]
+    fn test_interact() {
+        let mut output = vec![];
+        let state = greeter().interact(&b"xy\n"[..], &mut output).unwrap();
+
+        assert_eq!(state, ProgramState::Stop);
+        assert_eq!(String::from_utf8(output).unwrap(), "Hi\n> xy\n1000\n");
+    }
+
+    #[test]
+    fn test_step_limit() {
+        let mut ascii = greeter();
+        ascii.step_limit = Some(3);
+        assert_eq!(ascii.read_all(), "H");
+        assert_eq!(ascii.state, ProgramState::Running);
+    }
+}
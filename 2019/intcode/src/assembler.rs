@@ -0,0 +1,319 @@
+use crate::{operation_by_mnemonic, Int, Memory, ParameterMode};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum AssemblyErrorKind {
+    UnknownMnemonic(String),
+    ParameterCount(String, usize, usize),
+    InvalidOperand(String),
+    InvalidLabel(String),
+    DuplicateLabel(String),
+    UndefinedLabel(String),
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct AssemblyError {
+    pub line: usize,
+    pub kind: AssemblyErrorKind,
+}
+
+impl Display for AssemblyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            AssemblyErrorKind::UnknownMnemonic(mnemonic) => {
+                write!(f, "unknown mnemonic '{}'", mnemonic)
+            }
+            AssemblyErrorKind::ParameterCount(mnemonic, expected, found) => {
+                write!(
+                    f,
+                    "'{}' takes {} parameters, found {}",
+                    mnemonic, expected, found
+                )
+            }
+            AssemblyErrorKind::InvalidOperand(operand) => {
+                write!(f, "invalid operand '{}'", operand)
+            }
+            AssemblyErrorKind::InvalidLabel(label) => {
+                write!(f, "invalid label '{}'", label)
+            }
+            AssemblyErrorKind::DuplicateLabel(label) => {
+                write!(f, "label '{}' is defined twice", label)
+            }
+            AssemblyErrorKind::UndefinedLabel(label) => {
+                write!(f, "label '{}' is not defined", label)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AssemblyError {}
+
+#[derive(Debug)]
+enum Operand {
+    Number(Int),
+    Label(String),
+}
+
+#[derive(Debug)]
+enum Item {
+    Instruction(Int, Vec<(ParameterMode, Operand)>),
+    Data(Vec<Operand>),
+}
+
+#[derive(Debug)]
+struct Statement {
+    line: usize,
+    item: Item,
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+fn parse_operand(s: &str) -> Option<Operand> {
+    if let Ok(number) = s.parse() {
+        Some(Operand::Number(number))
+    } else if is_identifier(s) {
+        Some(Operand::Label(s.to_string()))
+    } else {
+        None
+    }
+}
+
+fn parse_parameter(s: &str) -> Option<(ParameterMode, Operand)> {
+    let mode = match s.chars().next()? {
+        '@' => ParameterMode::Address,
+        '#' => ParameterMode::Value,
+        '$' => ParameterMode::Relative,
+        _ => return None,
+    };
+
+    parse_operand(&s[1..]).map(|operand| (mode, operand))
+}
+
+fn parse_item(code: &str) -> Result<Item, AssemblyErrorKind> {
+    let (mnemonic, rest) = match code.find(char::is_whitespace) {
+        Some(index) => (&code[..index], code[index..].trim()),
+        None => (code, ""),
+    };
+
+    let operands = if rest.is_empty() {
+        vec![]
+    } else {
+        rest.split(',').map(str::trim).collect()
+    };
+
+    if mnemonic == "data" {
+        return operands
+            .into_iter()
+            .map(|operand| {
+                parse_operand(operand).ok_or_else(|| {
+                    AssemblyErrorKind::InvalidOperand(operand.to_string())
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map(Item::Data);
+    }
+
+    let (code, operation) =
+        operation_by_mnemonic(mnemonic).ok_or_else(|| {
+            AssemblyErrorKind::UnknownMnemonic(mnemonic.to_string())
+        })?;
+
+    let number_parameters = operation.number_parameters();
+    if operands.len() != number_parameters {
+        return Err(AssemblyErrorKind::ParameterCount(
+            mnemonic.to_string(),
+            number_parameters,
+            operands.len(),
+        ));
+    }
+
+    operands
+        .into_iter()
+        .map(|operand| {
+            parse_parameter(operand).ok_or_else(|| {
+                AssemblyErrorKind::InvalidOperand(operand.to_string())
+            })
+        })
+        .collect::<Result<_, _>>()
+        .map(|parameters| Item::Instruction(code, parameters))
+}
+
+impl Item {
+    fn len(&self) -> usize {
+        match self {
+            Item::Instruction(_, parameters) => parameters.len() + 1,
+            Item::Data(values) => values.len(),
+        }
+    }
+}
+
+fn parse_statements(
+    source: &str,
+) -> Result<(Vec<Statement>, HashMap<String, Int>), AssemblyError> {
+    let mut statements = vec![];
+    let mut labels = HashMap::new();
+    let mut address = 0;
+
+    for (index, source_line) in source.lines().enumerate() {
+        let line = index + 1;
+        let error = |kind| AssemblyError { line, kind };
+
+        let mut code = source_line.split(';').next().unwrap().trim();
+
+        while let Some(colon_index) = code.find(':') {
+            let label = code[..colon_index].trim();
+            if !is_identifier(label) {
+                return Err(error(AssemblyErrorKind::InvalidLabel(
+                    label.to_string(),
+                )));
+            }
+
+            if labels.insert(label.to_string(), address).is_some() {
+                return Err(error(AssemblyErrorKind::DuplicateLabel(
+                    label.to_string(),
+                )));
+            }
+
+            code = code[colon_index + 1..].trim();
+        }
+
+        if code.is_empty() {
+            continue;
+        }
+
+        let item = parse_item(code).map_err(error)?;
+        address += item.len() as Int;
+        statements.push(Statement { line, item });
+    }
+
+    Ok((statements, labels))
+}
+
+pub fn assemble(source: &str) -> Result<Memory, AssemblyError> {
+    let (statements, labels) = parse_statements(source)?;
+    let mut memory = Memory::new();
+
+    for statement in statements {
+        let resolve = |operand: &Operand| match operand {
+            Operand::Number(number) => Ok(*number),
+            Operand::Label(label) => {
+                labels.get(label).copied().ok_or_else(|| AssemblyError {
+                    line: statement.line,
+                    kind: AssemblyErrorKind::UndefinedLabel(label.clone()),
+                })
+            }
+        };
+
+        match &statement.item {
+            Item::Instruction(operation_code, parameters) => {
+                let mut instruction_value = *operation_code;
+                let mut parameter_mask = 100;
+                for (mode, _) in parameters {
+                    instruction_value += *mode as Int * parameter_mask;
+                    parameter_mask *= 10;
+                }
+
+                memory.push(instruction_value);
+                for (_, operand) in parameters {
+                    memory.push(resolve(operand)?);
+                }
+            }
+            Item::Data(values) => {
+                for operand in values {
+                    memory.push(resolve(operand)?);
+                }
+            }
+        }
+    }
+
+    Ok(memory)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{disassemble, Program};
+
+    fn round_trip(content: &str) {
+        let program: Program = content.parse().unwrap();
+        let listing = disassemble(&program).to_string();
+        assert_eq!(assemble(&listing), Ok(program.memory.to_vec()));
+    }
+
+    #[test]
+    fn test_assemble() {
+        let source = "
+            start:  in    @result       ; read a value
+                    mul   @result, #3, @result
+                    out   @result
+                    jnz   #1, #end
+            result: data  0
+            end:    hlt
+        ";
+
+        assert_eq!(
+            assemble(source),
+            Ok(vec![3, 11, 1002, 11, 3, 11, 4, 11, 1105, 1, 12, 0, 99])
+        );
+    }
+
+    #[test]
+    fn test_relative_parameters() {
+        let source = "arb #-7\nadd $1, #2, $-3";
+        assert_eq!(assemble(source), Ok(vec![109, -7, 21201, 1, 2, -3]));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |line, kind| Err(AssemblyError { line, kind });
+
+        assert_eq!(
+            assemble("hlt\nmove @1"),
+            error(2, AssemblyErrorKind::UnknownMnemonic("move".to_string()))
+        );
+        assert_eq!(
+            assemble("add @1, @2"),
+            error(
+                1,
+                AssemblyErrorKind::ParameterCount("add".to_string(), 3, 2)
+            )
+        );
+        assert_eq!(
+            assemble("out 5"),
+            error(1, AssemblyErrorKind::InvalidOperand("5".to_string()))
+        );
+        assert_eq!(
+            assemble("a: hlt\na: hlt"),
+            error(2, AssemblyErrorKind::DuplicateLabel("a".to_string()))
+        );
+        assert_eq!(
+            assemble("jz #0, #nowhere"),
+            error(1, AssemblyErrorKind::UndefinedLabel("nowhere".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        round_trip("1002,4,3,4,99,109,19,204,-34");
+        round_trip("3,9,1006,9,0,1105,1,2,99,0");
+        round_trip("1105,1,12,-5,42,10099,300,0,0,0,0,0,99,1");
+        round_trip("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99");
+        round_trip(
+            "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,\
+             98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,\
+             1000,1,20,4,20,1105,1,46,98,99",
+        );
+    }
+}
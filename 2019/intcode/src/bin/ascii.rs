@@ -0,0 +1,20 @@
+use intcode::{Ascii, Program};
+use std::env;
+use std::fs;
+use std::io;
+
+fn main() {
+    let filename = env::args().nth(1).expect("Usage: ascii <program file>");
+
+    let content = fs::read_to_string(filename).unwrap();
+    let program: Program = content.parse().unwrap();
+    let mut ascii = Ascii::from(program);
+
+    let stdin = io::stdin();
+    let state = ascii.interact(stdin.lock(), io::stdout()).unwrap();
+    if let Some(error) = ascii.error {
+        eprintln!("Program error at {}", error);
+    } else {
+        eprintln!("Program ended in state {:?}", state);
+    }
+}
@@ -0,0 +1,14 @@
+use intcode::assemble;
+use itertools::Itertools;
+use std::env;
+use std::fs;
+
+fn main() {
+    let filename = env::args().nth(1).expect("Usage: assemble <assembly file>");
+
+    let source = fs::read_to_string(filename).unwrap();
+    match assemble(&source) {
+        Ok(memory) => println!("{}", memory.into_iter().join(",")),
+        Err(error) => eprintln!("{}", error),
+    }
+}
@@ -0,0 +1,236 @@
+use intcode::{run_outputs, Address, Engine, Int, Program, ProgramState};
+use itertools::Itertools;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+const SAMPLES: usize = 5;
+const COMPUTERS: usize = 50;
+
+trait Runner: Clone {
+    fn new(program: Program) -> Self;
+    fn input(&mut self, input: Int);
+    fn run_outputs(&mut self) -> ProgramState;
+    fn take_outputs(&mut self) -> Vec<Int>;
+    fn write(&mut self, address: Address, value: Int);
+}
+
+impl Runner for Program {
+    fn new(program: Program) -> Self {
+        program
+    }
+
+    fn input(&mut self, input: Int) {
+        Program::input(self, input)
+    }
+
+    fn run_outputs(&mut self) -> ProgramState {
+        run_outputs(self)
+    }
+
+    fn take_outputs(&mut self) -> Vec<Int> {
+        self.outputs.drain(..).collect()
+    }
+
+    fn write(&mut self, address: Address, value: Int) {
+        self.memory.write(address, value)
+    }
+}
+
+impl Runner for Engine {
+    fn new(program: Program) -> Self {
+        Engine::from(program)
+    }
+
+    fn input(&mut self, input: Int) {
+        Engine::input(self, input)
+    }
+
+    fn run_outputs(&mut self) -> ProgramState {
+        Engine::run_outputs(self)
+    }
+
+    fn take_outputs(&mut self) -> Vec<Int> {
+        Engine::take_outputs(self)
+    }
+
+    fn write(&mut self, address: Address, value: Int) {
+        Engine::write(self, address, value)
+    }
+}
+
+fn single_run<R: Runner>(runner: &R, input: Int) -> Int {
+    let mut runner = runner.clone();
+    runner.input(input);
+    runner.run_outputs();
+    runner.take_outputs().pop().unwrap()
+}
+
+fn day_5<R: Runner>(runner: &R) -> Int {
+    single_run(runner, 5)
+}
+
+fn day_9<R: Runner>(runner: &R) -> Int {
+    single_run(runner, 2)
+}
+
+fn day_13<R: Runner>(runner: &R) -> Int {
+    let mut runner = runner.clone();
+    runner.write(0, 2);
+
+    let (mut score, mut ball, mut paddle) = (0, 0, 0);
+    while runner.run_outputs() == ProgramState::Input {
+        for (col, row, id) in runner.take_outputs().into_iter().tuples() {
+            match (col, row, id) {
+                (-1, 0, _) => score = id,
+                (_, _, 3) => paddle = col,
+                (_, _, 4) => ball = col,
+                _ => {}
+            }
+        }
+        runner.input((ball - paddle).signum());
+    }
+
+    let outputs = runner.take_outputs();
+    outputs.chunks(3).fold(score, |score, chunk| match chunk {
+        [-1, 0, value] => *value,
+        _ => score,
+    })
+}
+
+fn day_19<R: Runner>(runner: &R) -> Int {
+    (0..50)
+        .cartesian_product(0..50)
+        .map(|(x, y)| {
+            let mut runner = runner.clone();
+            runner.input(x);
+            runner.input(y);
+            runner.run_outputs();
+            runner.take_outputs()[0]
+        })
+        .sum()
+}
+
+fn day_23<R: Runner>(runner: &R) -> Int {
+    let mut machines = (0..COMPUTERS as Int)
+        .map(|address| {
+            let mut machine = runner.clone();
+            machine.input(address);
+            machine
+        })
+        .collect::<Vec<_>>();
+    let mut queues = vec![vec![]; COMPUTERS];
+    let (mut nat, mut sent) = (None, None);
+
+    loop {
+        let mut idle = true;
+        for (machine, queue) in machines.iter_mut().zip(queues.iter_mut()) {
+            if queue.is_empty() {
+                machine.input(-1);
+            }
+            for value in queue.drain(..) {
+                machine.input(value);
+            }
+        }
+
+        for machine in machines.iter_mut() {
+            machine.run_outputs();
+            for (destination, x, y) in
+                machine.take_outputs().into_iter().tuples()
+            {
+                idle = false;
+                match queues.get_mut(destination as usize) {
+                    Some(queue) => queue.extend([x, y]),
+                    None => nat = Some((x, y)),
+                }
+            }
+        }
+
+        if idle {
+            let (x, y) = nat.unwrap();
+            if sent == Some(y) {
+                return y;
+            }
+            sent = Some(y);
+            queues[0].extend([x, y]);
+        }
+    }
+}
+
+fn time<R: Runner>(program: &Program, workload: fn(&R) -> Int) -> Timing {
+    let runner = R::new(program.clone());
+    let result = workload(&runner);
+    let duration = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            workload(&runner);
+            start.elapsed()
+        })
+        .min()
+        .unwrap();
+
+    (result, duration)
+}
+
+type Timing = (Int, Duration);
+type Workload = fn(&Program) -> Timing;
+
+fn main() {
+    let root = env::args().nth(1).unwrap_or_else(|| "..".to_string());
+    let workloads: [(usize, Workload, Workload); 5] = [
+        (
+            5,
+            |p| time(p, day_5::<Program>),
+            |p| time(p, day_5::<Engine>),
+        ),
+        (
+            9,
+            |p| time(p, day_9::<Program>),
+            |p| time(p, day_9::<Engine>),
+        ),
+        (
+            13,
+            |p| time(p, day_13::<Program>),
+            |p| time(p, day_13::<Engine>),
+        ),
+        (
+            19,
+            |p| time(p, day_19::<Program>),
+            |p| time(p, day_19::<Engine>),
+        ),
+        (
+            23,
+            |p| time(p, day_23::<Program>),
+            |p| time(p, day_23::<Engine>),
+        ),
+    ];
+
+    println!(
+        "{:<8} {:>14} {:>14} {:>8}",
+        "day", "interpreter", "engine", "speedup"
+    );
+    for (day, interpreter, engine) in workloads.iter() {
+        let path = Path::new(&root).join(format!("day-{}/res/input.txt", day));
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(error) => {
+                println!("{:<8} {}: {}", day, path.display(), error);
+                continue;
+            }
+        };
+        let program: Program = content.parse().unwrap();
+
+        let (expected, interpreter_time) = interpreter(&program);
+        let (result, engine_time) = engine(&program);
+        assert_eq!(result, expected, "engine disagrees on day {}", day);
+
+        println!(
+            "{:<8} {:>12.2?} {:>12.2?} {:>7.1}x",
+            day,
+            interpreter_time,
+            engine_time,
+            interpreter_time.as_secs_f64() / engine_time.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use intcode::{
+    disassemble_range, mnemonic, operation_code, Address, Debugger, Int, Pause,
+    Program,
+};
+use itertools::Itertools;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+
+const HELP: &str = "\
+step [n]           s   execute n instructions (default 1)
+continue           c   run until a breakpoint, watchpoint, input or stop
+break <address>    b   toggle a breakpoint on an address
+opcode <op>        bo  toggle a breakpoint on an opcode or mnemonic
+watch <address>    w   toggle a watchpoint on a memory cell
+info               i   show registers, queues and breakpoints
+list [n]           l   disassemble n instructions from the pointer
+memory <addr> [n]  x   show n memory cells from an address
+set <addr> <value>     write a value into memory
+input <values...>      queue integer inputs
+ascii <text>           queue a line of text as ASCII inputs
+output             o   print and clear the output queue
+save <file>            write a snapshot of the program to a file
+load <file>            replace the program with a snapshot from a file
+quit               q   leave the debugger";
+
+fn format_pause(pause: &Pause) -> String {
+    match pause {
+        Pause::Step => "Stepped".to_string(),
+        Pause::Breakpoint(address) => format!("Breakpoint at {}", address),
+        Pause::Opcode(code) => format!(
+            "Opcode breakpoint on {}",
+            mnemonic(*code).unwrap_or("unknown")
+        ),
+        Pause::Watchpoint(address, old, new) => {
+            format!("Watchpoint at {}: {} -> {}", address, old, new)
+        }
+        Pause::Input => "Waiting for input".to_string(),
+        Pause::Stop => "Program stopped".to_string(),
+        Pause::Error(error) => format!("Program error at {}", error),
+    }
+}
+
+fn format_outputs(outputs: &[Int]) -> String {
+    let ascii = outputs.iter().all(|output| (1..128).contains(output));
+
+    if ascii {
+        outputs.iter().map(|output| *output as u8 as char).collect()
+    } else {
+        outputs.iter().join(",")
+    }
+}
+
+fn print_info(debugger: &Debugger) {
+    let program = &debugger.program;
+    println!(
+        "ip={} rb={} steps={} memory={} bytes",
+        program.instruction_pointer(),
+        program.relative_base(),
+        debugger.steps,
+        program.memory.footprint()
+    );
+    println!(
+        "pages: [{}]",
+        program.memory.touched_pages().iter().join(",")
+    );
+    println!("inputs:  [{}]", program.inputs.iter().join(","));
+    println!("outputs: [{}]", program.outputs.iter().join(","));
+    println!("breakpoints: [{}]", debugger.breakpoints.iter().join(","));
+    println!(
+        "opcode breakpoints: [{}]",
+        debugger
+            .opcode_breakpoints
+            .iter()
+            .map(|code| mnemonic(*code).unwrap_or("?"))
+            .join(",")
+    );
+    println!(
+        "watchpoints: [{}]",
+        debugger
+            .watchpoints
+            .iter()
+            .map(|address| format!("{}={}", address, debugger.read(*address)))
+            .join(",")
+    );
+}
+
+fn print_listing(debugger: &Debugger, count: usize) {
+    let program = &debugger.program;
+    let listing =
+        disassemble_range(program, program.instruction_pointer(), count);
+    print!("{}", listing);
+}
+
+fn parse_address(argument: Option<&str>) -> Option<Address> {
+    argument.and_then(|argument| argument.parse().ok())
+}
+
+fn execute(debugger: &mut Debugger, line: &str) -> bool {
+    let mut words = line.split_whitespace();
+    let command = words.next().unwrap_or("step");
+    let argument = words.next();
+
+    match command {
+        "s" | "step" => {
+            let count = argument.and_then(|a| a.parse().ok()).unwrap_or(1);
+            let mut pause = Pause::Step;
+            for _ in 0..count {
+                pause = debugger.step();
+                if pause != Pause::Step {
+                    break;
+                }
+            }
+            println!("{}", format_pause(&pause));
+            print_listing(debugger, 1);
+        }
+        "c" | "continue" => {
+            let pause = debugger.resume();
+            println!("{}", format_pause(&pause));
+            print_listing(debugger, 1);
+        }
+        "b" | "break" => match parse_address(argument) {
+            Some(address) => {
+                let set = debugger.toggle_breakpoint(address);
+                println!("Breakpoint at {} {}", address, on_off(set));
+            }
+            None => println!("Usage: break <address>"),
+        },
+        "bo" | "opcode" => {
+            let code = argument.and_then(|argument| {
+                argument.parse().ok().or_else(|| operation_code(argument))
+            });
+            match code {
+                Some(code) => {
+                    let set = debugger.toggle_opcode_breakpoint(code);
+                    println!("Opcode breakpoint on {} {}", code, on_off(set));
+                }
+                None => println!("Usage: opcode <code or mnemonic>"),
+            }
+        }
+        "w" | "watch" => match parse_address(argument) {
+            Some(address) => {
+                let set = debugger.toggle_watchpoint(address);
+                println!("Watchpoint at {} {}", address, on_off(set));
+            }
+            None => println!("Usage: watch <address>"),
+        },
+        "i" | "info" => print_info(debugger),
+        "l" | "list" => {
+            let count = argument.and_then(|a| a.parse().ok()).unwrap_or(10);
+            print_listing(debugger, count);
+        }
+        "x" | "memory" => match parse_address(argument) {
+            Some(address) => {
+                let count = parse_address(words.next()).unwrap_or(8);
+                match address.checked_add(count) {
+                    Some(end) => {
+                        let values = (address..end)
+                            .map(|address| debugger.read(address))
+                            .join(",");
+                        println!("{}: {}", address, values);
+                    }
+                    None => println!("Range past the end of memory"),
+                }
+            }
+            None => println!("Usage: memory <address> [count]"),
+        },
+        "set" => {
+            let value = words.next().and_then(|value| value.parse().ok());
+            match (parse_address(argument), value) {
+                (Some(address), Some(value)) => debugger.write(address, value),
+                _ => println!("Usage: set <address> <value>"),
+            }
+        }
+        "input" => {
+            let inputs: Result<Vec<Int>, _> =
+                line.split_whitespace().skip(1).map(str::parse).collect();
+            match inputs {
+                Ok(inputs) => debugger.program.inputs.extend(inputs),
+                Err(_) => println!("Usage: input <values...>"),
+            }
+        }
+        "ascii" => {
+            let text = line.trim_start()["ascii".len()..].trim_start();
+            let bytes = text.bytes().chain(std::iter::once(b'\n'));
+            debugger.program.inputs.extend(bytes.map(Int::from));
+        }
+        "o" | "output" => {
+            let outputs = debugger.program.outputs.drain(..).collect_vec();
+            println!("{}", format_outputs(&outputs));
+        }
+        "save" => match argument {
+            Some(filename) => match debugger.program.save(filename) {
+                Ok(()) => println!("Saved snapshot to {}", filename),
+                Err(error) => println!("Could not save snapshot: {}", error),
+            },
+            None => println!("Usage: save <file>"),
+        },
+        "load" => match argument.map(Program::load) {
+            Some(Ok(program)) => {
+                debugger.program = program;
+                print_listing(debugger, 1);
+            }
+            Some(Err(error)) => println!("Could not load snapshot: {}", error),
+            None => println!("Usage: load <file>"),
+        },
+        "q" | "quit" => return false,
+        _ => println!("{}", HELP),
+    }
+
+    true
+}
+
+fn on_off(set: bool) -> &'static str {
+    if set {
+        "set"
+    } else {
+        "removed"
+    }
+}
+
+fn main() {
+    let filename = env::args().nth(1).expect("Usage: debug <program file>");
+
+    let content = fs::read_to_string(filename).unwrap();
+    let program: Program = content.parse().unwrap();
+    let mut debugger = Debugger::new(program);
+
+    print_listing(&debugger, 1);
+
+    let stdin = io::stdin();
+    loop {
+        print!("(intcode) ");
+        io::stdout().flush().unwrap();
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap() == 0 {
+            break;
+        }
+
+        if !execute(&mut debugger, line.trim()) {
+            break;
+        }
+    }
+}
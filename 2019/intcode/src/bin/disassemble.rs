@@ -0,0 +1,14 @@
+use intcode::{disassemble, Program};
+use std::env;
+use std::fs;
+
+fn main() {
+    let filename = env::args()
+        .nth(1)
+        .expect("Usage: disassemble <program file>");
+
+    let content = fs::read_to_string(filename).unwrap();
+    let program: Program = content.parse().unwrap();
+
+    print!("{}", disassemble(&program));
+}
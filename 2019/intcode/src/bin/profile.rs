@@ -0,0 +1,41 @@
+use intcode::{Int, Profiler, Program};
+use itertools::Itertools;
+use std::env;
+use std::fs::{self, File};
+use std::io::BufWriter;
+
+fn main() {
+    let args = env::args().skip(1).collect_vec();
+    let usage = "Usage: profile <program file> [--trace <file>] [inputs...]";
+    let filename = args.first().expect(usage);
+
+    let (trace_filename, inputs) = match args.get(1).map(String::as_str) {
+        Some("--trace") => (Some(args.get(2).expect(usage)), &args[3..]),
+        _ => (None, &args[1..]),
+    };
+
+    let content = fs::read_to_string(filename).unwrap();
+    let mut program: Program = content.parse().unwrap();
+    for input in inputs {
+        program.input(input.parse::<Int>().expect(usage));
+    }
+
+    let profile = match trace_filename {
+        Some(trace_filename) => {
+            let file = BufWriter::new(File::create(trace_filename).unwrap());
+            let mut profiler = Profiler::with_trace(file);
+            let state = profiler.run_outputs(&mut program).unwrap();
+            println!("State: {:?}", state);
+            profiler.profile
+        }
+        None => {
+            let mut profiler = Profiler::new();
+            let state = profiler.run_outputs(&mut program).unwrap();
+            println!("State: {:?}", state);
+            profiler.profile
+        }
+    };
+
+    println!("Outputs: {}", program.outputs.iter().join(","));
+    print!("{}", profile);
+}
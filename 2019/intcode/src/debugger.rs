@@ -0,0 +1,183 @@
+use crate::{try_step, Address, Int, Program, ProgramError, ProgramState};
+use std::collections::BTreeSet;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Pause {
+    Step,
+    Breakpoint(Address),
+    Opcode(Int),
+    Watchpoint(Address, Int, Int),
+    Input,
+    Stop,
+    Error(ProgramError),
+}
+
+#[derive(Clone, Debug)]
+pub struct Debugger {
+    pub program: Program,
+    pub breakpoints: BTreeSet<Address>,
+    pub opcode_breakpoints: BTreeSet<Int>,
+    pub watchpoints: BTreeSet<Address>,
+    pub steps: usize,
+}
+
+fn toggle<T: Ord>(set: &mut BTreeSet<T>, value: T) -> bool {
+    if set.remove(&value) {
+        false
+    } else {
+        set.insert(value);
+        true
+    }
+}
+
+impl Debugger {
+    pub fn new(program: Program) -> Debugger {
+        Debugger {
+            program,
+            breakpoints: BTreeSet::new(),
+            opcode_breakpoints: BTreeSet::new(),
+            watchpoints: BTreeSet::new(),
+            steps: 0,
+        }
+    }
+
+    pub fn toggle_breakpoint(&mut self, address: Address) -> bool {
+        toggle(&mut self.breakpoints, address)
+    }
+
+    pub fn toggle_opcode_breakpoint(&mut self, operation_code: Int) -> bool {
+        toggle(&mut self.opcode_breakpoints, operation_code)
+    }
+
+    pub fn toggle_watchpoint(&mut self, address: Address) -> bool {
+        toggle(&mut self.watchpoints, address)
+    }
+
+    pub fn read(&self, address: Address) -> Int {
+        self.program.memory.read(address)
+    }
+
+    pub fn write(&mut self, address: Address, value: Int) {
+        self.program.memory.write(address, value);
+    }
+
+    pub fn step(&mut self) -> Pause {
+        let watched_values = self
+            .watchpoints
+            .iter()
+            .map(|&address| (address, self.read(address)))
+            .collect::<Vec<_>>();
+
+        match try_step(&mut self.program) {
+            Ok(ProgramState::Input) => return Pause::Input,
+            Ok(ProgramState::Stop) => return Pause::Stop,
+            Ok(ProgramState::Running | ProgramState::Output) => {}
+            Err(error) => return Pause::Error(error),
+        }
+
+        self.steps += 1;
+
+        for (address, old_value) in watched_values {
+            let new_value = self.read(address);
+            if old_value != new_value {
+                return Pause::Watchpoint(address, old_value, new_value);
+            }
+        }
+
+        Pause::Step
+    }
+
+    pub fn resume(&mut self) -> Pause {
+        loop {
+            let pause = self.step();
+            if pause != Pause::Step {
+                return pause;
+            }
+
+            let instruction_pointer = self.program.instruction_pointer();
+            if self.breakpoints.contains(&instruction_pointer) {
+                return Pause::Breakpoint(instruction_pointer);
+            }
+
+            let operation_code = self.read(instruction_pointer) % 100;
+            if self.opcode_breakpoints.contains(&operation_code) {
+                return Pause::Opcode(operation_code);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assemble;
+
+    fn debugger(source: &str) -> Debugger {
+        Debugger::new(Program::from(assemble(source).unwrap()))
+    }
+
+    const COUNTDOWN: &str = "
+        loop: add @counter, #-1, @counter
+              out @counter
+              jnz @counter, #loop
+              in  @counter
+              hlt
+        counter: data 3
+    ";
+
+    #[test]
+    fn test_step() {
+        let mut debugger = debugger(COUNTDOWN);
+        assert_eq!(debugger.step(), Pause::Step);
+        assert_eq!(debugger.program.instruction_pointer(), 4);
+        assert_eq!(debugger.read(12), 2);
+        assert_eq!(debugger.steps, 1);
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut debugger = debugger(COUNTDOWN);
+        assert!(debugger.toggle_breakpoint(4));
+        assert_eq!(debugger.resume(), Pause::Breakpoint(4));
+        assert_eq!(debugger.resume(), Pause::Breakpoint(4));
+        assert_eq!(debugger.program.outputs, vec![2]);
+
+        assert!(!debugger.toggle_breakpoint(4));
+        assert!(debugger.toggle_opcode_breakpoint(3));
+        assert_eq!(debugger.resume(), Pause::Opcode(3));
+        assert_eq!(debugger.program.outputs, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn test_watchpoints() {
+        let mut debugger = debugger(COUNTDOWN);
+        debugger.toggle_watchpoint(12);
+        assert_eq!(debugger.resume(), Pause::Watchpoint(12, 3, 2));
+        assert_eq!(debugger.resume(), Pause::Watchpoint(12, 2, 1));
+
+        debugger.write(12, 5);
+        assert_eq!(debugger.resume(), Pause::Watchpoint(12, 5, 4));
+    }
+
+    #[test]
+    fn test_input() {
+        let mut debugger = debugger(COUNTDOWN);
+        assert_eq!(debugger.resume(), Pause::Input);
+        assert_eq!(debugger.resume(), Pause::Input);
+
+        debugger.program.input(7);
+        debugger.toggle_watchpoint(12);
+        assert_eq!(debugger.resume(), Pause::Watchpoint(12, 0, 7));
+        assert_eq!(debugger.resume(), Pause::Stop);
+    }
+
+    #[test]
+    fn test_error() {
+        let mut debugger = debugger("jnz #1, #-4");
+        let pause = debugger.resume();
+        assert!(
+            matches!(pause, Pause::Error(error) if error.instruction_pointer == 0)
+        );
+        assert_eq!(debugger.steps, 0);
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{
+    mnemonic, parse_parameters_modes, Address, Int, Operation, ParameterMode,
+    Program,
+};
+use std::{
+    collections::HashSet,
+    convert::TryFrom,
+    fmt::{Display, Formatter, Result},
+};
+
+const DATA_PER_LINE: usize = 8;
+
+#[derive(Debug)]
+struct DecodedInstruction {
+    instruction_value: Int,
+    operation_code: Int,
+    operation: Operation,
+    parameters: Vec<(ParameterMode, Int)>,
+}
+
+impl DecodedInstruction {
+    fn len(&self) -> usize {
+        self.parameters.len() + 1
+    }
+
+    fn jump_target(&self) -> Option<Address> {
+        match (&self.operation, self.parameters.get(1)) {
+            (Operation::Jump(_), Some((ParameterMode::Value, target)))
+                if *target >= 0 =>
+            {
+                Some(*target as Address)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Line {
+    Instruction(Address, DecodedInstruction),
+    Data(Address, Vec<Int>),
+}
+
+impl Line {
+    fn address(&self) -> Address {
+        match self {
+            Line::Instruction(address, _) => *address,
+            Line::Data(address, _) => *address,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Listing {
+    lines: Vec<Line>,
+    labels: HashSet<Address>,
+}
+
+fn decode_instruction(
+    memory: &[Int],
+    address: Address,
+) -> Option<DecodedInstruction> {
+    let instruction_value = memory[address];
+    if instruction_value < 0 {
+        return None;
+    }
+
+    let operation_code = instruction_value % 100;
+    let operation = Operation::try_from(operation_code).ok()?;
+    let number_parameters = operation.number_parameters();
+
+    let parameters_modes =
+        parse_parameters_modes(instruction_value, number_parameters)?;
+
+    let unused_modes =
+        instruction_value / (100 * 10_i64.pow(number_parameters as u32));
+    if unused_modes != 0 {
+        return None;
+    }
+
+    let values = memory.get(address + 1..address + 1 + number_parameters)?;
+    let parameters = parameters_modes
+        .into_iter()
+        .zip(values.iter().copied())
+        .collect();
+
+    Some(DecodedInstruction {
+        instruction_value,
+        operation_code,
+        operation,
+        parameters,
+    })
+}
+
+fn decode_lines(memory: &[Int], start: Address, count: usize) -> Vec<Line> {
+    let mut lines = vec![];
+    let mut address = start;
+
+    while address < memory.len() && lines.len() < count {
+        match decode_instruction(memory, address) {
+            Some(instruction) => {
+                let length = instruction.len();
+                lines.push(Line::Instruction(address, instruction));
+                address += length;
+            }
+            None => {
+                lines.push(Line::Data(address, vec![memory[address]]));
+                address += 1;
+            }
+        }
+    }
+
+    lines
+}
+
+fn merge_data_lines(lines: Vec<Line>, labels: &HashSet<Address>) -> Vec<Line> {
+    let mut merged_lines: Vec<Line> = vec![];
+
+    for line in lines {
+        if let (Some(Line::Data(_, values)), Line::Data(address, value)) =
+            (merged_lines.last_mut(), &line)
+        {
+            if values.len() < DATA_PER_LINE && !labels.contains(address) {
+                values.extend(value);
+                continue;
+            }
+        }
+
+        merged_lines.push(line);
+    }
+
+    merged_lines
+}
+
+pub fn disassemble(program: &Program) -> Listing {
+    let lines = decode_lines(&program.memory, 0, usize::MAX);
+
+    let line_addresses: HashSet<_> = lines.iter().map(Line::address).collect();
+    let labels = lines
+        .iter()
+        .filter_map(|line| match line {
+            Line::Instruction(_, instruction) => instruction.jump_target(),
+            Line::Data(_, _) => None,
+        })
+        .filter(|target| line_addresses.contains(target))
+        .collect();
+
+    let lines = merge_data_lines(lines, &labels);
+
+    Listing { lines, labels }
+}
+
+pub fn disassemble_range(
+    program: &Program,
+    start: Address,
+    count: usize,
+) -> Listing {
+    let lines = decode_lines(&program.memory, start, count);
+
+    Listing {
+        lines,
+        labels: HashSet::new(),
+    }
+}
+
+fn label(address: Address) -> String {
+    format!("label_{}", address)
+}
+
+fn join<T: Display>(
+    values: impl Iterator<Item = T>,
+    separator: &str,
+) -> String {
+    values
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+impl Listing {
+    fn format_parameter(
+        &self,
+        instruction: &DecodedInstruction,
+        index: usize,
+    ) -> String {
+        let (mode, value) = instruction.parameters[index];
+        let prefix = match mode {
+            ParameterMode::Address => '@',
+            ParameterMode::Value => '#',
+            ParameterMode::Relative => '$',
+        };
+
+        match instruction.jump_target() {
+            Some(target) if index == 1 && self.labels.contains(&target) => {
+                format!("{}{}", prefix, label(target))
+            }
+            _ => format!("{}{}", prefix, value),
+        }
+    }
+
+    fn format_line(&self, line: &Line) -> (String, String) {
+        match line {
+            Line::Instruction(address, instruction) => {
+                let parameters = (0..instruction.parameters.len())
+                    .map(|index| self.format_parameter(instruction, index));
+                let code = format!(
+                    "{:<5} {}",
+                    mnemonic(instruction.operation_code).unwrap(),
+                    join(parameters, ", ")
+                );
+
+                let raw_values =
+                    instruction.parameters.iter().map(|(_, value)| *value);
+                let raw = std::iter::once(instruction.instruction_value)
+                    .chain(raw_values);
+                let comment = format!("{}: {}", address, join(raw, ","));
+
+                (code, comment)
+            }
+            Line::Data(address, values) => {
+                let code =
+                    format!("{:<5} {}", "data", join(values.iter(), ", "));
+                (code, address.to_string())
+            }
+        }
+    }
+}
+
+impl Display for Listing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for line in &self.lines {
+            if self.labels.contains(&line.address()) {
+                writeln!(f, "{}:", label(line.address()))?;
+            }
+
+            let (code, comment) = self.format_line(line);
+            writeln!(f, "    {:<36} ; {}", code.trim_end(), comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing(content: &str) -> Vec<String> {
+        let program: Program = content.parse().unwrap();
+        disassemble(&program)
+            .to_string()
+            .lines()
+            .map(|line| line.trim_end().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_parameter_modes() {
+        assert_eq!(
+            listing("1002,4,3,4,99,109,19,204,-34"),
+            vec![
+                "    mul   @4, #3, @4                     ; 0: 1002,4,3,4",
+                "    hlt                                  ; 4: 99",
+                "    arb   #19                            ; 5: 109,19",
+                "    out   $-34                           ; 7: 204,-34",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jump_labels() {
+        assert_eq!(
+            listing("3,9,1006,9,0,1105,1,2,99,0"),
+            vec![
+                "label_0:",
+                "    in    @9                             ; 0: 3,9",
+                "label_2:",
+                "    jz    @9, #label_0                   ; 2: 1006,9,0",
+                "    jnz   #1, #label_2                   ; 5: 1105,1,2",
+                "    hlt                                  ; 8: 99",
+                "    data  0                              ; 9",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_data_regions() {
+        assert_eq!(
+            listing("1105,1,12,-5,42,10099,300,0,0,0,0,0,99,1"),
+            vec![
+                "    jnz   #1, #label_12                  ; 0: 1105,1,12",
+                "    data  -5, 42, 10099, 300, 0, 0, 0, 0 ; 3",
+                "    data  0                              ; 11",
+                "label_12:",
+                "    hlt                                  ; 12: 99",
+                "    data  1                              ; 13",
+            ]
+        );
+    }
+}
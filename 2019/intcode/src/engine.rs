@@ -0,0 +1,438 @@
+use crate::memory::DIRECT_PAGES;
+use crate::{
+    parse_parameters_modes, Address, Inputs, Int, Operation, Outputs,
+    ParameterMode, Program, ProgramError, ProgramErrorKind, ProgramState,
+    PAGE_SIZE,
+};
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::rc::Rc;
+
+const MAX_PARAMETERS: usize = 3;
+
+#[derive(Clone, Copy, Debug)]
+struct Decoded {
+    operation_code: u8,
+    number_parameters: u8,
+    modes: [ParameterMode; MAX_PARAMETERS],
+}
+
+impl Decoded {
+    fn from_value(instruction_value: Int) -> Result<Decoded, ProgramErrorKind> {
+        let operation_code = instruction_value % 100;
+        let number_parameters =
+            Operation::try_from(operation_code)?.number_parameters();
+
+        let parameters_modes =
+            parse_parameters_modes(instruction_value, number_parameters)
+                .ok_or(ProgramErrorKind::InvalidMode(instruction_value))?;
+
+        let mut modes = [ParameterMode::Address; MAX_PARAMETERS];
+        modes[..number_parameters].copy_from_slice(&parameters_modes);
+
+        Ok(Decoded {
+            operation_code: operation_code as u8,
+            number_parameters: number_parameters as u8,
+            modes,
+        })
+    }
+}
+
+type CacheEntry = Cell<Option<(Int, Decoded)>>;
+
+#[derive(Clone, Debug)]
+struct Page {
+    values: [Int; PAGE_SIZE],
+    mapped: bool,
+}
+
+impl Default for Page {
+    fn default() -> Self {
+        Page {
+            values: [0; PAGE_SIZE],
+            mapped: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Engine {
+    instruction_pointer: Address,
+    relative_base: Int,
+    image_len: usize,
+    direct: Vec<Option<Rc<Page>>>,
+    far: HashMap<usize, Rc<Page>>,
+    cache: Rc<[CacheEntry]>,
+    inputs: Inputs,
+    outputs: Outputs,
+}
+
+impl From<Program> for Engine {
+    fn from(program: Program) -> Self {
+        let direct = program
+            .memory
+            .chunks(PAGE_SIZE)
+            .map(|chunk| {
+                let mut page = Page::default();
+                page.values[..chunk.len()].copy_from_slice(chunk);
+                Some(Rc::new(page))
+            })
+            .collect();
+
+        let mut engine = Engine {
+            instruction_pointer: program.instruction_pointer,
+            relative_base: program.relative_base,
+            image_len: program.memory.len(),
+            direct,
+            far: HashMap::new(),
+            cache: program.memory.iter().map(|_| Cell::new(None)).collect(),
+            inputs: program.inputs,
+            outputs: program.outputs,
+        };
+        for index in program.memory.touched_pages() {
+            Rc::make_mut(engine.page_mut(index)).mapped = true;
+        }
+        for (address, value) in program.memory.sparse_cells() {
+            engine.write(address, value);
+        }
+        engine
+    }
+}
+
+impl Engine {
+    pub fn to_program(&self) -> Program {
+        let image = (0..self.image_len).map(|address| self.read(address));
+        let mut program = Program::from(image.collect::<Vec<_>>());
+        program.instruction_pointer = self.instruction_pointer;
+        program.relative_base = self.relative_base;
+        program.inputs = self.inputs.clone();
+        program.outputs = self.outputs.clone();
+
+        let direct = self
+            .direct
+            .iter()
+            .enumerate()
+            .filter_map(|(index, page)| Some((index, page.as_ref()?)));
+        let far = self.far.iter().map(|(index, page)| (*index, page));
+        for (index, page) in direct.chain(far).filter(|(_, page)| page.mapped) {
+            program.memory.map_page(index);
+            for (offset, value) in page.values.iter().enumerate() {
+                let address = index * PAGE_SIZE + offset;
+                if address >= self.image_len {
+                    program.memory.write(address, *value);
+                }
+            }
+        }
+        program
+    }
+
+    pub fn instruction_pointer(&self) -> Address {
+        self.instruction_pointer
+    }
+
+    pub fn relative_base(&self) -> Int {
+        self.relative_base
+    }
+
+    pub fn input(&mut self, input: Int) {
+        self.inputs.push_back(input)
+    }
+
+    pub fn output(&mut self) -> Option<Int> {
+        self.outputs.pop_back()
+    }
+
+    pub fn take_outputs(&mut self) -> Vec<Int> {
+        self.outputs.drain(..).collect()
+    }
+
+    fn error(&self, kind: ProgramErrorKind) -> ProgramError {
+        ProgramError {
+            instruction_pointer: self.instruction_pointer,
+            kind,
+        }
+    }
+
+    fn page(&self, index: usize) -> Option<&Rc<Page>> {
+        if index < DIRECT_PAGES {
+            self.direct.get(index).and_then(Option::as_ref)
+        } else {
+            self.far.get(&index)
+        }
+    }
+
+    fn page_mut(&mut self, index: usize) -> &mut Rc<Page> {
+        if index < DIRECT_PAGES {
+            if self.direct.len() <= index {
+                self.direct.resize(index + 1, None);
+            }
+            self.direct[index].get_or_insert_with(Rc::default)
+        } else {
+            self.far.entry(index).or_default()
+        }
+    }
+
+    fn mapped_page(&self, address: Address) -> Option<&Rc<Page>> {
+        self.page(address / PAGE_SIZE)
+            .filter(|page| address < self.image_len || page.mapped)
+    }
+
+    pub fn is_mapped(&self, address: Address) -> bool {
+        self.mapped_page(address).is_some()
+    }
+
+    pub fn read(&self, address: Address) -> Int {
+        self.page(address / PAGE_SIZE)
+            .map(|page| page.values[address % PAGE_SIZE])
+            .unwrap_or_default()
+    }
+
+    pub fn write(&mut self, address: Address, value: Int) {
+        let mapped = address >= self.image_len;
+        let page = Rc::make_mut(self.page_mut(address / PAGE_SIZE));
+        let offset = address % PAGE_SIZE;
+        page.values[offset] = value;
+        page.mapped |= mapped;
+    }
+
+    fn decode(&self) -> Result<Decoded, ProgramError> {
+        let instruction_pointer = self.instruction_pointer;
+        let page = self
+            .mapped_page(instruction_pointer)
+            .ok_or_else(|| self.error(ProgramErrorKind::EndOfMemory))?;
+
+        let instruction_value = page.values[instruction_pointer % PAGE_SIZE];
+        let cached = self.cache.get(instruction_pointer);
+        if let Some((value, decoded)) = cached.and_then(Cell::get) {
+            if value == instruction_value {
+                return Ok(decoded);
+            }
+        }
+
+        let decoded = Decoded::from_value(instruction_value)
+            .map_err(|kind| self.error(kind))?;
+        if let Some(cached) = cached {
+            cached.set(Some((instruction_value, decoded)));
+        }
+        Ok(decoded)
+    }
+
+    fn program_address(&self, value: Int) -> Result<Address, ProgramError> {
+        if value < 0 {
+            Err(self.error(ProgramErrorKind::NegativeAddress(value)))
+        } else {
+            Ok(value as Address)
+        }
+    }
+
+    fn address(
+        &self,
+        decoded: &Decoded,
+        index: usize,
+    ) -> Result<Address, ProgramError> {
+        let parameter_address = self.instruction_pointer + index + 1;
+        let parameter = self.read(parameter_address);
+
+        match decoded.modes[index] {
+            ParameterMode::Value => Ok(parameter_address),
+            ParameterMode::Address => self.program_address(parameter),
+            ParameterMode::Relative => {
+                self.program_address(self.relative_base + parameter)
+            }
+        }
+    }
+
+    fn parameter(
+        &self,
+        decoded: &Decoded,
+        index: usize,
+    ) -> Result<Int, ProgramError> {
+        Ok(self.read(self.address(decoded, index)?))
+    }
+
+    fn store(
+        &mut self,
+        decoded: &Decoded,
+        index: usize,
+        value: Int,
+    ) -> Result<(), ProgramError> {
+        if decoded.modes[index] == ParameterMode::Value {
+            let kind = ProgramErrorKind::ImmediateWrite(index);
+            return Err(self.error(kind));
+        }
+
+        let address = self.address(decoded, index)?;
+        self.write(address, value);
+        Ok(())
+    }
+
+    pub fn try_step(&mut self) -> Result<ProgramState, ProgramError> {
+        let decoded = self.decode()?;
+        let instruction_pointer = self.instruction_pointer;
+        let number_parameters = decoded.number_parameters as usize;
+        let last_address = instruction_pointer + number_parameters;
+        if !self.is_mapped(last_address) {
+            return Err(self.error(ProgramErrorKind::EndOfMemory));
+        }
+
+        let mut state = ProgramState::Running;
+        let mut next = instruction_pointer + number_parameters + 1;
+
+        match decoded.operation_code {
+            1 | 2 | 7 | 8 => {
+                let left = self.parameter(&decoded, 0)?;
+                let right = self.parameter(&decoded, 1)?;
+                let result = match decoded.operation_code {
+                    1 => left + right,
+                    2 => left * right,
+                    7 => (left < right) as Int,
+                    _ => (left == right) as Int,
+                };
+                self.store(&decoded, 2, result)?;
+            }
+            3 => match self.inputs.pop_front() {
+                Some(input) => self.store(&decoded, 0, input)?,
+                None => return Ok(ProgramState::Input),
+            },
+            4 => {
+                let output = self.parameter(&decoded, 0)?;
+                self.outputs.push_back(output);
+                state = ProgramState::Output;
+            }
+            5 | 6 => {
+                let value = self.parameter(&decoded, 0)?;
+                if (value != 0) == (decoded.operation_code == 5) {
+                    let destination = self.parameter(&decoded, 1)?;
+                    let destination = self.program_address(destination)?;
+                    if destination != instruction_pointer {
+                        next = destination;
+                    }
+                }
+            }
+            9 => self.relative_base += self.parameter(&decoded, 0)?,
+            _ => return Ok(ProgramState::Stop),
+        }
+
+        self.instruction_pointer = next;
+        Ok(state)
+    }
+
+    pub fn try_run(&mut self) -> Result<ProgramState, ProgramError> {
+        let mut state = ProgramState::Running;
+        while state == ProgramState::Running {
+            state = self.try_step()?;
+        }
+
+        Ok(state)
+    }
+
+    pub fn try_run_outputs(&mut self) -> Result<ProgramState, ProgramError> {
+        let mut state = ProgramState::Running;
+        while state == ProgramState::Running || state == ProgramState::Output {
+            state = self.try_step()?;
+        }
+
+        Ok(state)
+    }
+
+    pub fn run(&mut self) -> ProgramState {
+        self.try_run().unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn run_outputs(&mut self) -> ProgramState {
+        self.try_run_outputs()
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assemble, try_run_outputs};
+
+    fn compare(content: &str, inputs: &[Int]) {
+        let mut program: Program = content.parse().unwrap();
+        program.inputs.extend(inputs);
+        let mut engine = Engine::from(program.clone());
+
+        let state = try_run_outputs(&mut program);
+        assert_eq!(engine.try_run_outputs(), state);
+
+        let engine = engine.to_program();
+        assert_eq!(engine.memory, program.memory);
+        assert_eq!(engine.outputs, program.outputs);
+        assert_eq!(engine.instruction_pointer, program.instruction_pointer);
+    }
+
+    #[test]
+    fn test_same_behaviour() {
+        compare("1,9,10,3,2,3,11,0,99,30,40,50", &[]);
+        compare("3,3,1107,-1,8,3,4,3,99", &[7]);
+        compare(
+            "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99",
+            &[],
+        );
+        compare("104,1125899906842624,99", &[]);
+        compare("104,1,42", &[]);
+        compare("11101,1,1,0,99", &[]);
+        compare("1105,1,-2", &[]);
+        compare("1105,1,3", &[]);
+        compare("1101,20,22,5000,4,5000,1101,0,0,5000,1105,1,5000", &[]);
+    }
+
+    #[test]
+    fn test_jump_to_self() {
+        let source = "
+            self: jnz #1, #self
+                  out #7
+                  hlt
+        ";
+        let mut program = Program::from(assemble(source).unwrap());
+        let mut engine = Engine::from(program.clone());
+
+        assert_eq!(try_run_outputs(&mut program), Ok(ProgramState::Stop));
+        assert_eq!(engine.try_run_outputs(), Ok(ProgramState::Stop));
+        assert_eq!(engine.to_program().outputs, program.outputs);
+        assert_eq!(program.outputs, vec![7]);
+    }
+
+    #[test]
+    fn test_clone_shares_pages() {
+        let source = "
+            in  @value
+            out @value
+            hlt
+            value: data 0
+        ";
+        let engine = Engine::from(Program::from(assemble(source).unwrap()));
+        engine.decode().unwrap();
+
+        let mut clone = engine.clone();
+        assert!(Rc::ptr_eq(engine.page(0).unwrap(), clone.page(0).unwrap()));
+        clone.input(42);
+        assert_eq!(clone.run(), ProgramState::Output);
+        assert_eq!(clone.output(), Some(42));
+        assert!(!Rc::ptr_eq(engine.page(0).unwrap(), clone.page(0).unwrap()));
+        assert_eq!((engine.read(5), clone.read(5)), (0, 42));
+        assert!(Rc::ptr_eq(&engine.cache, &clone.cache));
+        assert!(engine.cache[2].get().is_some());
+    }
+
+    #[test]
+    fn test_self_modifying_code() {
+        let source = "
+            patch: out @value
+                   add #104, #0, @patch
+                   jz  #0, #patch
+            value: data 5
+        ";
+        let memory = assemble(source).unwrap();
+        let mut engine = Engine::from(Program::from(memory));
+
+        assert_eq!(engine.run(), ProgramState::Output);
+        assert_eq!(engine.output(), Some(5));
+        assert_eq!(engine.run(), ProgramState::Output);
+        assert_eq!(engine.output(), Some(9));
+    }
+}
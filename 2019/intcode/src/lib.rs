@@ -0,0 +1,584 @@
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::num::ParseIntError;
+use std::{collections::VecDeque, str::FromStr};
+
+#[macro_use]
+extern crate num_derive;
+
+mod ascii;
+mod assembler;
+mod debugger;
+mod disassembler;
+mod engine;
+mod machine;
+mod memory;
+mod network;
+mod profiler;
+mod snapshot;
+
+pub use ascii::{is_ascii, Ascii};
+pub use assembler::{assemble, AssemblyError, AssemblyErrorKind};
+pub use debugger::{Debugger, Pause};
+pub use disassembler::{disassemble, disassemble_range, Listing};
+pub use engine::Engine;
+pub use machine::{
+    run_connected, Blocking, InputFn, InputIter, InputSource, Machine,
+    OutputFn, OutputSink, Pipe,
+};
+pub use memory::{PagedMemory, PAGE_SIZE};
+pub use network::{
+    CaptureNat, Event, IdlePolicy, InvalidPacket, NatPolicy, Network, Packet,
+    PacketLog, QuietRounds, RoundStats, Topology, WakeNat,
+};
+pub use profiler::{Profile, Profiler};
+pub use snapshot::SnapshotError;
+
+pub type Int = i64;
+pub type Memory = Vec<Int>;
+pub type Address = usize;
+pub type Inputs = VecDeque<Int>;
+pub type Outputs = VecDeque<Int>;
+
+#[derive(Clone, Debug)]
+pub struct Program {
+    instruction_pointer: Address,
+    relative_base: Int,
+    pub memory: PagedMemory,
+    pub inputs: Inputs,
+    pub outputs: Outputs,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProgramState {
+    Running,
+    Input,
+    Output,
+    Stop,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProgramErrorKind {
+    InvalidOpcode(Int),
+    InvalidMode(Int),
+    NegativeAddress(Int),
+    ImmediateWrite(usize),
+    EndOfMemory,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramError {
+    pub instruction_pointer: Address,
+    pub kind: ProgramErrorKind,
+}
+
+impl Display for ProgramError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "instruction {}: ", self.instruction_pointer)?;
+        match &self.kind {
+            ProgramErrorKind::InvalidOpcode(code) => {
+                write!(f, "invalid opcode {}", code)
+            }
+            ProgramErrorKind::InvalidMode(instruction_value) => {
+                write!(f, "invalid parameter mode in {}", instruction_value)
+            }
+            ProgramErrorKind::NegativeAddress(address) => {
+                write!(f, "negative address {}", address)
+            }
+            ProgramErrorKind::ImmediateWrite(parameter_index) => write!(
+                f,
+                "write to immediate mode parameter {}",
+                parameter_index
+            ),
+            ProgramErrorKind::EndOfMemory => {
+                write!(f, "instruction runs past the end of memory")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProgramError {}
+
+impl Program {
+    pub fn input(&mut self, input: Int) {
+        self.inputs.push_back(input)
+    }
+
+    pub fn output(&mut self) -> Option<Int> {
+        self.outputs.pop_back()
+    }
+
+    pub fn instruction_pointer(&self) -> Address {
+        self.instruction_pointer
+    }
+
+    pub fn relative_base(&self) -> Int {
+        self.relative_base
+    }
+
+    fn error(&self, kind: ProgramErrorKind) -> ProgramError {
+        ProgramError {
+            instruction_pointer: self.instruction_pointer,
+            kind,
+        }
+    }
+
+    fn address(&self, value: Int) -> Result<Address, ProgramError> {
+        if value < 0 {
+            Err(self.error(ProgramErrorKind::NegativeAddress(value)))
+        } else {
+            Ok(value as Address)
+        }
+    }
+}
+
+impl From<Memory> for Program {
+    fn from(memory: Memory) -> Self {
+        Program {
+            instruction_pointer: 0,
+            relative_base: 0,
+            memory: PagedMemory::from(memory),
+            inputs: VecDeque::new(),
+            outputs: VecDeque::new(),
+        }
+    }
+}
+
+impl FromStr for Program {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let memory_result: Result<Vec<_>, _> =
+            s.trim().split(',').map(&str::parse::<Int>).collect();
+
+        Ok(Program::from(memory_result?))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, FromPrimitive, PartialEq, ToPrimitive)]
+enum ParameterMode {
+    Address = 0,
+    Value = 1,
+    Relative = 2,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Operation {
+    BinOp(fn(Int, Int) -> Int),
+    Input,
+    Output,
+    Jump(fn(Int) -> bool),
+    Rebase,
+    Stop,
+}
+
+const OPERATIONS: [(Int, &str, Operation); 10] = [
+    (1, "add", Operation::BinOp(|l, r| l + r)),
+    (2, "mul", Operation::BinOp(|l, r| l * r)),
+    (3, "in", Operation::Input),
+    (4, "out", Operation::Output),
+    (5, "jnz", Operation::Jump(|v| v != 0)),
+    (6, "jz", Operation::Jump(|v| v == 0)),
+    (7, "lt", Operation::BinOp(|l, r| (l < r) as Int)),
+    (8, "eq", Operation::BinOp(|l, r| (l == r) as Int)),
+    (9, "arb", Operation::Rebase),
+    (99, "hlt", Operation::Stop),
+];
+
+pub fn mnemonic(operation_code: Int) -> Option<&'static str> {
+    OPERATIONS
+        .iter()
+        .find(|(code, _, _)| *code == operation_code)
+        .map(|(_, mnemonic, _)| *mnemonic)
+}
+
+fn operation_by_mnemonic(mnemonic: &str) -> Option<(Int, Operation)> {
+    OPERATIONS
+        .iter()
+        .find(|(_, name, _)| *name == mnemonic)
+        .map(|(code, _, operation)| (*code, *operation))
+}
+
+pub fn operation_code(mnemonic: &str) -> Option<Int> {
+    operation_by_mnemonic(mnemonic).map(|(code, _)| code)
+}
+
+impl TryFrom<Int> for Operation {
+    type Error = ProgramErrorKind;
+
+    fn try_from(operation_code: Int) -> Result<Self, Self::Error> {
+        OPERATIONS
+            .iter()
+            .find(|(code, _, _)| *code == operation_code)
+            .map(|(_, _, operation)| *operation)
+            .ok_or(ProgramErrorKind::InvalidOpcode(operation_code))
+    }
+}
+
+impl Operation {
+    fn number_parameters(&self) -> usize {
+        match self {
+            Operation::BinOp(_) => 3,
+            Operation::Input => 1,
+            Operation::Output => 1,
+            Operation::Jump(_) => 2,
+            Operation::Rebase => 1,
+            Operation::Stop => 0,
+        }
+    }
+
+    fn written_parameter(&self) -> Option<usize> {
+        match self {
+            Operation::BinOp(_) => Some(2),
+            Operation::Input => Some(0),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Instruction {
+    parameters_modes: Vec<ParameterMode>,
+    operation: Operation,
+}
+
+impl Instruction {
+    fn parameter_value_address(
+        &self,
+        program: &Program,
+        parameter_index: usize,
+    ) -> Result<Address, ProgramError> {
+        let parameter_address =
+            program.instruction_pointer + parameter_index + 1;
+        let parameter = program.memory.read(parameter_address);
+
+        match self.parameters_modes[parameter_index] {
+            ParameterMode::Value => Ok(parameter_address),
+            ParameterMode::Address => program.address(parameter),
+            ParameterMode::Relative => {
+                program.address(program.relative_base + parameter)
+            }
+        }
+    }
+
+    fn parameter_value(
+        &self,
+        program: &Program,
+        parameter_index: usize,
+    ) -> Result<Int, ProgramError> {
+        let address = self.parameter_value_address(program, parameter_index)?;
+        Ok(program.memory.read(address))
+    }
+
+    fn parameter_value_mut<'a>(
+        &self,
+        program: &'a mut Program,
+        parameter_index: usize,
+    ) -> Result<&'a mut Int, ProgramError> {
+        if self.parameters_modes[parameter_index] == ParameterMode::Value {
+            let kind = ProgramErrorKind::ImmediateWrite(parameter_index);
+            return Err(program.error(kind));
+        }
+
+        let address = self.parameter_value_address(program, parameter_index)?;
+        Ok(program.memory.entry(address))
+    }
+
+    fn apply(
+        &self,
+        program: &mut Program,
+    ) -> Result<ProgramState, ProgramError> {
+        let instruction_pointer = program.instruction_pointer;
+        let number_outputs = program.outputs.len();
+
+        match self.operation {
+            Operation::BinOp(op) => {
+                let left_value = self.parameter_value(program, 0)?;
+                let right_value = self.parameter_value(program, 1)?;
+                let result_value = self.parameter_value_mut(program, 2)?;
+                *result_value = op(left_value, right_value);
+            }
+
+            Operation::Input => match program.inputs.pop_front() {
+                None => return Ok(ProgramState::Input),
+                Some(input) => {
+                    let value = self.parameter_value_mut(program, 0)?;
+                    *value = input;
+                }
+            },
+
+            Operation::Output => {
+                let output = self.parameter_value(program, 0)?;
+                program.outputs.push_back(output);
+            }
+
+            Operation::Jump(predicate) => {
+                let parameter = self.parameter_value(program, 0)?;
+                if predicate(parameter) {
+                    let destination = self.parameter_value(program, 1)?;
+                    program.instruction_pointer =
+                        program.address(destination)?;
+                }
+            }
+
+            Operation::Rebase => {
+                let parameter = self.parameter_value(program, 0)?;
+                program.relative_base += parameter;
+            }
+
+            Operation::Stop => {
+                return Ok(ProgramState::Stop);
+            }
+        }
+
+        if instruction_pointer == program.instruction_pointer {
+            let pointer_delta = self.operation.number_parameters() + 1;
+            program.instruction_pointer += pointer_delta;
+        }
+
+        if program.outputs.len() != number_outputs {
+            Ok(ProgramState::Output)
+        } else {
+            Ok(ProgramState::Running)
+        }
+    }
+}
+
+fn parse_parameters_modes(
+    instruction_value: Int,
+    number_parameters: usize,
+) -> Option<Vec<ParameterMode>> {
+    let mut parameters_modes = vec![];
+    let mut parameter_mask = 100;
+    for _ in 0..number_parameters {
+        let parameter_mode_int = instruction_value / parameter_mask % 10;
+        let parameter_mode: ParameterMode =
+            num::FromPrimitive::from_i64(parameter_mode_int)?;
+
+        parameters_modes.push(parameter_mode);
+        parameter_mask *= 10;
+    }
+
+    Some(parameters_modes)
+}
+
+fn parse_instruction(program: &Program) -> Result<Instruction, ProgramError> {
+    if !program.memory.is_mapped(program.instruction_pointer) {
+        return Err(program.error(ProgramErrorKind::EndOfMemory));
+    }
+    let instruction_value = program.memory.read(program.instruction_pointer);
+
+    let operation_code = instruction_value % 100;
+    let operation = Operation::try_from(operation_code)
+        .map_err(|kind| program.error(kind))?;
+    let number_parameters = operation.number_parameters();
+
+    let last_address = program.instruction_pointer + number_parameters;
+    if !program.memory.is_mapped(last_address) {
+        return Err(program.error(ProgramErrorKind::EndOfMemory));
+    }
+
+    let parameters_modes =
+        parse_parameters_modes(instruction_value, number_parameters)
+            .ok_or_else(|| {
+                program.error(ProgramErrorKind::InvalidMode(instruction_value))
+            })?;
+
+    Ok(Instruction {
+        parameters_modes,
+        operation,
+    })
+}
+
+pub fn try_step(program: &mut Program) -> Result<ProgramState, ProgramError> {
+    let instruction = parse_instruction(program)?;
+    instruction.apply(program)
+}
+
+pub fn try_run(program: &mut Program) -> Result<ProgramState, ProgramError> {
+    let mut state = ProgramState::Running;
+    while state == ProgramState::Running {
+        state = try_step(program)?;
+    }
+
+    Ok(state)
+}
+
+pub fn try_run_outputs(
+    program: &mut Program,
+) -> Result<ProgramState, ProgramError> {
+    let mut state = ProgramState::Running;
+    while state == ProgramState::Running || state == ProgramState::Output {
+        state = try_step(program)?;
+    }
+
+    Ok(state)
+}
+
+pub fn step(program: &mut Program) -> ProgramState {
+    try_step(program).unwrap_or_else(|error| panic!("{}", error))
+}
+
+pub fn run(program: &mut Program) -> ProgramState {
+    try_run(program).unwrap_or_else(|error| panic!("{}", error))
+}
+
+pub fn run_outputs(program: &mut Program) -> ProgramState {
+    try_run_outputs(program).unwrap_or_else(|error| panic!("{}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_mode() {
+        let mut program: Program =
+            "1,9,10,3,2,3,11,0,99,30,40,50".parse().unwrap();
+        assert_eq!(run(&mut program), ProgramState::Stop);
+        assert_eq!(program.memory[0], 3500);
+    }
+
+    #[test]
+    fn test_comparisons() {
+        let content = "3,3,1107,-1,8,3,4,3,99";
+        for (input, expected) in [(7, 1), (8, 0), (9, 0)] {
+            let mut program: Program = content.parse().unwrap();
+            program.input(input);
+            assert_eq!(run_outputs(&mut program), ProgramState::Stop);
+            assert_eq!(program.output(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_input_state() {
+        let mut program: Program = "3,0,4,0,99".parse().unwrap();
+        assert_eq!(run(&mut program), ProgramState::Input);
+        program.input(42);
+        assert_eq!(run(&mut program), ProgramState::Output);
+        assert_eq!(program.output(), Some(42));
+        assert_eq!(run(&mut program), ProgramState::Stop);
+    }
+
+    #[test]
+    fn test_relative_mode() {
+        let content =
+            "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+        let mut program: Program = content.parse().unwrap();
+        run_outputs(&mut program);
+        let outputs = program.outputs.iter().copied().collect::<Vec<_>>();
+        assert_eq!(outputs, program.memory[..16].to_vec());
+    }
+
+    fn run_assembly(source: &str, inputs: &[Int]) -> (Program, Vec<Int>) {
+        let mut program = Program::from(assemble(source).unwrap());
+        program.inputs.extend(inputs);
+        assert_eq!(run_outputs(&mut program), ProgramState::Stop);
+        let outputs = program.outputs.drain(..).collect();
+        (program, outputs)
+    }
+
+    #[test]
+    fn test_binary_operations() {
+        let source = "
+            add @a, @b, @sum
+            mul @a, #3, @product
+            lt  @a, @b, @less
+            eq  #7, @b, @equal
+            hlt
+            a: data 5
+            b: data 7
+            sum: data 0
+            product: data 0
+            less: data 0
+            equal: data 0
+        ";
+        let (program, _) = run_assembly(source, &[]);
+        assert_eq!(program.memory[17..], [5, 7, 12, 15, 1, 1]);
+    }
+
+    #[test]
+    fn test_input_output() {
+        let source = "
+            in  @value
+            out @value
+            out #-3
+            hlt
+            value: data 0
+        ";
+        assert_eq!(run_assembly(source, &[42]).1, vec![42, -3]);
+    }
+
+    #[test]
+    fn test_jumps() {
+        let source = "
+            loop: in  @value
+                  jz  @value, #done
+                  out @value
+                  jnz #1, #loop
+            done: hlt
+            value: data 0
+        ";
+        assert_eq!(run_assembly(source, &[3, 2, 1, 0]).1, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_relative_base() {
+        let source = "
+            arb #values
+            out $0
+            arb #2
+            out $-1
+            in  $1
+            out $1
+            hlt
+            values: data 10, 20, 30, 40
+        ";
+        assert_eq!(run_assembly(source, &[50]).1, vec![10, 20, 50]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |content: &str, instruction_pointer, kind| {
+            let mut program: Program = content.parse().unwrap();
+            let expected = ProgramError {
+                instruction_pointer,
+                kind,
+            };
+            assert_eq!(try_run_outputs(&mut program), Err(expected));
+        };
+
+        error("104,1,42", 2, ProgramErrorKind::InvalidOpcode(42));
+        error("1,0,0,0,304,0", 4, ProgramErrorKind::InvalidMode(304));
+        error("4,-1,99", 0, ProgramErrorKind::NegativeAddress(-1));
+        error("109,-5,204,2,99", 2, ProgramErrorKind::NegativeAddress(-3));
+        error("1105,1,-2", 0, ProgramErrorKind::NegativeAddress(-2));
+        error("11101,1,1,0,99", 0, ProgramErrorKind::ImmediateWrite(2));
+        error("104,1,1,0,0", 2, ProgramErrorKind::EndOfMemory);
+        error("1105,1,3", 3, ProgramErrorKind::EndOfMemory);
+    }
+
+    #[test]
+    fn test_operation_table() {
+        for (code, name, operation) in OPERATIONS.iter() {
+            assert_eq!(mnemonic(*code), Some(*name));
+            assert_eq!(operation_code(name), Some(*code));
+            assert_eq!(
+                Operation::try_from(*code).unwrap().number_parameters(),
+                operation.number_parameters()
+            );
+        }
+        assert_eq!(
+            Operation::try_from(42).unwrap_err(),
+            ProgramErrorKind::InvalidOpcode(42)
+        );
+        assert_eq!(mnemonic(42), None);
+    }
+
+    #[test]
+    fn test_large_numbers() {
+        let mut program: Program = "104,1125899906842624,99".parse().unwrap();
+        run_outputs(&mut program);
+        assert_eq!(program.output(), Some(1125899906842624));
+    }
+}
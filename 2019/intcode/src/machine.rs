@@ -0,0 +1,298 @@
+use crate::{try_step, Int, Program, ProgramError, ProgramState};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    rc::Rc,
+    sync::mpsc::{Receiver, Sender},
+    thread::{self, JoinHandle},
+};
+
+pub trait InputSource {
+    fn next_input(&mut self) -> Option<Int>;
+}
+
+pub trait OutputSink {
+    fn push_output(&mut self, value: Int);
+}
+
+impl InputSource for VecDeque<Int> {
+    fn next_input(&mut self) -> Option<Int> {
+        self.pop_front()
+    }
+}
+
+impl OutputSink for VecDeque<Int> {
+    fn push_output(&mut self, value: Int) {
+        self.push_back(value)
+    }
+}
+
+impl OutputSink for Vec<Int> {
+    fn push_output(&mut self, value: Int) {
+        self.push(value)
+    }
+}
+
+impl<T: InputSource + ?Sized> InputSource for Box<T> {
+    fn next_input(&mut self) -> Option<Int> {
+        (**self).next_input()
+    }
+}
+
+impl<T: OutputSink + ?Sized> OutputSink for Box<T> {
+    fn push_output(&mut self, value: Int) {
+        (**self).push_output(value)
+    }
+}
+
+pub struct InputFn<F>(pub F);
+
+impl<F: FnMut() -> Option<Int>> InputSource for InputFn<F> {
+    fn next_input(&mut self) -> Option<Int> {
+        (self.0)()
+    }
+}
+
+pub struct OutputFn<F>(pub F);
+
+impl<F: FnMut(Int)> OutputSink for OutputFn<F> {
+    fn push_output(&mut self, value: Int) {
+        (self.0)(value)
+    }
+}
+
+pub struct InputIter<I>(pub I);
+
+impl<I: Iterator<Item = Int>> InputSource for InputIter<I> {
+    fn next_input(&mut self) -> Option<Int> {
+        self.0.next()
+    }
+}
+
+impl InputSource for Receiver<Int> {
+    fn next_input(&mut self) -> Option<Int> {
+        self.try_recv().ok()
+    }
+}
+
+impl OutputSink for Sender<Int> {
+    fn push_output(&mut self, value: Int) {
+        let _ = self.send(value);
+    }
+}
+
+pub struct Blocking(pub Receiver<Int>);
+
+impl InputSource for Blocking {
+    fn next_input(&mut self) -> Option<Int> {
+        self.0.recv().ok()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Pipe(Rc<RefCell<VecDeque<Int>>>);
+
+impl Pipe {
+    pub fn new() -> Pipe {
+        Pipe::default()
+    }
+
+    pub fn push(&self, value: Int) {
+        self.0.borrow_mut().push_back(value)
+    }
+
+    pub fn pop(&self) -> Option<Int> {
+        self.0.borrow_mut().pop_front()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+}
+
+impl InputSource for Pipe {
+    fn next_input(&mut self) -> Option<Int> {
+        self.pop()
+    }
+}
+
+impl OutputSink for Pipe {
+    fn push_output(&mut self, value: Int) {
+        self.push(value)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Machine<I, O> {
+    pub program: Program,
+    pub source: I,
+    pub sink: O,
+    pub steps: usize,
+}
+
+impl<I: InputSource, O: OutputSink> Machine<I, O> {
+    pub fn new(program: Program, source: I, sink: O) -> Machine<I, O> {
+        Machine {
+            program,
+            source,
+            sink,
+            steps: 0,
+        }
+    }
+
+    fn flush_outputs(&mut self) {
+        while let Some(output) = self.program.outputs.pop_front() {
+            self.sink.push_output(output);
+        }
+    }
+
+    pub fn try_run(&mut self) -> Result<ProgramState, ProgramError> {
+        self.flush_outputs();
+
+        loop {
+            match try_step(&mut self.program)? {
+                ProgramState::Input => match self.source.next_input() {
+                    Some(input) => self.program.input(input),
+                    None => return Ok(ProgramState::Input),
+                },
+                ProgramState::Output => {
+                    self.steps += 1;
+                    self.flush_outputs();
+                }
+                ProgramState::Running => self.steps += 1,
+                ProgramState::Stop => return Ok(ProgramState::Stop),
+            }
+        }
+    }
+
+    pub fn run(&mut self) -> ProgramState {
+        self.try_run().unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl<I, O> Machine<I, O>
+where
+    I: InputSource + Send + 'static,
+    O: OutputSink + Send + 'static,
+{
+    pub fn spawn(mut self) -> JoinHandle<Result<Self, ProgramError>> {
+        thread::spawn(move || self.try_run().map(|_| self))
+    }
+}
+
+pub fn run_connected<I: InputSource, O: OutputSink>(
+    machines: &mut [Machine<I, O>],
+) -> Result<Vec<ProgramState>, ProgramError> {
+    loop {
+        let steps = machines.iter().map(|machine| machine.steps).sum::<usize>();
+        let states = machines
+            .iter_mut()
+            .map(Machine::try_run)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let all_stopped =
+            states.iter().all(|state| *state == ProgramState::Stop);
+        let new_steps = machines.iter().map(|machine| machine.steps).sum();
+        if all_stopped || steps == new_steps {
+            return Ok(states);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assemble;
+    use std::sync::mpsc::channel;
+
+    const DOUBLER: &str = "
+        loop: in  @value
+              mul @value, #2, @value
+              out @value
+              jnz #1, #loop
+        value: data 0
+    ";
+
+    const ECHO: &str = "
+        loop: in  @value
+              out @value
+              jnz #1, #loop
+        value: data 0
+    ";
+
+    const COUNTER: &str = "
+        loop: in  @value
+              eq  @value, #0, @done
+              jnz @done, #end
+              add @value, #-1, @value
+              out @value
+              jnz #1, #loop
+        end:  hlt
+        value: data 0
+        done: data 0
+    ";
+
+    fn program(source: &str) -> Program {
+        Program::from(assemble(source).unwrap())
+    }
+
+    #[test]
+    fn test_sources_and_sinks() {
+        let mut machine =
+            Machine::new(program(DOUBLER), InputIter(1..4), vec![]);
+        assert_eq!(machine.run(), ProgramState::Input);
+        assert_eq!(machine.sink, vec![2, 4, 6]);
+
+        let mut outputs = vec![];
+        let mut next = 10;
+        let source = InputFn(|| {
+            next -= 5;
+            Some(next).filter(|value| *value > 0)
+        });
+        let sink = OutputFn(|value| outputs.push(value));
+        Machine::new(program(DOUBLER), source, sink).run();
+        assert_eq!(outputs, vec![10]);
+    }
+
+    #[test]
+    fn test_connected() {
+        let pipes = [Pipe::new(), Pipe::new()];
+        pipes[0].push(5);
+
+        let mut machines = vec![
+            Machine::new(program(COUNTER), pipes[0].clone(), pipes[1].clone()),
+            Machine::new(program(ECHO), pipes[1].clone(), pipes[0].clone()),
+        ];
+        let states = run_connected(&mut machines).unwrap();
+
+        assert_eq!(states, vec![ProgramState::Stop, ProgramState::Input]);
+        assert!(pipes[0].is_empty() && pipes[1].is_empty());
+        assert_eq!(machines[1].steps, 5 * 3);
+    }
+
+    #[test]
+    fn test_threads() {
+        let (input_sender, input_receiver) = channel();
+        let (output_sender, output_receiver) = channel();
+
+        let machine = Machine::new(
+            program(DOUBLER),
+            Blocking(input_receiver),
+            output_sender,
+        );
+        let handle = machine.spawn();
+
+        for value in 1..=3 {
+            input_sender.send(value).unwrap();
+            assert_eq!(output_receiver.recv(), Ok(value * 2));
+        }
+
+        drop(input_sender);
+        let machine = handle.join().unwrap().unwrap();
+        assert_eq!(machine.program.inputs.len(), 0);
+    }
+}
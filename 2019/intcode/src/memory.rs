@@ -0,0 +1,186 @@
+use crate::{Address, Int, Memory};
+use std::collections::hash_map::{Entry, HashMap};
+use std::mem::size_of;
+use std::ops::{Deref, DerefMut};
+
+pub const PAGE_SIZE: usize = 1024;
+pub(crate) const DIRECT_PAGES: usize = 4096;
+
+type Page = Box<[Int]>;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PagedMemory {
+    image: Memory,
+    direct: Vec<Option<Page>>,
+    far: HashMap<usize, Page>,
+    pages: usize,
+}
+
+impl From<Memory> for PagedMemory {
+    fn from(image: Memory) -> Self {
+        PagedMemory {
+            image,
+            direct: vec![],
+            far: HashMap::new(),
+            pages: 0,
+        }
+    }
+}
+
+impl Deref for PagedMemory {
+    type Target = [Int];
+
+    fn deref(&self) -> &Self::Target {
+        &self.image
+    }
+}
+
+impl DerefMut for PagedMemory {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.image
+    }
+}
+
+impl PagedMemory {
+    fn page(&self, index: usize) -> Option<&Page> {
+        if index < DIRECT_PAGES {
+            self.direct.get(index).and_then(Option::as_ref)
+        } else {
+            self.far.get(&index)
+        }
+    }
+
+    #[inline(never)]
+    fn page_mut(&mut self, index: usize) -> &mut Page {
+        let new_page = || vec![0; PAGE_SIZE].into_boxed_slice();
+        if index < DIRECT_PAGES {
+            if self.direct.len() <= index {
+                self.direct.resize(index + 1, None);
+            }
+            if self.direct[index].is_none() {
+                self.pages += 1;
+            }
+            self.direct[index].get_or_insert_with(new_page)
+        } else {
+            match self.far.entry(index) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    self.pages += 1;
+                    entry.insert(new_page())
+                }
+            }
+        }
+    }
+
+    #[inline]
+    pub fn read(&self, address: Address) -> Int {
+        match self.image.get(address) {
+            Some(value) => *value,
+            None => self.read_page(address),
+        }
+    }
+
+    #[inline(never)]
+    fn read_page(&self, address: Address) -> Int {
+        self.page(address / PAGE_SIZE)
+            .map(|page| page[address % PAGE_SIZE])
+            .unwrap_or_default()
+    }
+
+    #[inline]
+    pub fn entry(&mut self, address: Address) -> &mut Int {
+        if address < self.image.len() {
+            return &mut self.image[address];
+        }
+
+        &mut self.page_mut(address / PAGE_SIZE)[address % PAGE_SIZE]
+    }
+
+    pub fn write(&mut self, address: Address, value: Int) {
+        *self.entry(address) = value;
+    }
+
+    pub fn map_page(&mut self, index: usize) {
+        assert!(
+            index <= usize::MAX / PAGE_SIZE,
+            "page {} out of range",
+            index
+        );
+        self.page_mut(index);
+    }
+
+    #[inline]
+    pub fn is_mapped(&self, address: Address) -> bool {
+        address < self.image.len() || self.page(address / PAGE_SIZE).is_some()
+    }
+
+    pub fn touched_pages(&self) -> Vec<usize> {
+        let direct = self
+            .direct
+            .iter()
+            .enumerate()
+            .filter(|(_, page)| page.is_some())
+            .map(|(index, _)| index);
+        let mut far = self.far.keys().copied().collect::<Vec<_>>();
+        far.sort_unstable();
+
+        direct.chain(far).collect()
+    }
+
+    pub fn footprint(&self) -> usize {
+        let cells = self.image.len() + self.pages * PAGE_SIZE;
+        cells * size_of::<Int>() + self.direct.len() * size_of::<Option<Page>>()
+    }
+
+    pub fn sparse_cells(&self) -> impl Iterator<Item = (Address, Int)> + '_ {
+        self.touched_pages().into_iter().flat_map(move |index| {
+            let start = index * PAGE_SIZE;
+            self.page(index)
+                .unwrap()
+                .iter()
+                .enumerate()
+                .map(move |(offset, value)| (start + offset, *value))
+                .filter(move |(address, value)| {
+                    *address >= self.image.len() && *value != 0
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assemble, run, Program, ProgramState};
+
+    #[test]
+    fn test_sparse_write() {
+        let mut memory = PagedMemory::from(vec![1, 2, 3]);
+        memory.write(1, 7);
+        memory.write(1 << 40, 5);
+        memory.write((1 << 40) + 1, 6);
+
+        assert_eq!(memory[..], [1, 7, 3]);
+        assert_eq!(memory.read(1 << 40), 5);
+        assert_eq!(memory.read(1 << 41), 0);
+        assert_eq!(memory.touched_pages(), vec![(1 << 40) / PAGE_SIZE]);
+        assert_eq!(memory.footprint(), (3 + PAGE_SIZE) * size_of::<Int>());
+        assert_eq!(
+            memory.sparse_cells().collect::<Vec<_>>(),
+            vec![(1 << 40, 5), ((1 << 40) + 1, 6)]
+        );
+    }
+
+    #[test]
+    fn test_far_address() {
+        let source = "
+            add #21, #21, @1099511627776
+            out @1099511627776
+            hlt
+        ";
+        let mut program = Program::from(assemble(source).unwrap());
+
+        assert_eq!(run(&mut program), ProgramState::Output);
+        assert_eq!(program.output(), Some(42));
+        assert_eq!(program.memory.touched_pages().len(), 1);
+    }
+}
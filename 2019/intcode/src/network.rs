@@ -0,0 +1,512 @@
+use crate::{try_run_outputs, Int, Program, ProgramError};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+#[derive(Clone, Debug)]
+pub struct Topology {
+    pub addresses: Vec<Int>,
+    pub packet_size: usize,
+    pub empty_input: Int,
+}
+
+impl Topology {
+    pub fn new(machines: usize) -> Topology {
+        Topology {
+            addresses: (0..machines as Int).collect(),
+            packet_size: 2,
+            empty_input: -1,
+        }
+    }
+
+    pub fn machine_index(&self, address: Int) -> Option<usize> {
+        self.addresses.iter().position(|other| *other == address)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Packet {
+    pub round: usize,
+    pub source: Int,
+    pub destination: Int,
+    pub values: Vec<Int>,
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values = self
+            .values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        write!(
+            f,
+            "{} {} -> {}: {}",
+            self.round, self.source, self.destination, values
+        )
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct InvalidPacket(pub String);
+
+impl Display for InvalidPacket {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid packet '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidPacket {}
+
+impl FromStr for Packet {
+    type Err = InvalidPacket;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidPacket(s.to_string());
+
+        let (route, values) = s.split_once(':').ok_or_else(invalid)?;
+        let (header, destination) =
+            route.split_once("->").ok_or_else(invalid)?;
+        let (round, source) =
+            header.trim().split_once(' ').ok_or_else(invalid)?;
+
+        let values = values
+            .trim()
+            .split(',')
+            .filter(|value| !value.is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| invalid())?;
+
+        Ok(Packet {
+            round: round.parse().map_err(|_| invalid())?,
+            source: source.trim().parse().map_err(|_| invalid())?,
+            destination: destination.trim().parse().map_err(|_| invalid())?,
+            values,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PacketLog {
+    pub packets: Vec<Packet>,
+}
+
+impl PacketLog {
+    pub fn replay<N: NatPolicy>(
+        &self,
+        topology: &Topology,
+        nat: &mut N,
+    ) -> bool {
+        for packet in &self.packets {
+            let event = if topology.machine_index(packet.source).is_none() {
+                nat.idle(packet.round)
+            } else if topology.machine_index(packet.destination).is_none() {
+                nat.receive(packet)
+            } else {
+                Event::Continue
+            };
+
+            if event == Event::Stop {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+impl Display for PacketLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for packet in &self.packets {
+            writeln!(f, "{}", packet)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for PacketLog {
+    type Err = InvalidPacket;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let packets = s
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(str::parse)
+            .collect::<Result<_, _>>()?;
+
+        Ok(PacketLog { packets })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Event {
+    Continue,
+    Send(Packet),
+    Stop,
+}
+
+pub trait NatPolicy {
+    fn receive(&mut self, packet: &Packet) -> Event;
+    fn idle(&mut self, round: usize) -> Event;
+}
+
+#[derive(Clone, Debug)]
+pub struct CaptureNat {
+    pub address: Int,
+    pub packet: Option<Packet>,
+}
+
+impl CaptureNat {
+    pub fn new(address: Int) -> CaptureNat {
+        CaptureNat {
+            address,
+            packet: None,
+        }
+    }
+}
+
+impl NatPolicy for CaptureNat {
+    fn receive(&mut self, packet: &Packet) -> Event {
+        if packet.destination != self.address {
+            return Event::Continue;
+        }
+
+        self.packet = Some(packet.clone());
+        Event::Stop
+    }
+
+    fn idle(&mut self, _round: usize) -> Event {
+        Event::Stop
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct WakeNat {
+    pub address: Int,
+    pub target: Int,
+    pub last: Option<Packet>,
+    pub sent: Option<Packet>,
+    pub repeated: Option<Packet>,
+}
+
+impl WakeNat {
+    pub fn new(address: Int, target: Int) -> WakeNat {
+        WakeNat {
+            address,
+            target,
+            last: None,
+            sent: None,
+            repeated: None,
+        }
+    }
+}
+
+impl NatPolicy for WakeNat {
+    fn receive(&mut self, packet: &Packet) -> Event {
+        if packet.destination == self.address {
+            self.last = Some(packet.clone());
+        }
+
+        Event::Continue
+    }
+
+    fn idle(&mut self, round: usize) -> Event {
+        let last = match &self.last {
+            Some(last) => last,
+            None => return Event::Stop,
+        };
+
+        let packet = Packet {
+            round,
+            source: self.address,
+            destination: self.target,
+            values: last.values.clone(),
+        };
+
+        let sent_y = self.sent.as_ref().and_then(|sent| sent.values.last());
+        if sent_y.is_some() && sent_y == packet.values.last() {
+            self.repeated = Some(packet);
+            return Event::Stop;
+        }
+
+        self.sent = Some(packet.clone());
+        Event::Send(packet)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundStats {
+    pub round: usize,
+    pub packets: usize,
+    pub queued: usize,
+    pub waiting: usize,
+    pub machines: usize,
+}
+
+pub trait IdlePolicy {
+    fn is_idle(&mut self, stats: &RoundStats) -> bool;
+}
+
+impl<F: FnMut(&RoundStats) -> bool> IdlePolicy for F {
+    fn is_idle(&mut self, stats: &RoundStats) -> bool {
+        self(stats)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct QuietRounds {
+    pub rounds: usize,
+    quiet: usize,
+}
+
+impl QuietRounds {
+    pub fn new(rounds: usize) -> QuietRounds {
+        QuietRounds { rounds, quiet: 0 }
+    }
+}
+
+impl IdlePolicy for QuietRounds {
+    fn is_idle(&mut self, stats: &RoundStats) -> bool {
+        let quiet = stats.packets == 0 && stats.queued == 0;
+        if quiet && stats.waiting == stats.machines {
+            self.quiet += 1;
+        } else {
+            self.quiet = 0;
+        }
+
+        if self.quiet < self.rounds {
+            return false;
+        }
+
+        self.quiet = 0;
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Network {
+    pub topology: Topology,
+    pub machines: Vec<Program>,
+    pub log: PacketLog,
+    pub round: usize,
+    waiting: usize,
+}
+
+impl Network {
+    pub fn new(program: &Program, topology: Topology) -> Network {
+        let machines = topology
+            .addresses
+            .iter()
+            .map(|address| {
+                let mut machine = program.clone();
+                machine.input(*address);
+                machine
+            })
+            .collect();
+
+        Network {
+            topology,
+            machines,
+            log: PacketLog::default(),
+            round: 0,
+            waiting: 0,
+        }
+    }
+
+    pub fn run_round(&mut self) -> Result<Vec<Packet>, ProgramError> {
+        let packet_length = self.topology.packet_size + 1;
+        let mut packets = vec![];
+        self.waiting = 0;
+
+        for (index, machine) in self.machines.iter_mut().enumerate() {
+            if machine.inputs.is_empty() {
+                machine.input(self.topology.empty_input);
+                self.waiting += 1;
+            }
+
+            try_run_outputs(machine)?;
+
+            while machine.outputs.len() >= packet_length {
+                let mut values = machine.outputs.drain(..packet_length);
+                let destination = values.next().unwrap();
+                packets.push(Packet {
+                    round: self.round,
+                    source: self.topology.addresses[index],
+                    destination,
+                    values: values.collect(),
+                });
+            }
+        }
+
+        self.round += 1;
+        Ok(packets)
+    }
+
+    fn deliver(&mut self, packet: Packet) {
+        if let Some(index) = self.topology.machine_index(packet.destination) {
+            self.machines[index].inputs.extend(&packet.values);
+        }
+
+        self.log.packets.push(packet);
+    }
+
+    fn handle(&mut self, event: Event) -> bool {
+        match event {
+            Event::Continue => true,
+            Event::Send(packet) => {
+                self.deliver(packet);
+                true
+            }
+            Event::Stop => false,
+        }
+    }
+
+    pub fn run<N: NatPolicy, D: IdlePolicy>(
+        &mut self,
+        nat: &mut N,
+        idle: &mut D,
+    ) -> Result<(), ProgramError> {
+        loop {
+            let packets = self.run_round()?;
+            let packets_count = packets.len();
+
+            for packet in packets {
+                let external =
+                    self.topology.machine_index(packet.destination).is_none();
+                let event = if external {
+                    nat.receive(&packet)
+                } else {
+                    Event::Continue
+                };
+
+                self.deliver(packet);
+                if !self.handle(event) {
+                    return Ok(());
+                }
+            }
+
+            let stats = RoundStats {
+                round: self.round - 1,
+                packets: packets_count,
+                queued: self.machines.iter().map(|m| m.inputs.len()).sum(),
+                waiting: self.waiting,
+                machines: self.machines.len(),
+            };
+
+            if idle.is_idle(&stats) {
+                let event = nat.idle(stats.round);
+                if !self.handle(event) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assemble;
+
+    const FORWARD: &str = "
+              in  @address
+              jz  @address, #first
+        loop: in  @value
+              eq  @value, #-1, @empty
+              jnz @empty, #loop
+              in  @y
+              add @address, #1, @next
+              out @next
+              out @value
+              out @y
+              jnz #1, #loop
+        first: out #1
+              out #7
+              out #0
+              jnz #1, #loop
+        address: data 0
+        value: data 0
+        y: data 0
+        next: data 0
+        empty: data 0
+    ";
+
+    fn network() -> Network {
+        let program = Program::from(assemble(FORWARD).unwrap());
+        Network::new(&program, Topology::new(3))
+    }
+
+    #[test]
+    fn test_capture() {
+        let mut network = network();
+        let mut nat = CaptureNat::new(3);
+        network.run(&mut nat, &mut QuietRounds::new(1)).unwrap();
+
+        let packet = nat.packet.unwrap();
+        assert_eq!((packet.source, packet.values), (2, vec![7, 0]));
+        assert_eq!(network.log.packets.len(), 3);
+    }
+
+    #[test]
+    fn test_wake_and_replay() {
+        let mut network = network();
+        let mut nat = WakeNat::new(3, 1);
+        network.run(&mut nat, &mut QuietRounds::new(2)).unwrap();
+
+        let repeated = nat.repeated.unwrap();
+        assert_eq!(repeated.values, vec![7, 0]);
+
+        let log: PacketLog = network.log.to_string().parse().unwrap();
+        assert_eq!(log, network.log);
+
+        let mut replayed = WakeNat::new(3, 1);
+        assert!(!log.replay(&network.topology, &mut replayed));
+        assert_eq!(replayed.sent, nat.sent);
+        assert_eq!(replayed.idle(repeated.round), Event::Stop);
+        assert_eq!(replayed.repeated, Some(repeated));
+    }
+
+    struct PatientNat {
+        idles: usize,
+    }
+
+    impl NatPolicy for PatientNat {
+        fn receive(&mut self, _packet: &Packet) -> Event {
+            Event::Continue
+        }
+
+        fn idle(&mut self, _round: usize) -> Event {
+            self.idles += 1;
+            if self.idles < 3 {
+                return Event::Continue;
+            }
+
+            Event::Stop
+        }
+    }
+
+    #[test]
+    fn test_idle_continue() {
+        let mut network = network();
+        let mut nat = PatientNat { idles: 0 };
+        network.run(&mut nat, &mut QuietRounds::new(1)).unwrap();
+
+        assert_eq!(nat.idles, 3);
+        assert_eq!(network.log.packets.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!("1 2 -> 3: 4,5".parse::<Packet>().is_ok());
+        assert_eq!(
+            "1 2 3: 4".parse::<Packet>(),
+            Err(InvalidPacket("1 2 3: 4".to_string()))
+        );
+        assert!("1 2 -> 3: a".parse::<Packet>().is_err());
+    }
+}
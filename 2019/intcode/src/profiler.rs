@@ -0,0 +1,283 @@
+use crate::{
+    mnemonic, parse_instruction, Address, Int, Program, ProgramError,
+    ProgramState,
+};
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    io::{self, Sink, Write},
+};
+
+const REPORT_SIZE: usize = 10;
+
+fn invalid_data(error: ProgramError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    pub steps: usize,
+    pub memory_grows: usize,
+    pub address_counts: HashMap<Address, usize>,
+    pub loop_counts: HashMap<(Address, Address), usize>,
+    operation_codes: HashMap<Address, Int>,
+}
+
+impl Profile {
+    pub fn hottest_addresses(&self, count: usize) -> Vec<(Address, usize)> {
+        let mut addresses = self
+            .address_counts
+            .iter()
+            .map(|(address, executions)| (*address, *executions))
+            .collect::<Vec<_>>();
+
+        addresses.sort_by_key(|(address, executions)| {
+            (Reverse(*executions), *address)
+        });
+        addresses.truncate(count);
+        addresses
+    }
+
+    pub fn loop_steps(&self, start: Address, end: Address) -> usize {
+        (start..=end)
+            .filter_map(|address| self.address_counts.get(&address))
+            .sum()
+    }
+
+    pub fn hottest_loops(
+        &self,
+        count: usize,
+    ) -> Vec<((Address, Address), usize, usize)> {
+        let mut loops = self
+            .loop_counts
+            .iter()
+            .map(|(&(start, end), iterations)| {
+                ((start, end), *iterations, self.loop_steps(start, end))
+            })
+            .collect::<Vec<_>>();
+
+        loops.sort_by_key(|(range, iterations, steps)| {
+            (Reverse(*iterations), Reverse(*steps), *range)
+        });
+        loops.truncate(count);
+        loops
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "steps: {}", self.steps)?;
+        writeln!(f, "memory grows: {}", self.memory_grows)?;
+
+        writeln!(f, "hottest addresses:")?;
+        for (address, executions) in self.hottest_addresses(REPORT_SIZE) {
+            let operation_code = self.operation_codes[&address];
+            writeln!(
+                f,
+                "  {:>6} {:<4} {:>10} ({:.1}%)",
+                address,
+                mnemonic(operation_code).unwrap_or("?"),
+                executions,
+                100.0 * executions as f64 / self.steps as f64
+            )?;
+        }
+
+        writeln!(f, "hottest loops:")?;
+        for ((start, end), iterations, steps) in self.hottest_loops(REPORT_SIZE)
+        {
+            writeln!(
+                f,
+                "  {:>6}..{:<6} {:>10} iterations {:>10} steps ({:.1}%)",
+                start,
+                end,
+                iterations,
+                steps,
+                100.0 * steps as f64 / self.steps as f64
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Profiler<W: Write> {
+    pub profile: Profile,
+    trace: Option<W>,
+}
+
+impl Profiler<Sink> {
+    pub fn new() -> Profiler<Sink> {
+        Profiler {
+            profile: Profile::default(),
+            trace: None,
+        }
+    }
+}
+
+impl Default for Profiler<Sink> {
+    fn default() -> Self {
+        Profiler::new()
+    }
+}
+
+impl<W: Write> Profiler<W> {
+    pub fn with_trace(trace: W) -> Profiler<W> {
+        Profiler {
+            profile: Profile::default(),
+            trace: Some(trace),
+        }
+    }
+
+    pub fn into_trace(self) -> Option<W> {
+        self.trace
+    }
+
+    pub fn step(&mut self, program: &mut Program) -> io::Result<ProgramState> {
+        let address = program.instruction_pointer;
+        let instruction_value = program.memory.read(address);
+        let footprint = program.memory.footprint();
+
+        let instruction = parse_instruction(program).map_err(invalid_data)?;
+        let written_parameter = instruction.operation.written_parameter();
+        let operands = (0..instruction.operation.number_parameters())
+            .map(|index| match written_parameter {
+                Some(written) if written == index => instruction
+                    .parameter_value_address(program, index)
+                    .map(|address| address as Int),
+                _ => instruction.parameter_value(program, index),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(invalid_data)?;
+        let written_address = written_parameter
+            .map(|index| instruction.parameter_value_address(program, index))
+            .transpose()
+            .map_err(invalid_data)?;
+
+        let state = instruction.apply(program).map_err(invalid_data)?;
+        if state == ProgramState::Input {
+            return Ok(state);
+        }
+
+        let operation_code = instruction_value % 100;
+        let profile = &mut self.profile;
+        profile.steps += 1;
+        *profile.address_counts.entry(address).or_default() += 1;
+        profile.operation_codes.insert(address, operation_code);
+
+        if footprint < program.memory.footprint() {
+            profile.memory_grows += 1;
+        }
+
+        if program.instruction_pointer < address {
+            let range = (program.instruction_pointer, address);
+            *profile.loop_counts.entry(range).or_default() += 1;
+        }
+
+        if let Some(trace) = &mut self.trace {
+            write!(
+                trace,
+                "{} {} {} [{}]",
+                address,
+                mnemonic(operation_code).unwrap_or("?"),
+                instruction_value,
+                operands
+                    .iter()
+                    .map(|operand| operand.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+
+            if let Some(written_address) = written_address {
+                let value = program.memory.read(written_address);
+                write!(trace, " -> @{}={}", written_address, value)?;
+            }
+
+            writeln!(trace)?;
+        }
+
+        Ok(state)
+    }
+
+    pub fn run(&mut self, program: &mut Program) -> io::Result<ProgramState> {
+        let mut state = ProgramState::Running;
+        while state == ProgramState::Running {
+            state = self.step(program)?;
+        }
+
+        Ok(state)
+    }
+
+    pub fn run_outputs(
+        &mut self,
+        program: &mut Program,
+    ) -> io::Result<ProgramState> {
+        let mut state = ProgramState::Running;
+        while state == ProgramState::Running || state == ProgramState::Output {
+            state = self.step(program)?;
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assemble, run_outputs};
+
+    const COUNTDOWN: &str = "
+        loop: add @counter, #-1, @counter
+              out @counter
+              jnz @counter, #loop
+              add #1, #1, @20
+              hlt
+        counter: data 3
+    ";
+
+    fn program() -> Program {
+        Program::from(assemble(COUNTDOWN).unwrap())
+    }
+
+    #[test]
+    fn test_profile() {
+        let mut profiler = Profiler::new();
+        let state = profiler.run_outputs(&mut program()).unwrap();
+        assert_eq!(state, ProgramState::Stop);
+
+        let profile = profiler.profile;
+        assert_eq!(profile.steps, 3 * 3 + 2);
+        assert_eq!(profile.memory_grows, 1);
+        assert_eq!(profile.hottest_addresses(2), vec![(0, 3), (4, 3)]);
+        assert_eq!(profile.hottest_loops(1), vec![((0, 6), 2, 9)]);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut profiler = Profiler::with_trace(vec![]);
+        profiler.run_outputs(&mut program()).unwrap();
+
+        let trace = String::from_utf8(profiler.into_trace().unwrap()).unwrap();
+        let lines = trace.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 11);
+        assert_eq!(lines[0], "0 add 1001 [3, -1, 14] -> @14=2");
+        assert_eq!(lines[1], "4 out 4 [2]");
+        assert_eq!(lines[2], "6 jnz 1005 [2, 0]");
+        assert_eq!(lines[9], "9 add 1101 [1, 1, 20] -> @20=2");
+        assert_eq!(lines[10], "13 hlt 99 []");
+    }
+
+    #[test]
+    fn test_same_behaviour() {
+        let mut plain = program();
+        let mut profiled = program();
+        let plain_state = run_outputs(&mut plain);
+        let profiled_state =
+            Profiler::new().run_outputs(&mut profiled).unwrap();
+
+        assert_eq!(plain_state, profiled_state);
+        assert_eq!(plain.memory, profiled.memory);
+        assert_eq!(plain.outputs, profiled.outputs);
+    }
+}
@@ -0,0 +1,328 @@
+use crate::{Address, Int, Program, PAGE_SIZE};
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter},
+    fs, io,
+    path::Path,
+};
+
+const HEADER: &str = "intcode-snapshot 1";
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    Io(io::Error),
+    Header,
+    MissingField(&'static str),
+    InvalidField(String, Box<dyn Error + Send + Sync>),
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnapshotError::Io(error) => write!(f, "{}", error),
+            SnapshotError::Header => {
+                write!(f, "not an intcode snapshot, expected '{}'", HEADER)
+            }
+            SnapshotError::MissingField(field) => {
+                write!(f, "missing field '{}'", field)
+            }
+            SnapshotError::InvalidField(field, error) => {
+                write!(f, "invalid field '{}': {}", field, error)
+            }
+        }
+    }
+}
+
+impl Error for SnapshotError {}
+
+impl From<io::Error> for SnapshotError {
+    fn from(error: io::Error) -> Self {
+        SnapshotError::Io(error)
+    }
+}
+
+fn invalid<E: Error + Send + Sync + 'static>(
+    field: &str,
+) -> impl Fn(E) -> SnapshotError + '_ {
+    move |error| SnapshotError::InvalidField(field.to_string(), Box::new(error))
+}
+
+fn join<'a>(values: impl Iterator<Item = &'a Int>) -> String {
+    values
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn parse_values(
+    fields: &HashMap<&str, &str>,
+    field: &'static str,
+) -> Result<Vec<Int>, SnapshotError> {
+    let value = fields
+        .get(field)
+        .ok_or(SnapshotError::MissingField(field))?;
+
+    if value.is_empty() {
+        return Ok(vec![]);
+    }
+
+    value
+        .split(',')
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .map_err(invalid(field))
+}
+
+fn parse_cells(
+    fields: &HashMap<&str, &str>,
+    field: &'static str,
+) -> Result<Vec<(Address, Int)>, SnapshotError> {
+    let value = fields.get(field).copied().unwrap_or_default();
+    if value.is_empty() {
+        return Ok(vec![]);
+    }
+
+    value
+        .split(',')
+        .map(|cell| {
+            let (address, value) = cell.split_once(':').unwrap_or((cell, ""));
+            Ok((
+                address.parse().map_err(invalid(field))?,
+                value.parse().map_err(invalid(field))?,
+            ))
+        })
+        .collect()
+}
+
+fn parse_pages(
+    fields: &HashMap<&str, &str>,
+    field: &'static str,
+) -> Result<Vec<usize>, SnapshotError> {
+    let value = fields.get(field).copied().unwrap_or_default();
+    if value.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let pages: Vec<usize> = value
+        .split(',')
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .map_err(invalid(field))?;
+
+    match pages.iter().find(|&&index| index > usize::MAX / PAGE_SIZE) {
+        Some(index) => Err(SnapshotError::InvalidField(
+            field.to_string(),
+            format!("page {} is past the end of memory", index).into(),
+        )),
+        None => Ok(pages),
+    }
+}
+
+fn parse_value(
+    fields: &HashMap<&str, &str>,
+    field: &'static str,
+) -> Result<Int, SnapshotError> {
+    let value = fields
+        .get(field)
+        .ok_or(SnapshotError::MissingField(field))?;
+
+    value.parse().map_err(invalid(field))
+}
+
+impl Program {
+    pub fn to_snapshot(&self) -> String {
+        format!(
+            "{}\n\
+             instruction_pointer={}\n\
+             relative_base={}\n\
+             inputs={}\n\
+             outputs={}\n\
+             memory={}\n\
+             mapped={}\n\
+             pages={}\n",
+            HEADER,
+            self.instruction_pointer,
+            self.relative_base,
+            join(self.inputs.iter()),
+            join(self.outputs.iter()),
+            join(self.memory.iter()),
+            self.memory
+                .touched_pages()
+                .iter()
+                .map(|index| index.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+            self.memory
+                .sparse_cells()
+                .map(|(address, value)| format!("{}:{}", address, value))
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+
+    pub fn from_snapshot(snapshot: &str) -> Result<Program, SnapshotError> {
+        let mut lines = snapshot.lines();
+        if lines.next().map(str::trim) != Some(HEADER) {
+            return Err(SnapshotError::Header);
+        }
+
+        let fields: HashMap<_, _> = lines
+            .filter_map(|line| line.split_once('='))
+            .map(|(field, value)| (field.trim(), value.trim()))
+            .collect();
+
+        let mut program = Program::from(parse_values(&fields, "memory")?);
+        let instruction_pointer = parse_value(&fields, "instruction_pointer")?;
+        program.instruction_pointer = Address::try_from(instruction_pointer)
+            .map_err(invalid("instruction_pointer"))?;
+        program.relative_base = parse_value(&fields, "relative_base")?;
+        program.inputs = parse_values(&fields, "inputs")?.into();
+        program.outputs = parse_values(&fields, "outputs")?.into();
+        for index in parse_pages(&fields, "mapped")? {
+            program.memory.map_page(index);
+        }
+        for (address, value) in parse_cells(&fields, "pages")? {
+            program.memory.write(address, value);
+        }
+
+        Ok(program)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_snapshot())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Program, SnapshotError> {
+        let snapshot = fs::read_to_string(path)?;
+        Program::from_snapshot(&snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assemble, run, run_outputs, ProgramState};
+
+    const ECHO: &str = "
+                arb #buffer
+        loop:   in  $0
+                out $0
+                arb #1
+                jnz #1, #loop
+        buffer: data 0
+    ";
+
+    #[test]
+    fn test_round_trip() {
+        let mut program = Program::from(assemble(ECHO).unwrap());
+        program.inputs.extend(vec![1, 2]);
+        assert_eq!(run(&mut program), ProgramState::Output);
+        program.input(3);
+
+        let snapshot = program.to_snapshot();
+        let mut restored = Program::from_snapshot(&snapshot).unwrap();
+        assert_eq!(restored.to_snapshot(), snapshot);
+        assert_eq!(restored.instruction_pointer(), 6);
+        assert_eq!(restored.relative_base(), 11);
+
+        assert_eq!(run_outputs(&mut program), ProgramState::Input);
+        assert_eq!(run_outputs(&mut restored), ProgramState::Input);
+        assert_eq!(restored.outputs, vec![1, 2, 3]);
+        assert_eq!(restored.memory, program.memory);
+        assert_eq!(restored.relative_base(), program.relative_base());
+    }
+
+    #[test]
+    fn test_pages() {
+        let mut program = Program::from(assemble(ECHO).unwrap());
+        program.memory.write(1 << 40, 7);
+
+        let snapshot = program.to_snapshot();
+        assert!(snapshot.contains("\npages=1099511627776:7\n"));
+        let restored = Program::from_snapshot(&snapshot).unwrap();
+        assert_eq!(restored.memory, program.memory);
+    }
+
+    #[test]
+    fn test_zeroed_pages() {
+        let mut program = Program::from(assemble(ECHO).unwrap());
+        program.memory.write(1 << 40, 7);
+        program.memory.write(1 << 40, 0);
+        program.memory.write(2000, 0);
+
+        let snapshot = program.to_snapshot();
+        assert!(snapshot.contains("\nmapped=1,1073741824\npages=\n"));
+        let restored = Program::from_snapshot(&snapshot).unwrap();
+        assert_eq!(restored.memory.footprint(), program.memory.footprint());
+        for address in [1 << 40, (1 << 40) + PAGE_SIZE, 2000, 5000] {
+            assert_eq!(
+                restored.memory.is_mapped(address),
+                program.memory.is_mapped(address)
+            );
+        }
+        assert_eq!(restored.memory, program.memory);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let name = format!("intcode-snapshot-test-{}.txt", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        let mut program = Program::from(assemble(ECHO).unwrap());
+        program.input(42);
+        run(&mut program);
+
+        program.save(&path).unwrap();
+        let restored = Program::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(restored.to_snapshot(), program.to_snapshot());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            Program::from_snapshot("1,2,3"),
+            Err(SnapshotError::Header)
+        ));
+
+        let snapshot = format!("{}\nmemory=99\n", HEADER);
+        assert!(matches!(
+            Program::from_snapshot(&snapshot),
+            Err(SnapshotError::MissingField("instruction_pointer"))
+        ));
+
+        let snapshot = format!(
+            "{}\ninstruction_pointer=0\nrelative_base=0\n\
+             inputs=a\noutputs=\nmemory=99\n",
+            HEADER
+        );
+        assert!(matches!(
+            Program::from_snapshot(&snapshot),
+            Err(SnapshotError::InvalidField(field, _)) if field == "inputs"
+        ));
+
+        let snapshot = format!(
+            "{}\ninstruction_pointer=-1\nrelative_base=0\n\
+             inputs=\noutputs=\nmemory=99\n",
+            HEADER
+        );
+        assert!(matches!(
+            Program::from_snapshot(&snapshot),
+            Err(SnapshotError::InvalidField(field, _))
+                if field == "instruction_pointer"
+        ));
+
+        let snapshot = format!(
+            "{}\ninstruction_pointer=0\nrelative_base=0\n\
+             inputs=\noutputs=\nmemory=99\nmapped={}\n",
+            HEADER,
+            usize::MAX
+        );
+        assert!(matches!(
+            Program::from_snapshot(&snapshot),
+            Err(SnapshotError::InvalidField(field, _)) if field == "mapped"
+        ));
+    }
+}
@@ -1,8 +1,5 @@
-#![feature(once_cell)]
-
-use std::env;
-use std::fs;
-use std::lazy::Lazy;
+use aoc_common::{print_answers, Part, Solution};
+use std::cell::LazyCell;
 
 fn find_numbers(numbers: &[u64], remaining: u64, expected: u64) -> u64 {
     match (numbers.first(), remaining, expected) {
@@ -11,7 +8,7 @@ fn find_numbers(numbers: &[u64], remaining: u64, expected: u64) -> u64 {
         (None, _, _) => 0,
         (Some(&head), _, _) => {
             let tail = &numbers[1..];
-            let found_product = Lazy::new(|| {
+            let found_product = LazyCell::new(|| {
                 find_numbers(tail, remaining - 1, expected - head)
             });
 
@@ -24,27 +21,31 @@ fn find_numbers(numbers: &[u64], remaining: u64, expected: u64) -> u64 {
     }
 }
 
-fn solve(content: &str, remaining: u64) -> u64 {
-    let numbers: Vec<u64> = content
-        .lines()
-        .map(|line| line.parse::<u64>().unwrap())
-        .collect();
+pub struct Puzzle;
 
-    find_numbers(&numbers, remaining, 2020)
-}
+impl Solution for Puzzle {
+    type Input<'a> = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+    fn parse(content: &str) -> Self::Input<'_> {
+        content
+            .lines()
+            .map(|line| line.parse::<u64>().unwrap())
+            .collect()
+    }
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+    fn part_1(numbers: &Self::Input<'_>) -> Self::Answer1 {
+        find_numbers(numbers, 2, 2020)
+    }
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn part_2(numbers: &Self::Input<'_>) -> Self::Answer2 {
+        find_numbers(numbers, 3, 2020)
+    }
+}
 
-    println!("Part 1: {}", solve(&content, 2));
-    println!("Part 2: {}", solve(&content, 3));
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,7 +1,6 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::env;
-use std::fs;
 
 type Number = u64;
 type Numbers = HashSet<Number>;
@@ -48,14 +47,13 @@ fn count_diff_to_target(
     )
 }
 
-fn solve_part_1(content: &str) {
-    let numbers = parse_numbers(content);
-    let device_voltage = get_device_joltage(&numbers);
+fn solve_part_1(numbers: &Numbers) -> usize {
+    let device_voltage = get_device_joltage(numbers);
 
-    let differences = count_diff_to_target(&numbers, 0, device_voltage);
+    let differences = count_diff_to_target(numbers, 0, device_voltage);
 
     let (differences_1, _, differences_3) = differences;
-    println!("Part 1: {}", differences_1 * differences_3)
+    differences_1 * differences_3
 }
 
 fn count_arrangements(
@@ -64,10 +62,7 @@ fn count_arrangements(
     target_joltage: Number,
     arrangements_count_cache: &mut Cache,
 ) -> usize {
-    match arrangements_count_cache.get(&current_joltage) {
-        Some(&count) => return count,
-        None => {}
-    }
+    if let Some(&count) = arrangements_count_cache.get(&current_joltage) { return count }
 
     let mut count = 0;
     let possible_differences = &[1, 2, 3];
@@ -97,34 +92,41 @@ fn count_arrangements(
     count
 }
 
-fn solve_part_2(content: &str) {
-    let numbers = parse_numbers(content);
+fn solve_part_2(numbers: &Numbers) -> usize {
     let start_voltage = 0;
-    let device_voltage = get_device_joltage(&numbers);
+    let device_voltage = get_device_joltage(numbers);
     let mut arrangements_count_cache = Cache::new();
 
-    let count = count_arrangements(
-        &numbers,
+    count_arrangements(
+        numbers,
         start_voltage,
         device_voltage,
         &mut arrangements_count_cache,
-    );
-
-    println!("Part 2: {}", count)
+    )
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Puzzle;
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+impl Solution for Puzzle {
+    type Input<'a> = Numbers;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_numbers(content)
+    }
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn part_1(numbers: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(numbers)
+    }
+
+    fn part_2(numbers: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(numbers)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
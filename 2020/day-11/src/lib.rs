@@ -1,16 +1,16 @@
-use std::env;
-use std::fs;
+use aoc_common::{print_answers, Part, Solution};
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
-enum GridTile {
+pub enum GridTile {
     Floor,
     EmptySeat,
     OccupiedSeat,
 }
 
-#[derive(Debug)]
-struct Grid {
+#[derive(Clone, Debug)]
+pub struct Grid {
     tiles: Vec<Vec<GridTile>>,
 }
 
@@ -43,12 +43,10 @@ impl Grid {
         col: usize,
     ) -> Vec<(usize, usize)> {
         (-1..=1)
-            .into_iter()
             .filter(|r| row > 0 || *r >= 0)
             .filter(|r| add(row, *r) < self.height())
             .flat_map(|r| {
                 (-1..=1)
-                    .into_iter()
                     .filter(move |c| col > 0 || *c >= 0)
                     .filter(move |c| add(col, *c) < self.width())
                     .map(move |c| (add(row, r), add(col, c)))
@@ -109,12 +107,9 @@ impl Grid {
         col: usize,
     ) -> Vec<(usize, usize)> {
         (-1..=1)
-            .into_iter()
-            .flat_map(|r| (-1..=1).into_iter().map(move |c| (r, c)))
+            .flat_map(|r| (-1..=1).map(move |c| (r, c)))
             .filter(|(r, c)| *r != 0 || *c != 0)
-            .map(|direction| self.get_far_neighbor(row, col, direction))
-            .filter(|n| n.is_some())
-            .map(|n| n.unwrap())
+            .filter_map(|direction| self.get_far_neighbor(row, col, direction))
             .collect()
     }
 
@@ -151,9 +146,10 @@ impl FromStr for Grid {
     }
 }
 
-impl ToString for Grid {
-    fn to_string(&self) -> String {
-        self.tiles
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let text: String = self
+            .tiles
             .iter()
             .flat_map(|row| {
                 row.iter()
@@ -164,12 +160,13 @@ impl ToString for Grid {
                     })
                     .chain(['\n'].iter().cloned())
             })
-            .collect()
+            .collect();
+        write!(f, "{}", text)
     }
 }
 
 type GridIteratorItem = (usize, usize, GridTile);
-struct GridIterator<'a> {
+pub struct GridIterator<'a> {
     grid: &'a Grid,
     row: usize,
     col: usize,
@@ -257,40 +254,46 @@ fn converge_grid(
             return old_grid;
         }
 
-        let tmp = old_grid;
-        old_grid = new_grid;
-        new_grid = tmp;
+        std::mem::swap(&mut old_grid, &mut new_grid);
     }
 }
 
-fn solve_part_1(content: &str) {
-    let start_grid: Grid = content.parse().unwrap();
-    let final_grid = converge_grid(start_grid, 4, |grid, row, col| {
+fn solve_part_1(start_grid: &Grid) -> usize {
+    let final_grid = converge_grid(start_grid.clone(), 4, |grid, row, col| {
         grid.get_immediate_neightbors(row, col)
     });
-    println!("Part 1: {}", final_grid.count_occupied_seat());
+    final_grid.count_occupied_seat()
 }
 
-fn solve_part_2(content: &str) {
-    let start_grid: Grid = content.parse().unwrap();
-    let final_grid = converge_grid(start_grid, 5, |grid, row, col| {
+fn solve_part_2(start_grid: &Grid) -> usize {
+    let final_grid = converge_grid(start_grid.clone(), 5, |grid, row, col| {
         grid.get_far_neightbors(row, col)
     });
-    println!("Part 2: {}", final_grid.count_occupied_seat());
+    final_grid.count_occupied_seat()
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Puzzle;
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+impl Solution for Puzzle {
+    type Input<'a> = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.parse().unwrap()
+    }
+
+    fn part_1(grid: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(grid)
+    }
+
+    fn part_2(grid: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(grid)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
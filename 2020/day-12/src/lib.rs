@@ -1,5 +1,4 @@
-use std::env;
-use std::fs;
+use aoc_common::{print_answers, Part, Solution};
 
 extern crate num;
 #[macro_use]
@@ -47,7 +46,7 @@ fn parse_content<'a>(
 ) -> impl Iterator<Item = (char, isize)> + 'a {
     content.lines().map(|line| {
         let operation_letter = line.chars().next().unwrap();
-        let operation_number = (&line[1..]).parse().unwrap();
+        let operation_number = line[1..].parse().unwrap();
         (operation_letter, operation_number)
     })
 }
@@ -72,13 +71,15 @@ fn move_ship_to(ship: &mut Ship, position: &Position, number: usize) {
     }
 }
 
-fn solve_part_1(content: &str) {
+type Operation = (char, isize);
+
+fn solve_part_1(operations: &[Operation]) -> isize {
     let mut ship = Ship {
         position: Position::default(),
         direction: Direction::East,
     };
 
-    for (operation_letter, operation_number) in parse_content(content) {
+    for &(operation_letter, operation_number) in operations {
         match operation_letter {
             'N' => ship.position.north += operation_number,
             'S' => ship.position.north -= operation_number,
@@ -91,19 +92,17 @@ fn solve_part_1(content: &str) {
         }
     }
 
-    let distance =
-        isize::abs(ship.position.north) + isize::abs(ship.position.east);
-    println!("Part 1: {}", distance)
+    isize::abs(ship.position.north) + isize::abs(ship.position.east)
 }
 
-fn solve_part_2(content: &str) {
+fn solve_part_2(operations: &[Operation]) -> isize {
     let mut ship = Ship {
         position: Position::default(),
         direction: Direction::East,
     };
     let mut waypoint = Position { north: 1, east: 10 };
 
-    for (letter, number) in parse_content(content) {
+    for &(letter, number) in operations {
         match letter {
             'N' => waypoint.north += number,
             'S' => waypoint.north -= number,
@@ -116,23 +115,31 @@ fn solve_part_2(content: &str) {
         }
     }
 
-    let distance =
-        isize::abs(ship.position.north) + isize::abs(ship.position.east);
-    println!("Part 2: {}", distance)
+    isize::abs(ship.position.north) + isize::abs(ship.position.east)
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Operation>;
+    type Answer1 = isize;
+    type Answer2 = isize;
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_content(content).collect()
+    }
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn part_1(operations: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(operations)
+    }
+
+    fn part_2(operations: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(operations)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,28 +1,45 @@
-use std::env;
-use std::fs;
-
+use aoc_common::{print_answers, Part, Solution};
 use num_bigint::BigInt;
 
 fn first_bigger_multiple(n: usize, target: usize) -> usize {
-    ((target + n - 1) / n) * n
+    target.div_ceil(n) * n
+}
+
+pub struct Notes {
+    estimated_time: usize,
+    bus_ids: Vec<(usize, usize)>,
 }
 
-fn solve_part_1(content: &str) {
+fn parse_notes(content: &str) -> Notes {
     let mut lines = content.lines();
-    let estimated_time: usize = lines.next().unwrap().parse().unwrap();
-    let (bus_id, delta_time) = lines
+    let estimated_time = lines.next().unwrap().parse().unwrap();
+    let bus_ids = lines
         .next()
         .unwrap()
         .split(',')
-        .filter(|bus_id_str| *bus_id_str != "x")
-        .map(str::parse)
-        .map(Result::unwrap)
+        .enumerate()
+        .filter(|(_, bus_id_str)| *bus_id_str != "x")
+        .map(|(index, bus_id_str)| (index, bus_id_str.parse().unwrap()))
+        .collect();
+
+    Notes {
+        estimated_time,
+        bus_ids,
+    }
+}
+
+fn solve_part_1(notes: &Notes) -> usize {
+    let estimated_time = notes.estimated_time;
+    let (bus_id, delta_time) = notes
+        .bus_ids
+        .iter()
+        .map(|&(_, bus_id)| bus_id)
         .map(|bus_id| (bus_id, first_bigger_multiple(bus_id, estimated_time)))
         .map(|(bus_id, next_stop)| (bus_id, next_stop - estimated_time))
         .min_by_key(|(_, delta_time)| *delta_time)
         .unwrap();
 
-    println!("Part 1: {}", bus_id * delta_time);
+    bus_id * delta_time
 }
 
 fn bezout_identity(a: &BigInt, b: &BigInt) -> (BigInt, BigInt) {
@@ -40,17 +57,8 @@ fn bezout_identity(a: &BigInt, b: &BigInt) -> (BigInt, BigInt) {
     (x2.clone(), x1 - q * x2)
 }
 
-fn solve_part_2(content: &str) {
-    let mut lines = content.lines();
-    lines.next();
-    let mut bus_ids: Vec<(usize, usize)> = lines
-        .next()
-        .unwrap()
-        .split(',')
-        .enumerate()
-        .filter(|(_, bus_id_str)| *bus_id_str != "x")
-        .map(|(index, bus_id_str)| (index, bus_id_str.parse().unwrap()))
-        .collect();
+fn solve_part_2(notes: &Notes) -> BigInt {
+    let mut bus_ids = notes.bus_ids.clone();
 
     bus_ids.sort_by_key(|(_, bus_id)| *bus_id);
     bus_ids.reverse();
@@ -70,21 +78,31 @@ fn solve_part_2(content: &str) {
         index += 1;
     }
 
-    println!("Part 2: {}", q - r);
+    q - r
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Puzzle;
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+impl Solution for Puzzle {
+    type Input<'a> = Notes;
+    type Answer1 = usize;
+    type Answer2 = BigInt;
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_notes(content)
+    }
+
+    fn part_1(notes: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(notes)
+    }
+
+    fn part_2(notes: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(notes)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,9 +1,8 @@
 use std::collections::HashMap;
-use std::env;
-use std::fs;
 
 #[macro_use]
 extern crate lazy_static;
+use aoc_common::{print_answers, Part, Solution};
 use regex::Regex;
 
 #[derive(Debug, Default)]
@@ -14,12 +13,13 @@ struct Program<'a> {
     mem: HashMap<u64, u64>,
 }
 
-enum Line<'a> {
+#[derive(Clone, Copy)]
+pub enum Line<'a> {
     Mask(&'a str),
     Mem(u64, u64),
 }
 
-fn parse_line(line: &str) -> Line {
+fn parse_line(line: &str) -> Line<'_> {
     lazy_static! {
         static ref REGEX: Regex =
             Regex::new(r"^mem\[(\d*)\] = (\d*)$").unwrap();
@@ -103,10 +103,10 @@ fn apply_mem_part_2(mut program: Program, address: u64, value: u64) -> Program {
     program
 }
 
-fn solve_part_1(content: &str) {
-    let program = content.lines().map(crate::parse_line).fold(
+fn solve_part_1(lines: &[Line]) -> u64 {
+    let program = lines.iter().fold(
         Program::default(),
-        |program, line| match line {
+        |program, line| match *line {
             Line::Mask(mask_str) => apply_mask_part_1(program, mask_str),
             Line::Mem(address, value) => {
                 apply_mem_part_1(program, address, value)
@@ -114,15 +114,13 @@ fn solve_part_1(content: &str) {
         },
     );
 
-    let final_sum: u64 = program.mem.iter().map(|(_, value)| value).sum();
-
-    println!("Part 1: {}", final_sum);
+    program.mem.values().sum()
 }
 
-fn solve_part_2(content: &str) {
-    let program = content.lines().map(crate::parse_line).fold(
+fn solve_part_2(lines: &[Line]) -> u64 {
+    let program = lines.iter().fold(
         Program::default(),
-        |program, line| match line {
+        |program, line| match *line {
             Line::Mask(mask_str) => apply_mask_part_2(program, mask_str),
             Line::Mem(address, value) => {
                 apply_mem_part_2(program, address, value)
@@ -130,23 +128,31 @@ fn solve_part_2(content: &str) {
         },
     );
 
-    let final_sum: u64 = program.mem.iter().map(|(_, value)| value).sum();
-
-    println!("Part 2: {}", final_sum);
+    program.mem.values().sum()
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Line<'a>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.lines().map(parse_line).collect()
+    }
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+    fn part_1(lines: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(lines)
+    }
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn part_2(lines: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(lines)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
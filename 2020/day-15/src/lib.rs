@@ -1,5 +1,5 @@
-use std::fs;
-use std::{collections::HashMap, env};
+use aoc_common::{print_answers, Part, Solution};
+use std::collections::HashMap;
 
 fn find_last_turn(numbers: &[u64], target: u64) -> Option<usize> {
     for index in (0..numbers.len() - 1).rev() {
@@ -10,15 +10,8 @@ fn find_last_turn(numbers: &[u64], target: u64) -> Option<usize> {
     None
 }
 
-fn solve_part_1(content: &str) {
-    let mut numbers: Vec<u64> = content
-        .lines()
-        .next()
-        .unwrap()
-        .split(',')
-        .map(str::parse)
-        .map(Result::unwrap)
-        .collect();
+fn solve_part_1(start_numbers: &[u64]) -> u64 {
+    let mut numbers = start_numbers.to_vec();
 
     while numbers.len() < 2020 {
         let &last_spoked_number = numbers.last().unwrap();
@@ -27,25 +20,16 @@ fn solve_part_1(content: &str) {
         numbers.push(difference as u64);
     }
 
-    println!("Part 1: {:#?}", numbers);
+    *numbers.last().unwrap()
 }
 
-fn solve_part_2(content: &str) {
-    let start_numbers: Vec<u64> = content
-        .lines()
-        .next()
-        .unwrap()
-        .split(',')
-        .map(str::parse)
-        .map(Result::unwrap)
-        .collect();
-
+fn solve_part_2(start_numbers: &[u64]) -> u64 {
     let last_start_number = *start_numbers.last().unwrap();
 
     let mut numbers: HashMap<u64, (u64, Option<u64>)> = start_numbers
-        .into_iter()
+        .iter()
         .enumerate()
-        .map(|(i, n)| (n, (i as u64 + 1, None)))
+        .map(|(i, n)| (*n, (i as u64 + 1, None)))
         .collect();
 
     let mut last_speech =
@@ -64,21 +48,38 @@ fn solve_part_2(content: &str) {
         last_speech = (spoken_number, spoken_value);
     }
 
-    println!("Part 2: {:#?}", last_speech.0);
+    last_speech.0
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content
+            .lines()
+            .next()
+            .unwrap()
+            .split(',')
+            .map(str::parse)
+            .map(Result::unwrap)
+            .collect()
+    }
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+    fn part_1(numbers: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(numbers)
+    }
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn part_2(numbers: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(numbers)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
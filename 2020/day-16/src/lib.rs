@@ -1,8 +1,8 @@
-use std::fs;
-use std::{collections::HashMap, env};
+use std::collections::HashMap;
 
 #[macro_use]
 extern crate lazy_static;
+use aoc_common::{print_answers, Part, Solution};
 use regex::Regex;
 
 #[derive(Debug)]
@@ -29,13 +29,13 @@ struct Ticket {
 }
 
 #[derive(Debug)]
-struct Input<'a> {
+pub struct Input<'a> {
     rules: Vec<Rule<'a>>,
     my_ticket: Ticket,
     nearby_tickets: Vec<Ticket>,
 }
 
-fn parse_rule(line: &str) -> Rule {
+fn parse_rule(line: &str) -> Rule<'_> {
     lazy_static! {
         static ref REGEX: Regex =
             Regex::new(r"^(.*): (\d*)-(\d*) or (\d*)-(\d*)$").unwrap();
@@ -67,7 +67,7 @@ fn parse_ticket(line: &str) -> Ticket {
     Ticket { values }
 }
 
-fn parse(content: &str) -> Input {
+fn parse(content: &str) -> Input<'_> {
     let lines: Vec<&str> = content.lines().collect();
     let paragraphs: Vec<&[&str]> = lines.split(|s| s.is_empty()).collect();
 
@@ -101,16 +101,13 @@ fn is_valid_for_any(rules: &[Rule], value: usize) -> bool {
     rules.iter().any(|r| is_valid(r, value))
 }
 
-fn solve_part_1(content: &str) {
-    let input = parse(content);
-    let r: usize = input
+fn solve_part_1(input: &Input) -> usize {
+    input
         .nearby_tickets
         .iter()
         .flat_map(|t| t.values.iter())
         .filter(|v| !is_valid_for_any(&input.rules, **v))
-        .sum();
-
-    println!("Part 1: {:?}", r)
+        .sum()
 }
 
 fn find_possible_rules<'a, 'b>(
@@ -123,8 +120,7 @@ fn find_possible_rules<'a, 'b>(
         .collect()
 }
 
-fn solve_part_2(content: &str) {
-    let input = parse(content);
+fn solve_part_2(input: &Input) -> usize {
     let remaining_tickets: Vec<&Ticket> = input
         .nearby_tickets
         .iter()
@@ -163,28 +159,36 @@ fn solve_part_2(content: &str) {
         }
     }
 
-    let departure_product: usize = rule_names_map
+    rule_names_map
         .into_iter()
         .filter(|(rule_name, _)| rule_name.starts_with("departure"))
         .map(|(_, values_index)| values_index)
         .map(|values_index| input.my_ticket.values[values_index])
-        .product();
-
-    println!("Part 2: {}", departure_product)
+        .product()
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Input<'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse(content)
+    }
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(input)
+    }
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(input)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
-use std::{collections::HashMap, fs, hash::Hash};
-use std::{collections::HashSet, env};
+use std::{collections::HashMap, hash::Hash};
+use std::collections::HashSet;
 
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
 type Tiles<T> = HashSet<T>;
@@ -92,10 +93,13 @@ fn get_grid_neighbors(tile: &GridTile) -> Vec<GridTile> {
         .collect()
 }
 
-fn solve_part_1(content: &str) {
-    let start_tiles = parse(content, |y, x| (0, y as isize, x as isize));
+fn solve_part_1(cubes: &Tiles<(usize, usize)>) -> usize {
+    let start_tiles = cubes
+        .iter()
+        .map(|&(y, x)| (0, y as isize, x as isize))
+        .collect();
     let final_tiles = iterate_algorithm(start_tiles, get_grid_neighbors);
-    println!("Part 1: {}", final_tiles.len());
+    final_tiles.len()
 }
 
 fn get_hyper_grid_neighbors(tile: &HyperTile) -> Vec<HyperTile> {
@@ -110,24 +114,37 @@ fn get_hyper_grid_neighbors(tile: &HyperTile) -> Vec<HyperTile> {
         .collect()
 }
 
-fn solve_part_2(content: &str) {
-    let start_tiles = parse(content, |y, x| (0, 0, y as isize, x as isize));
+fn solve_part_2(cubes: &Tiles<(usize, usize)>) -> usize {
+    let start_tiles = cubes
+        .iter()
+        .map(|&(y, x)| (0, 0, y as isize, x as isize))
+        .collect();
     let final_tiles = iterate_algorithm(start_tiles, get_hyper_grid_neighbors);
-    println!("Part 2: {}", final_tiles.len());
+    final_tiles.len()
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Puzzle;
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+impl Solution for Puzzle {
+    type Input<'a> = Tiles<(usize, usize)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse(content, |y, x| (y, x))
+    }
+
+    fn part_1(cubes: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(cubes)
+    }
+
+    fn part_2(cubes: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(cubes)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,8 +1,7 @@
-use std::env;
-use std::fs;
+use aoc_common::{print_answers, Part, Solution};
 
-#[derive(Debug)]
-enum Token {
+#[derive(Clone, Debug)]
+pub enum Token {
     Num(u64),
     Add,
     Mul,
@@ -15,15 +14,13 @@ fn parse(content: &str) -> Vec<Vec<Token>> {
         .lines()
         .map(|line| {
             line.chars()
-                .map(|c| match c {
+                .filter_map(|c| match c {
                     '+' => Some(Token::Add),
                     '*' => Some(Token::Mul),
                     '(' => Some(Token::Open),
                     ')' => Some(Token::Close),
                     _ => c.to_digit(10).map(u32::into).map(Token::Num),
                 })
-                .filter(Option::is_some)
-                .map(Option::unwrap)
                 .collect()
         })
         .collect()
@@ -85,32 +82,35 @@ fn compute(line: Vec<Token>, is_add_prioritised: bool) -> u64 {
     operands.pop().unwrap()
 }
 
-fn solve_part_1(content: &str) {
-    let lines = parse(content);
-    let compute_line = |line| compute(line, false);
-    let total: u64 = lines.into_iter().map(compute_line).sum();
-    println!("Part 1: {}", total)
+fn solve(lines: &[Vec<Token>], is_add_prioritised: bool) -> u64 {
+    lines
+        .iter()
+        .map(|line| compute(line.clone(), is_add_prioritised))
+        .sum()
 }
 
-fn solve_part_2(content: &str) {
-    let lines = parse(content);
-    let compute_line = |line| compute(line, true);
-    let total: u64 = lines.into_iter().map(compute_line).sum();
-    println!("Part 2: {}", total)
-}
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Vec<Token>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse(content)
+    }
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+    fn part_1(lines: &Self::Input<'_>) -> Self::Answer1 {
+        solve(lines, false)
+    }
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn part_2(lines: &Self::Input<'_>) -> Self::Answer2 {
+        solve(lines, true)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,10 +1,8 @@
-#![feature(str_split_once)]
-
-use std::fs;
-use std::{collections::HashMap, env};
+use aoc_common::{print_answers, Part, Solution};
+use std::collections::HashMap;
 
 #[derive(Clone, Debug)]
-enum Rule {
+pub enum Rule {
     Letter(u8),
     And(Vec<usize>),
     Or(Vec<usize>, Vec<usize>),
@@ -12,9 +10,7 @@ enum Rule {
 
 fn parse_numbers<'a>(i: impl IntoIterator<Item = &'a str>) -> Vec<usize> {
     i.into_iter()
-        .map(|n| n.parse())
-        .filter(Result::is_ok)
-        .map(Result::unwrap)
+        .flat_map(|n| n.parse())
         .collect()
 }
 
@@ -97,37 +93,55 @@ fn is_valid(rules: &HashMap<usize, Rule>, message: &[u8]) -> bool {
     advance(rules, message, 0).contains(&message.len())
 }
 
-fn solve(part: &str, content: &str) {
-    let lines: Vec<_> = content.lines().collect();
-    let paragraphs: Vec<_> = lines.split(|line| line.is_empty()).collect();
-    let rules = parse_rules(paragraphs[0]);
-    let messages = parse_messages(paragraphs[1]);
+type Rules = HashMap<usize, Rule>;
 
-    let valid_count = messages
-        .into_iter()
-        .filter(|message| is_valid(&rules, message))
-        .count();
+const LOOPING_RULES: [&str; 2] = ["8: 42 | 42 8", "11: 42 31 | 42 11 31"];
 
-    println!("{}: {}", part, valid_count);
+fn count_valid(rules: &Rules, messages: &[Vec<u8>]) -> usize {
+    messages
+        .iter()
+        .filter(|message| is_valid(rules, message))
+        .count()
 }
 
-fn get_content(index: usize, default_filename: &str) -> String {
-    let args: Vec<String> = env::args().collect();
-    let filename: &str = args
-        .get(index)
-        .map(|s| s.as_ref())
-        .unwrap_or(default_filename);
+pub struct Puzzle;
 
-    fs::read_to_string(filename).expect("Something went wrong reading the file")
-}
+impl Solution for Puzzle {
+    type Input<'a> = (Rules, Vec<Vec<u8>>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        let lines: Vec<_> = content.lines().collect();
+        let paragraphs: Vec<_> = lines.split(|line| line.is_empty()).collect();
+        (parse_rules(paragraphs[0]), parse_messages(paragraphs[1]))
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        let (rules, messages) = input;
+        count_valid(rules, messages)
+    }
 
-fn main() {
-    let content_part_1 = &get_content(1, "./res/input-1.txt");
-    solve("Part 1", content_part_1);
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        let (rules, messages) = input;
+        let mut rules = rules.clone();
+        rules.extend(parse_rules(&LOOPING_RULES));
+        count_valid(&rules, messages)
+    }
+}
 
-    let content_part_2 = &get_content(2, "./res/input-2.txt");
-    solve("Part 2", content_part_2);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    #[test]
+    fn looping_rules_test() {
+        let input = Puzzle::parse(include_str!("../res/example-2.txt"));
+        assert_eq!(Puzzle::part_1(&input), 3);
+        assert_eq!(Puzzle::part_2(&input), 12);
+    }
+}
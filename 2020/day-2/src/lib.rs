@@ -1,14 +1,10 @@
-#![feature(str_split_once)]
-
-use std::env;
-use std::fs;
-
 #[macro_use]
 extern crate lazy_static;
+use aoc_common::{print_answers, Part, Solution};
 use regex::Regex;
 
 #[derive(Debug)]
-struct Line<'a> {
+pub struct Line<'a> {
     min: usize,
     max: usize,
     letter: char,
@@ -57,26 +53,35 @@ fn is_line_valid_part_2(line: &Line) -> bool {
     xor(letter_1 == line.letter, letter_2 == line.letter)
 }
 
-fn solve(content: &str, is_line_valid: fn(&Line) -> bool) -> usize {
-    content
-        .lines()
-        .map(|line_str| line_from_str(line_str))
-        .filter(|line| is_line_valid(line))
-        .count()
+fn solve(lines: &[Line], is_line_valid: fn(&Line) -> bool) -> usize {
+    lines.iter().filter(|line| is_line_valid(line)).count()
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Line<'a>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content
+            .lines()
+            .map(|line_str| line_from_str(line_str))
+            .collect()
+    }
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+    fn part_1(lines: &Self::Input<'_>) -> Self::Answer1 {
+        solve(lines, is_line_valid_part_1)
+    }
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn part_2(lines: &Self::Input<'_>) -> Self::Answer2 {
+        solve(lines, is_line_valid_part_2)
+    }
+}
 
-    println!("Part 1: {}", solve(&content, is_line_valid_part_1));
-    println!("Part 2: {}", solve(&content, is_line_valid_part_2));
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
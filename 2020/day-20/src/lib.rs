@@ -1,15 +1,14 @@
-use std::fs;
-use std::{collections::HashMap, env};
+use std::collections::HashMap;
 
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
 type Image = Vec<Vec<bool>>;
 
 #[derive(Clone, Debug)]
-struct Tile {
+pub struct Tile {
     id: u64,
     image: Image,
-    transform: Transform,
 }
 
 type Flip = u8;
@@ -29,13 +28,6 @@ impl Transform {
         Transform { flip, rotation }
     }
 
-    fn identity() -> Transform {
-        Transform {
-            flip: 0,
-            rotation: 0,
-        }
-    }
-
     fn all() -> Vec<Transform> {
         vec![
             Transform::new(0, 0),
@@ -65,11 +57,7 @@ fn parse_tile(paragraph: &[&str]) -> Tile {
         .map(|line| line.chars().map(|c| c == '#').collect())
         .collect();
 
-    Tile {
-        id,
-        image,
-        transform: Transform::identity(),
-    }
+    Tile { id, image }
 }
 
 fn parse_tiles(content: &str) -> Vec<Tile> {
@@ -80,7 +68,7 @@ fn parse_tiles(content: &str) -> Vec<Tile> {
     paragraphs
         .into_iter()
         .filter(|paragraph| !paragraph.is_empty())
-        .map(|paragraph| parse_tile(paragraph))
+        .map(parse_tile)
         .collect()
 }
 
@@ -92,6 +80,7 @@ fn flip_horizontal(image: &mut Image) {
     image.iter_mut().for_each(|line| line.reverse());
 }
 
+#[allow(clippy::needless_range_loop)]
 fn rotate(image: &mut Image) {
     let h = image.len();
     let w = image.first().unwrap().len();
@@ -127,11 +116,7 @@ fn apply_transform(mut image: Image, transform: Transform) -> Image {
 fn apply_transform_tile(tile: Tile, transform: Transform) -> Tile {
     let id = tile.id;
     let image = apply_transform(tile.image, transform);
-    Tile {
-        id,
-        image,
-        transform,
-    }
+    Tile { id, image }
 }
 
 fn get_borders(tile: &Tile) -> Borders {
@@ -205,8 +190,8 @@ fn insert_tile_in_image(
             current_position.0 + delta_position.0,
             current_position.1 + delta_position.1,
         );
-        if !image_info.contains_key(&neighbor_position) {
-            image_info.insert(neighbor_position, neighbor);
+        if let std::collections::hash_map::Entry::Vacant(e) = image_info.entry(neighbor_position) {
+            e.insert(neighbor);
             insert_tile_in_image(tiles, image_info, neighbor_position);
         }
     }
@@ -240,11 +225,10 @@ fn is_monster_here(
     true
 }
 
-fn solve_part_1(content: &str) {
-    let tiles = parse_tiles(content);
+fn solve_part_1(tiles: &[Tile]) -> u64 {
     let neighbors: HashMap<u64, usize> = tiles
         .iter()
-        .map(|tile| (tile.id, get_possible_neighbors(&tiles, tile).len()))
+        .map(|tile| (tile.id, get_possible_neighbors(tiles, tile).len()))
         .collect();
 
     let corners_id = neighbors
@@ -253,8 +237,7 @@ fn solve_part_1(content: &str) {
         .map(|(tile_id, _)| tile_id)
         .collect_vec();
 
-    let corner_product: u64 = corners_id.into_iter().product();
-    println!("Part 1: {}", corner_product);
+    corners_id.into_iter().product()
 }
 
 fn get_image_tiles_grid(image_info: HashMap<Position, Tile>) -> Vec<Vec<Tile>> {
@@ -281,10 +264,7 @@ fn assemble_full_image(tiles: &[Tile], image_tiles: Vec<Vec<Tile>>) -> Image {
             let mut image_line = vec![];
             for tile in image_tiles_line.iter() {
                 let tile_line = tile.image.get(tile_row).unwrap();
-                for tile_col in 1..tile_width - 1 {
-                    let bit = tile_line[tile_col];
-                    image_line.push(bit);
-                }
+                image_line.extend_from_slice(&tile_line[1..tile_width - 1]);
             }
             image.push(image_line);
         }
@@ -300,7 +280,7 @@ fn count_monsters(image: &Image, monster: &Image) -> usize {
     let mut monster_count = 0;
     for image_row in 0..=image_height - monster_height {
         for image_col in 0..=image_width - monster_width {
-            if is_monster_here(&image, &monster, image_row, image_col) {
+            if is_monster_here(image, monster, image_row, image_col) {
                 monster_count += 1;
             }
         }
@@ -327,39 +307,45 @@ fn count_set_bits(image: &Image) -> usize {
         .count()
 }
 
-fn solve_part_2(content: &str) {
-    let tiles = parse_tiles(content);
-
+fn solve_part_2(tiles: &[Tile]) -> usize {
     let mut image_info_map: HashMap<Position, Tile> = HashMap::new();
     let start_tile = tiles.first().unwrap();
     let start_position = (0, 0);
     image_info_map.insert(start_position, start_tile.clone());
-    insert_tile_in_image(&tiles, &mut image_info_map, start_position);
+    insert_tile_in_image(tiles, &mut image_info_map, start_position);
 
     let image_tiles = get_image_tiles_grid(image_info_map);
-    let image = assemble_full_image(&tiles, image_tiles);
+    let image = assemble_full_image(tiles, image_tiles);
     let monster = parse_monster();
     let monster_count = count_monsters_in_transforms(&image, &monster).unwrap();
     let monster_size = count_set_bits(&monster);
     let image_count = count_set_bits(&image);
-    
-    println!("Part 2: {}", image_count - monster_count * monster_size);
+
+    image_count - monster_count * monster_size
 }
 
-fn get_content(index: usize, default_filename: &str) -> String {
-    let args: Vec<String> = env::args().collect();
-    let filename: &str = args
-        .get(index)
-        .map(|s| s.as_ref())
-        .unwrap_or(default_filename);
+pub struct Puzzle;
 
-    fs::read_to_string(filename).expect("Something went wrong reading the file")
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Tile>;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_tiles(content)
+    }
+
+    fn part_1(tiles: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(tiles)
+    }
+
+    fn part_2(tiles: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(tiles)
+    }
 }
 
-fn main() {
-    let content = get_content(1, "./res/input.txt");
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,12 +1,9 @@
-use std::fs;
-use std::{
-    collections::{BTreeMap, HashMap, HashSet},
-    env,
-};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 #[macro_use]
 extern crate lazy_static;
 
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 use regex::Regex;
 
@@ -17,12 +14,12 @@ struct Ingredient<'a>(&'a str);
 struct Allergen<'a>(&'a str);
 
 #[derive(Debug)]
-struct Food<'a> {
+pub struct Food<'a> {
     ingredients: Vec<Ingredient<'a>>,
     allergens: Vec<Allergen<'a>>,
 }
 
-fn parse_foods(content: &str) -> Vec<Food> {
+fn parse_foods(content: &str) -> Vec<Food<'_>> {
     lazy_static! {
         static ref REGEX: Regex =
             Regex::new(r"^(.*) \(contains (.*)\)$").unwrap();
@@ -36,11 +33,11 @@ fn parse_foods(content: &str) -> Vec<Food> {
             let allergens_str = captures.get(2).unwrap().as_str();
             let ingredients = ingredients_str
                 .split(' ')
-                .map(|ingredient_str| Ingredient(ingredient_str))
+                .map(Ingredient)
                 .collect_vec();
             let allergens = allergens_str
                 .split(", ")
-                .map(|allergen_str| Allergen(allergen_str))
+                .map(Allergen)
                 .collect_vec();
             Food {
                 ingredients,
@@ -112,12 +109,10 @@ fn get_allergens_ingredient_map<'a>(
     allergens_ingredient_map
 }
 
-fn solve_part_1(content: &str) {
-    let foods = parse_foods(content);
+fn solve_part_1(foods: &[Food]) -> usize {
+    let allergens_ingredient_map = get_allergens_ingredient_map(foods);
 
-    let allergens_ingredient_map = get_allergens_ingredient_map(&foods);
-
-    let count = foods
+    foods
         .iter()
         .flat_map(|food| food.ingredients.iter())
         .filter(|ingredient| {
@@ -125,38 +120,40 @@ fn solve_part_1(content: &str) {
                 .values()
                 .any(|other_ingredient| other_ingredient == *ingredient)
         })
-        .count();
-
-    println!("Part 1: {}", count);
+        .count()
 }
 
-fn solve_part_2(content: &str) {
-    let foods = parse_foods(content);
-
-    let allergens_ingredient_map = get_allergens_ingredient_map(&foods);
+fn solve_part_2(foods: &[Food]) -> String {
+    let allergens_ingredient_map = get_allergens_ingredient_map(foods);
 
-    let ingredients_string = allergens_ingredient_map
+    allergens_ingredient_map
         .values()
         .map(|ingredient| ingredient.0)
-        .join(",");
-
-    println!("Part 2: {}", ingredients_string);
+        .join(",")
 }
 
-fn get_content(index: usize, default_filename: &str) -> String {
-    let args: Vec<String> = env::args().collect();
-    let filename: &str = args
-        .get(index)
-        .map(|s| s.as_ref())
-        .unwrap_or(default_filename);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Food<'a>>;
+    type Answer1 = usize;
+    type Answer2 = String;
 
-    fs::read_to_string(filename).expect("Something went wrong reading the file")
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_foods(content)
+    }
+
+    fn part_1(foods: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(foods)
+    }
+
+    fn part_2(foods: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(foods)
+    }
 }
 
-fn main() {
-    let content = get_content(1, "./res/input.txt");
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
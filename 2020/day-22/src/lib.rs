@@ -1,6 +1,7 @@
-use std::{collections::HashSet, fs};
-use std::{collections::VecDeque, env};
+use std::collections::HashSet;
+use std::collections::VecDeque;
 
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
 type Card = u64;
@@ -22,7 +23,7 @@ fn parse_decks(content: &str) -> Decks {
     let lines = content.lines().collect_vec();
     let paragraphs = lines.split(|line| line.is_empty());
     paragraphs
-        .map(|paragraph| parse_deck(paragraph))
+        .map(parse_deck)
         .collect_vec()
 }
 
@@ -41,7 +42,6 @@ fn find_winning_index(played_cards: &[u64]) -> usize {
     played_cards
         .iter()
         .enumerate()
-        .map(|(index, card)| (index, card))
         .max_by_key(|(_, card)| *card)
         .unwrap()
         .0
@@ -87,10 +87,8 @@ fn combat(mut decks: Decks) -> Score {
     compute_deck_score(winning_deck)
 }
 
-fn solve_part_1(content: &str) {
-    let decks = parse_decks(content);
-    let score = combat(decks);
-    println!("Part 1: {}", score);
+fn solve_part_1(decks: &Decks) -> Score {
+    combat(decks.clone())
 }
 
 fn can_play_recursive_game(decks: &[Deck], played_cards: &[u64]) -> bool {
@@ -142,26 +140,33 @@ fn recursive_combat(mut decks: Decks) -> (usize, Score) {
     (winning_index, winning_score)
 }
 
-fn solve_part_2(content: &str) {
-    let decks = parse_decks(content);
-    let (_, score) = recursive_combat(decks);
-    println!("Part 2: {}", score);
+fn solve_part_2(decks: &Decks) -> Score {
+    let (_, score) = recursive_combat(decks.clone());
+    score
 }
 
-fn get_content(index: usize, default_filename: &str) -> String {
-    let args: Vec<String> = env::args().collect();
-    let filename: &str = args
-        .get(index)
-        .map(|s| s.as_ref())
-        .unwrap_or(default_filename);
+pub struct Puzzle;
 
-    fs::read_to_string(filename).expect("Something went wrong reading the file")
+impl Solution for Puzzle {
+    type Input<'a> = Decks;
+    type Answer1 = Score;
+    type Answer2 = Score;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_decks(content)
+    }
+
+    fn part_1(decks: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(decks)
+    }
+
+    fn part_2(decks: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(decks)
+    }
 }
 
-fn main() {
-    let content = get_content(1, "./res/input.txt");
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
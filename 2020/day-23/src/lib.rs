@@ -1,14 +1,17 @@
-use std::env;
-use std::fs;
+use aoc_common::{print_answers, Part, Solution};
 
 type Cup = usize;
 type Cups = Vec<Cup>;
 
-fn parse_cups(content: &str, minimum_number: usize) -> (Cup, Cups) {
-    let mut cups_vec: Cups = content
+fn parse_labels(content: &str) -> Cups {
+    content
         .chars()
         .map(|c| c.to_string().parse().unwrap())
-        .collect();
+        .collect()
+}
+
+fn link_cups(labels: &[Cup], minimum_number: usize) -> (Cup, Cups) {
+    let mut cups_vec = labels.to_vec();
     while cups_vec.len() < minimum_number {
         cups_vec.push(cups_vec.len() + 1);
     }
@@ -63,38 +66,44 @@ fn get_labels_after_1(cups: Cups) -> String {
     labels
 }
 
-fn solve_part_1(content: &str) {
-    let (start_cup, mut cups) = parse_cups(content, 0);
+fn solve_part_1(labels: &[Cup]) -> String {
+    let (start_cup, mut cups) = link_cups(labels, 0);
     play_moves(&mut cups, start_cup, 100);
-    let labels = get_labels_after_1(cups);
-    println!("Part 1: {}", labels);
+    get_labels_after_1(cups)
 }
 
 fn get_product_after_1(cups: Cups) -> Cup {
     cups[1] * cups[cups[1]]
 }
 
-fn solve_part_2(content: &str) {
-    let (start_cup, mut cups) = parse_cups(content, 1000000);
+fn solve_part_2(labels: &[Cup]) -> Cup {
+    let (start_cup, mut cups) = link_cups(labels, 1000000);
     play_moves(&mut cups, start_cup, 10000000);
-    let product = get_product_after_1(cups);
-    println!("Part 2: {}", product);
+    get_product_after_1(cups)
 }
 
-fn get_content(index: usize, default_filename: &str) -> String {
-    let args: Vec<String> = env::args().collect();
-    let filename: &str = args
-        .get(index)
-        .map(|s| s.as_ref())
-        .unwrap_or(default_filename);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Cups;
+    type Answer1 = String;
+    type Answer2 = Cup;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_labels(content)
+    }
+
+    fn part_1(labels: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(labels)
+    }
 
-    fs::read_to_string(filename).expect("Something went wrong reading the file")
+    fn part_2(labels: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(labels)
+    }
 }
 
-fn main() {
-    let content = get_content(1, "./res/input.txt");
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
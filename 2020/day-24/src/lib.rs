@@ -1,6 +1,7 @@
+use aoc_common::{print_answers, Part, Solution};
 use core::panic;
-use std::{collections::HashMap, fs};
-use std::{collections::HashSet, env};
+use std::collections::HashMap;
+use std::collections::HashSet;
 
 type Position = (isize, isize);
 
@@ -90,13 +91,6 @@ fn flip_tiles(positions: PositionList) -> PositionSet {
         })
 }
 
-fn solve_part_1(content: &str) {
-    let paths = parse_paths(content);
-    let positions = fold_paths(paths);
-    let flipped_tiles = flip_tiles(positions);
-    println!("Part 1: {}", flipped_tiles.len());
-}
-
 fn get_neighbors(tile: Position) -> PositionSet {
     Directions::all()
         .iter()
@@ -136,28 +130,28 @@ fn iterate_days(tiles: PositionSet) -> PositionSet {
     (0..100).fold(tiles, |prev_tiles, _| iterate_day(prev_tiles))
 }
 
-fn solve_part_2(content: &str) {
-    let paths = parse_paths(content);
-    let positions = fold_paths(paths);
-    let start_tiles = flip_tiles(positions);
-    let final_tiles = iterate_days(start_tiles);
-    println!("Part 1: {}", final_tiles.len());
-}
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = PositionSet;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-fn get_content(index: usize, default_filename: &str) -> String {
-    let args: Vec<String> = env::args().collect();
-    let filename: &str = args
-        .get(index)
-        .map(|s| s.as_ref())
-        .unwrap_or(default_filename);
+    fn parse(content: &str) -> Self::Input<'_> {
+        flip_tiles(fold_paths(parse_paths(content)))
+    }
+
+    fn part_1(tiles: &Self::Input<'_>) -> Self::Answer1 {
+        tiles.len()
+    }
 
-    fs::read_to_string(filename).expect("Something went wrong reading the file")
+    fn part_2(tiles: &Self::Input<'_>) -> Self::Answer2 {
+        iterate_days(tiles.clone()).len()
+    }
 }
 
-fn main() {
-    let content = get_content(1, "./res/input.txt");
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -0,0 +1,56 @@
+use aoc_common::{print_answers, NoPuzzle, Part, Solution};
+use itertools::Itertools;
+
+fn apply(subject_number: u64, loop_size: u64) -> u64 {
+    let mut value = 1;
+    for _ in 0..loop_size {
+        value *= subject_number;
+        value %= 20201227;
+    }
+    value
+}
+
+fn get_values_loop_sizes() -> Vec<u64> {
+    let mut values = vec![0; 20201227];
+    let mut value = 1;
+    let subject_number = 7;
+    for loop_size in 1..=20201227 {
+        value *= subject_number;
+        value %= 20201227;
+        values[value] = loop_size;
+    }
+    values
+}
+
+fn solve_part_1(public_keys: &[u64]) -> u64 {
+    let values = get_values_loop_sizes();
+    let loop_sizes = public_keys.iter().map(|&public_key| values[public_key as usize]).collect_vec();
+    apply(public_keys[0], loop_sizes[1])
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = NoPuzzle;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.lines().map(str::parse).map(Result::unwrap).collect()
+    }
+
+    fn part_1(public_keys: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(public_keys)
+    }
+
+    fn part_2(_public_keys: &Self::Input<'_>) -> Self::Answer2 {
+        NoPuzzle
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
+
+#[cfg(test)]
+mod tests {}
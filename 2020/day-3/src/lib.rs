@@ -0,0 +1,70 @@
+use aoc_common::{print_answers, Part, Solution};
+
+fn add_mod(n: usize, i: usize, m: usize) -> usize {
+    (n + i) % m
+}
+
+fn solve_one(
+    map: &[&[u8]],
+    row_increment: usize,
+    col_increment: usize,
+) -> usize {
+    let map_tree_tile: u8 = b'#';
+
+    let map_height = map.len();
+    let map_width = map.first().unwrap().len();
+
+    let mut tree_count: usize = 0;
+    let mut position_row: usize = 0;
+    let mut position_col: usize = 0;
+    loop {
+        position_row += row_increment;
+        position_col = add_mod(position_col, col_increment, map_width);
+
+        if map_height <= position_row {
+            break;
+        }
+
+        let map_tile = map[position_row][position_col];
+        if map_tile == map_tree_tile {
+            tree_count += 1;
+        }
+    }
+
+    tree_count
+}
+
+fn solve(map: &[&[u8]], increments: &[(usize, usize)]) -> usize {
+    increments
+        .iter()
+        .map(|&(down, right)| solve_one(map, down, right))
+        .product()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<&'a [u8]>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.lines().map(|line| line.as_bytes()).collect()
+    }
+
+    fn part_1(map: &Self::Input<'_>) -> Self::Answer1 {
+        solve_one(map, 1, 3)
+    }
+
+    fn part_2(map: &Self::Input<'_>) -> Self::Answer2 {
+        let increments = [(1, 1), (1, 3), (1, 5), (1, 7), (2, 1)];
+        solve(map, &increments)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
+
+#[cfg(test)]
+mod tests {}
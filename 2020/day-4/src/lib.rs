@@ -1,13 +1,10 @@
-#![feature(str_split_once)]
-
 use std::collections::HashMap;
-use std::env;
-use std::fs;
 
+use aoc_common::{print_answers, Part, Solution};
 use regex::Regex;
 
 #[derive(Debug)]
-struct Passport<'a> {
+pub struct Passport<'a> {
     fields: HashMap<&'a str, &'a str>,
 }
 
@@ -23,7 +20,7 @@ fn parse_passport<'a>(paragraph: &[&'a str]) -> Passport<'a> {
 
 fn parse_passports<'a>(content: &'a str) -> Vec<Passport<'a>> {
     let lines: Vec<&str> = content.lines().collect();
-    let paragraphs = lines.split(|s: &&str| s.len() == 0);
+    let paragraphs = lines.split(|s: &&str| s.is_empty());
     let passports: Vec<Passport> = paragraphs
         .map(|paragraph: &[&str]| parse_passport(paragraph))
         .collect();
@@ -42,9 +39,7 @@ fn is_height(height_str: &str) -> bool {
     let units: &[(&str, u16, u16)] = &[("cm", 150, 193), ("in", 59, 76)];
 
     let option_unit = units
-        .iter()
-        .filter(|(unit_suffix, _, _)| height_str.ends_with(unit_suffix))
-        .next();
+        .iter().find(|(unit_suffix, _, _)| height_str.ends_with(unit_suffix));
     match option_unit {
         None => false,
         Some(&(_, min, max)) => {
@@ -70,8 +65,19 @@ fn is_eye_color(value: &str) -> bool {
     valid_colors.contains(&value)
 }
 
+type FieldRule = (&'static str, fn(&str) -> bool);
+
+const REQUIRED_FIELDS: &[&str] =
+    &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+fn has_required_fields(passport: &Passport) -> bool {
+    REQUIRED_FIELDS
+        .iter()
+        .all(|field_key| passport.fields.contains_key(field_key))
+}
+
 fn is_passport_valid(passport: &Passport) -> bool {
-    let required_fields: &[(&str, fn(&str) -> bool)] = &[
+    let required_fields: &[FieldRule] = &[
         ("byr", |s: &str| is_year(s, 1920, 2002)),
         ("iyr", |s: &str| is_year(s, 2010, 2020)),
         ("eyr", |s: &str| is_year(s, 2020, 2030)),
@@ -90,26 +96,32 @@ fn is_passport_valid(passport: &Passport) -> bool {
         })
 }
 
-fn solve(content: &str) {
-    let passports = parse_passports(content);
-    let valid_passport_count = passports
-        .iter()
-        .filter(|passport| is_passport_valid(passport))
-        .count();
-    println!("{}", valid_passport_count);
+fn solve(passports: &[Passport], is_valid: fn(&Passport) -> bool) -> usize {
+    passports.iter().filter(|passport| is_valid(passport)).count()
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Passport<'a>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_passports(content)
+    }
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn part_1(passports: &Self::Input<'_>) -> Self::Answer1 {
+        solve(passports, has_required_fields)
+    }
+
+    fn part_2(passports: &Self::Input<'_>) -> Self::Answer2 {
+        solve(passports, is_passport_valid)
+    }
+}
 
-    solve(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -133,8 +145,8 @@ mod tests {
 
     #[test]
     fn is_regex_test() {
-        assert_eq!(is_regex("#fffff", "^#[0-9a-f]{6}$"), false);
-        assert_eq!(is_regex("#ffffff", "^#[0-9a-f]{6}$"), true);
-        assert_eq!(is_regex("#fffffff", "^#[0-9a-f]{6}$"), false);
+        assert!(!is_regex("#fffff", "^#[0-9a-f]{6}$"));
+        assert!(is_regex("#ffffff", "^#[0-9a-f]{6}$"));
+        assert!(!is_regex("#fffffff", "^#[0-9a-f]{6}$"));
     }
 }
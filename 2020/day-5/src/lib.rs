@@ -1,5 +1,4 @@
-use std::env;
-use std::fs;
+use aoc_common::{print_answers, Part, Solution};
 
 type SeatId = u16;
 
@@ -31,28 +30,32 @@ fn find_missing_seat(mut seats_id: Vec<SeatId>) -> SeatId {
     panic!("The missing seat could not be found")
 }
 
-fn solve(content: &str) {
-    let seats_id: Vec<SeatId> = content
-        .lines()
-        .map(|line_str: &str| line_str.as_bytes())
-        .map(|line: &[u8]| compute_seat_id(line))
-        .collect();
+pub struct Puzzle;
 
-    let seat_id = find_missing_seat(seats_id);
-    println!("Seat id: {}", seat_id)
-}
+impl Solution for Puzzle {
+    type Input<'a> = Vec<SeatId>;
+    type Answer1 = SeatId;
+    type Answer2 = SeatId;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+    fn parse(content: &str) -> Self::Input<'_> {
+        content
+            .lines()
+            .map(|line_str: &str| line_str.as_bytes())
+            .map(|line: &[u8]| compute_seat_id(line))
+            .collect()
+    }
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+    fn part_1(seats_id: &Self::Input<'_>) -> Self::Answer1 {
+        *seats_id.iter().max().unwrap()
+    }
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn part_2(seats_id: &Self::Input<'_>) -> Self::Answer2 {
+        find_missing_seat(seats_id.clone())
+    }
+}
 
-    solve(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
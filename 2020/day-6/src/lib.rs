@@ -0,0 +1,79 @@
+use aoc_common::{print_answers, Part, Solution};
+
+fn summarize<'a, I>(questions_iter: I) -> [usize; 26]
+where
+    I: IntoIterator<Item = &'a u8>,
+{
+    questions_iter.into_iter().fold(
+        [0; 26],
+        |mut form_summary: [usize; 26], &question: &u8| {
+            let question_index: usize = (question - b'a').into();
+            form_summary[question_index] += 1;
+            form_summary
+        },
+    )
+}
+
+fn count_common_answers<'a, I>(number_people: usize, form_summary: I) -> usize
+where
+    I: IntoIterator<Item = &'a usize>,
+{
+    form_summary
+        .into_iter()
+        .filter(|&&question_count: &&usize| question_count == number_people)
+        .count()
+}
+
+type Group = (usize, [usize; 26]);
+
+fn solve(
+    groups: &[Group],
+    count_answers: fn(usize, &[usize; 26]) -> usize,
+) -> usize {
+    groups
+        .iter()
+        .map(|(number_people, form_summary)| {
+            count_answers(*number_people, form_summary)
+        })
+        .sum()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Group>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        let lines: Vec<&str> = content.lines().collect();
+        lines
+            .split(|&line: &&str| line.is_empty())
+            .map(|paragraph: &[&str]| {
+                let number_people = paragraph.len();
+                let questions_iter =
+                    paragraph.iter().flat_map(|line| line.as_bytes());
+                (number_people, summarize(questions_iter))
+            })
+            .collect()
+    }
+
+    fn part_1(groups: &Self::Input<'_>) -> Self::Answer1 {
+        solve(groups, |_, form_summary| {
+            form_summary.iter().filter(|&&count| count > 0).count()
+        })
+    }
+
+    fn part_2(groups: &Self::Input<'_>) -> Self::Answer2 {
+        solve(groups, |number_people, form_summary| {
+            count_common_answers(number_people, form_summary)
+        })
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
+
+#[cfg(test)]
+mod tests {}
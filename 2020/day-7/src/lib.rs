@@ -1,10 +1,9 @@
 use std::collections::HashSet;
 use std::collections::HashMap;
-use std::env;
-use std::fs;
 
 #[macro_use]
 extern crate lazy_static;
+use aoc_common::{print_answers, Part, Solution};
 use regex::Regex;
 
 type BagMap<'a> = HashMap<&'a str, Vec<(usize, &'a str)>>;
@@ -79,7 +78,7 @@ fn recurse_part_1<'a>(start_bag_name: &'a str, bag_map: &'a BagMap, set: &mut Ha
     }
 
     match bag_map.get(start_bag_name) {
-        None => return,
+        None => (),
         Some(next_bags) => {
             for next_bag in next_bags {
                 let &(_, next_bag_name) = next_bag;
@@ -90,41 +89,43 @@ fn recurse_part_1<'a>(start_bag_name: &'a str, bag_map: &'a BagMap, set: &mut Ha
     }
 }
 
-fn solve_part_1(content: &str) {
-    let normal_bag_map: HashMap<_, _> =
-        content.lines().map(|line| parse_bag_line(line)).collect();
-
-    let reversed_bag_map = create_reverse_bag_map(&normal_bag_map);
+fn solve_part_1(normal_bag_map: &BagMap) -> usize {
+    let reversed_bag_map = create_reverse_bag_map(normal_bag_map);
 
     let start_bag_name = "shiny gold";
     let mut set = HashSet::new();
     recurse_part_1(start_bag_name, &reversed_bag_map, &mut set);
 
-    println!("Part 1: {}", set.len());
+    set.len()
 }
 
-fn solve_part_2(content: &str) {
-    let bag_map: HashMap<_, _> =
-        content.lines().map(|line| parse_bag_line(line)).collect();
-
+fn solve_part_2(bag_map: &BagMap) -> usize {
     let start_bag_name = "shiny gold";
-    let count = recurse(start_bag_name, &bag_map);
-    
-    println!("Part 2: {}", count)
+    recurse(start_bag_name, bag_map)
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = BagMap<'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Self::Input<'_> {
+        content.lines().map(|line| parse_bag_line(line)).collect()
+    }
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+    fn part_1(bag_map: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(bag_map)
+    }
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn part_2(bag_map: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(bag_map)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
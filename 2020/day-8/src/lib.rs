@@ -1,12 +1,9 @@
-#![feature(str_split_once)]
-
-use std::env;
-use std::fs;
+use aoc_common::{print_answers, Part, Solution};
 
 type Argument = i64;
 
 #[derive(Debug)]
-enum Instruction {
+pub enum Instruction {
     Nop(Argument),
     Acc(Argument),
     Jmp(Argument),
@@ -69,6 +66,14 @@ fn execute_jmp(argument: &Argument, program: &mut Program) {
         add_to_usize(program.next_instruction_index, *argument);
 }
 
+fn execute(instruction: &Instruction, program: &mut Program) {
+    match instruction {
+        Instruction::Nop(_) => execute_nop(program),
+        Instruction::Acc(argument) => execute_acc(argument, program),
+        Instruction::Jmp(argument) => execute_jmp(argument, program),
+    }
+}
+
 fn try_replace(
     instructions: &Instructions,
     program: &Program,
@@ -114,33 +119,44 @@ fn accumulate_before_loop(
         }
         *executed_instruction = true;
 
-        match instruction {
-            Instruction::Nop(_) => execute_nop(&mut program),
-            Instruction::Acc(argument) => execute_acc(argument, &mut program),
-            Instruction::Jmp(argument) => execute_jmp(argument, &mut program),
-        }
+        execute(instruction, &mut program);
     }
 }
 
-fn solve(content: &str) {
-    let instructions = parse_instructions(content);
-    let program = Program::new(instructions.len());
-    let accumulation =
-        accumulate_before_loop(program, &instructions, true).unwrap();
-    println!("{}", accumulation)
+fn accumulate_until_loop(instructions: &Instructions) -> Argument {
+    let mut program = Program::new(instructions.len());
+    while !program.executed_instructions[program.next_instruction_index] {
+        program.executed_instructions[program.next_instruction_index] = true;
+        let instruction = &instructions[program.next_instruction_index];
+        execute(instruction, &mut program);
+    }
+
+    program.accumulation
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Instructions;
+    type Answer1 = Argument;
+    type Answer2 = Argument;
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_instructions(content)
+    }
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn part_1(instructions: &Self::Input<'_>) -> Self::Answer1 {
+        accumulate_until_loop(instructions)
+    }
+
+    fn part_2(instructions: &Self::Input<'_>) -> Self::Answer2 {
+        let program = Program::new(instructions.len());
+        accumulate_before_loop(program, instructions, true).unwrap()
+    }
+}
 
-    solve(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
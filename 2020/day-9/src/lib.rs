@@ -1,6 +1,5 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::collections::VecDeque;
-use std::env;
-use std::fs;
 
 type Number = u64;
 type Numbers = Vec<Number>;
@@ -73,31 +72,29 @@ fn find_weakness_num(numbers: &Numbers, target_overall_sum: Number) -> Number {
     }
 }
 
-fn solve_part_1(content: &str) {
-    let numbers = parse_numbers(content);
-    let invalid_number = find_first_invalid_number(&numbers, 25).unwrap();
-    println!("Part 1: {:?}", invalid_number)
-}
+pub struct Puzzle;
 
-fn solve_part_2(content: &str) {
-    let numbers = parse_numbers(content);
-    let invalid_number = find_first_invalid_number(&numbers, 25).unwrap();
-    let weakness_sum = find_weakness_num(&numbers, invalid_number);
-    println!("Part 2: {:?}", weakness_sum)
-}
+impl Solution for Puzzle {
+    type Input<'a> = Numbers;
+    type Answer1 = Number;
+    type Answer2 = Number;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+    fn parse(content: &str) -> Self::Input<'_> {
+        parse_numbers(content)
+    }
 
-    let default_filename: &str = "./res/input.txt";
-    let filename: &str =
-        args.get(1).map(|s| s.as_ref()).unwrap_or(default_filename);
+    fn part_1(numbers: &Self::Input<'_>) -> Self::Answer1 {
+        find_first_invalid_number(numbers, 25).unwrap()
+    }
 
-    let content = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
+    fn part_2(numbers: &Self::Input<'_>) -> Self::Answer2 {
+        let invalid_number = find_first_invalid_number(numbers, 25).unwrap();
+        find_weakness_num(numbers, invalid_number)
+    }
+}
 
-    solve_part_1(&content);
-    solve_part_2(&content);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -0,0 +1,40 @@
+use aoc_common::{print_answers, Part, Solution};
+
+fn count_increases(numbers: &[u64], window_size: usize) -> usize {
+    let mut count = 0;
+    for index in window_size..numbers.len() {
+        if numbers[index - window_size] < numbers[index] {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<u64>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(str::parse::<u64>)
+            .map(Result::unwrap)
+            .collect()
+    }
+
+    fn part_1(numbers: &Self::Input<'_>) -> Self::Answer1 {
+        count_increases(numbers, 1)
+    }
+
+    fn part_2(numbers: &Self::Input<'_>) -> Self::Answer2 {
+        count_increases(numbers, 3)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
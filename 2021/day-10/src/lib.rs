@@ -1,6 +1,7 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::collections::HashMap;
 
-enum Analysis {
+pub enum Analysis {
     Complete,
     Incomplete(Vec<u8>),
     Corrupted(u8),
@@ -93,11 +94,28 @@ fn solve_part_2(analyses: &[Analysis]) -> u64 {
     scores[scores.len() / 2]
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let analyses = analyze_input(input);
-    println!("Part 1: {}", solve_part_1(&analyses));
-    println!("Part 2: {}", solve_part_2(&analyses));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Analysis>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        analyze_input(input)
+    }
+
+    fn part_1(analyses: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(analyses)
+    }
+
+    fn part_2(analyses: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(analyses)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
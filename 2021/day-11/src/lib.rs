@@ -1,7 +1,8 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::{collections::HashSet, mem::swap, str::FromStr};
 
 #[derive(Clone, Debug)]
-struct Cavern {
+pub struct Cavern {
     width: usize,
     height: usize,
     octopuses: Vec<u8>,
@@ -123,11 +124,28 @@ fn solve_part_2(mut cavern: Cavern) -> usize {
         .unwrap()
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let cavern = Cavern::from_str(input).unwrap();
-    println!("Part 1: {}", solve_part_1(cavern.clone()));
-    println!("Part 2: {}", solve_part_2(cavern));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Cavern;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Cavern::from_str(input).unwrap()
+    }
+
+    fn part_1(cavern: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(cavern.clone())
+    }
+
+    fn part_2(cavern: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(cavern.clone())
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::{
     collections::{HashMap, HashSet},
     fmt::Display,
@@ -53,7 +54,7 @@ impl Display for Cave {
 }
 
 #[derive(Debug)]
-struct CaveSystem {
+pub struct CaveSystem {
     connections_per_cave: HashMap<Cave, HashSet<Cave>>,
 }
 
@@ -162,11 +163,28 @@ fn solve_part_2(cave_system: &CaveSystem) -> u64 {
     )
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let cave_system: CaveSystem = input.parse().unwrap();
-    println!("Part 1: {}", solve_part_1(&cave_system));
-    println!("Part 2: {}", solve_part_2(&cave_system));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = CaveSystem;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.parse().unwrap()
+    }
+
+    fn part_1(cave_system: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(cave_system)
+    }
+
+    fn part_2(cave_system: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(cave_system)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
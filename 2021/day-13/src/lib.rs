@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::{collections::HashSet, fmt::Display, str::FromStr};
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -49,7 +50,7 @@ impl Position {
 }
 
 #[derive(Debug)]
-struct Paper {
+pub struct Paper {
     dots: HashSet<Position>,
 }
 
@@ -63,7 +64,7 @@ impl FromStr for Paper {
 }
 
 #[derive(Clone, Copy, Debug)]
-enum Fold {
+pub enum Fold {
     Left(usize),
     Up(usize),
 }
@@ -127,15 +128,55 @@ fn parse(s: &str) -> (Paper, Vec<Fold>) {
     (paper, folds)
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let (initial_paper, folds) = parse(input);
+fn fold_all(paper: Paper, folds: Vec<Fold>) -> Paper {
+    folds
+        .into_iter()
+        .fold(paper, |temp_paper, fold| temp_paper.fold(fold))
+}
 
-    let folded_paper = initial_paper.fold(*folds.first().unwrap());
-    println!("Part 1: {}", folded_paper.dots.len());
+pub struct Puzzle;
 
-    let final_paper = folds
-        .into_iter()
-        .fold(folded_paper, |temp_paper, fold| temp_paper.fold(fold));
-    print!("Part 2:\n{}", final_paper);
+impl Solution for Puzzle {
+    type Input<'a> = (Paper, Vec<Fold>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        let (initial_paper, folds) = input;
+        initial_paper.fold(*folds.first().unwrap()).dots.len()
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        let (initial_paper, folds) = input;
+        let folded_paper = initial_paper.fold(*folds.first().unwrap());
+        let final_paper = fold_all(folded_paper, folds.clone());
+        match ocr::recognize(&final_paper.to_string()) {
+            Ok(letters) => letters,
+            Err(error) => format!("{}{}", final_paper, error),
+        }
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_part_2() {
+        let input = include_str!("./input.txt");
+        let (paper, folds) = parse(input);
+        let paper = fold_all(paper, folds);
+        assert_eq!(
+            ocr::recognize(&paper.to_string()),
+            Ok("LRFJBJEH".to_string())
+        );
+    }
 }
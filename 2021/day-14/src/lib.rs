@@ -1,10 +1,11 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::{collections::HashMap, str::FromStr};
 
 type Element = u8;
 type ElementPair = (Element, Element);
 
 #[derive(Clone, Debug)]
-struct Polymer {
+pub struct Polymer {
     count_per_pair: HashMap<ElementPair, u64>,
     first_element: Element,
     last_element: Element,
@@ -126,11 +127,30 @@ fn solve(initial_polymer: Polymer, rules: &Rules, number_steps: usize) -> u64 {
     max_count - min_count
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let (polymer, rules) = parse(input);
-    println!("Part 1: {}", solve(polymer.clone(), &rules, 10));
-    println!("Part 2: {}", solve(polymer, &rules, 40));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (Polymer, Rules);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        let (polymer, rules) = input;
+        solve(polymer.clone(), rules, 10)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        let (polymer, rules) = input;
+        solve(polymer.clone(), rules, 40)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::collections::{BinaryHeap, HashSet};
 
 type RiskLevel = u64;
@@ -143,11 +144,28 @@ fn solve(map: &Map, repeat: usize) -> RiskLevel {
     panic!("could not reach exit");
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let map = parse_map(input);
-    println!("Part 1: {}", solve(&map, 1));
-    println!("Part 2: {}", solve(&map, 5));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Map;
+    type Answer1 = RiskLevel;
+    type Answer2 = RiskLevel;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_map(input)
+    }
+
+    fn part_1(map: &Self::Input<'_>) -> Self::Answer1 {
+        solve(map, 1)
+    }
+
+    fn part_2(map: &Self::Input<'_>) -> Self::Answer2 {
+        solve(map, 5)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
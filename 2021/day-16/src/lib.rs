@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 use std::collections::HashMap;
 
@@ -10,7 +11,7 @@ enum PacketValue {
 }
 
 #[derive(Debug)]
-struct Packet {
+pub struct Packet {
     version: u64,
     value: PacketValue,
 }
@@ -200,9 +201,26 @@ fn solve_part_2(packet: &Packet) -> u64 {
     }
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let packet = parse(input);
-    println!("Part 1: {}", solve_part_1(&packet));
-    println!("Part 2: {}", solve_part_2(&packet));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Packet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_1(packet: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(packet)
+    }
+
+    fn part_2(packet: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(packet)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
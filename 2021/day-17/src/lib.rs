@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use regex::Regex;
 
 #[derive(Debug, Default)]
@@ -19,7 +20,7 @@ struct Target {
 }
 
 #[derive(Debug, Default)]
-struct Results {
+pub struct Results {
     max_y: i64,
     number: u64,
 }
@@ -98,11 +99,28 @@ fn solve(target: Target) -> Results {
     results
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let target = parse_target(input);
-    let results = solve(target);
-    dbg!(results);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Results;
+    type Answer1 = i64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        solve(parse_target(input))
+    }
+
+    fn part_1(results: &Self::Input<'_>) -> Self::Answer1 {
+        results.max_y
+    }
+
+    fn part_2(results: &Self::Input<'_>) -> Self::Answer2 {
+        results.number
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::{fmt::Display, ops::Add};
 
 #[derive(Clone, Debug)]
@@ -64,7 +65,7 @@ impl Element {
 }
 
 #[derive(Clone, Debug)]
-struct Number {
+pub struct Number {
     elements: [Element; 2],
 }
 
@@ -194,7 +195,7 @@ impl<'a> ParseContext<'a> {
 }
 
 fn is_ascii_numeric(byte: u8) -> bool {
-    (b'0'..=b'9').contains(&byte)
+    byte.is_ascii_digit()
 }
 
 fn parse_element(context: &mut ParseContext) -> Element {
@@ -269,9 +270,26 @@ fn solve_part_2(numbers: Vec<Number>) -> u64 {
     greatest_magnitude
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let numbers = parse(input);
-    println!("Part 1: {}", solve_part_1(numbers.clone()));
-    println!("Part 2: {}", solve_part_2(numbers));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Number>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_1(numbers: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(numbers.clone())
+    }
+
+    fn part_2(numbers: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(numbers.clone())
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 use nalgebra::{Matrix3, Point3, Vector3};
 use std::collections::HashMap;
@@ -42,7 +43,7 @@ fn generate_orientations() -> Vec<Orientation> {
 type BeaconSet = Vec<Beacon>;
 
 #[derive(Debug)]
-struct Scanner {
+pub struct Scanner {
     position: Point,
     beacons: BeaconSet,
 }
@@ -169,12 +170,28 @@ fn solve_part_2(identified_scannners: &[Scanner]) -> i64 {
         .unwrap()
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let beacon_sets = parse_beacons(input);
-    let identified_scannners = identify_scanners(beacon_sets);
-    println!("Part 1: {}", solve_part_1(&identified_scannners));
-    println!("Part 2: {}", solve_part_2(&identified_scannners));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Scanner>;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        identify_scanners(parse_beacons(input))
+    }
+
+    fn part_1(scanners: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(scanners)
+    }
+
+    fn part_2(scanners: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(scanners)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,7 +1,8 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::str::FromStr;
 
 #[derive(Clone, Copy, Debug)]
-enum Command {
+pub enum Command {
     Forward(u64),
     Up(u64),
     Down(u64),
@@ -85,12 +86,28 @@ where
     submarine.position * submarine.depth
 }
 
-fn main() -> Result<(), &'static str> {
-    let input = include_str!("./input.txt");
-    let commands = parse(input)?;
-    println!("Part 1: {}", solve(&commands, Submarine::follow_basic));
-    println!("Part 2: {}", solve(&commands, Submarine::follow_complex));
-    Ok(())
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Command>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input).unwrap()
+    }
+
+    fn part_1(commands: &Self::Input<'_>) -> Self::Answer1 {
+        solve(commands, Submarine::follow_basic)
+    }
+
+    fn part_2(commands: &Self::Input<'_>) -> Self::Answer2 {
+        solve(commands, Submarine::follow_complex)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use std::iter::repeat;
+use aoc_common::{print_answers, Part, Solution};
 
 type Algorithm<'a> = &'a [u8];
 type Pixel = (usize, usize);
@@ -10,16 +10,18 @@ struct Image {
 }
 
 fn get_surronding_square(pixel: &Pixel) -> [Pixel; 9] {
+    let (row, col) = *pixel;
+    let (up, left) = (row.wrapping_sub(1), col.wrapping_sub(1));
     [
-        (pixel.0 - 1, pixel.1 - 1),
-        (pixel.0 - 1, pixel.1),
-        (pixel.0 - 1, pixel.1 + 1),
-        (pixel.0, pixel.1 - 1),
-        *pixel,
-        (pixel.0, pixel.1 + 1),
-        (pixel.0 + 1, pixel.1 - 1),
-        (pixel.0 + 1, pixel.1),
-        (pixel.0 + 1, pixel.1 + 1),
+        (up, left),
+        (up, col),
+        (up, col + 1),
+        (row, left),
+        (row, col),
+        (row, col + 1),
+        (row + 1, left),
+        (row + 1, col),
+        (row + 1, col + 1),
     ]
 }
 
@@ -68,7 +70,7 @@ fn step_image(old_image: &Image, algorithm: Algorithm) -> Image {
     next_image
 }
 
-fn parse(input: &str) -> (Algorithm, Image) {
+fn parse(input: &str) -> (Algorithm<'_>, Image) {
     let mut lines = input.lines();
     let algorithm = lines.next().unwrap().trim().as_bytes();
     lines.next().unwrap();
@@ -94,16 +96,14 @@ fn parse(input: &str) -> (Algorithm, Image) {
 fn prepare_pixels(pixels: Vec<Vec<bool>>, steps: usize) -> Vec<Vec<bool>> {
     let width = pixels.first().unwrap().len();
 
-    repeat(vec![false; width + steps * 2])
-        .take(steps)
+    std::iter::repeat_n(vec![false; width + steps * 2], steps)
         .chain(pixels.into_iter().map(|line| {
-            repeat(false)
-                .take(steps)
-                .chain(line.into_iter())
-                .chain(repeat(false).take(steps))
+            std::iter::repeat_n(false, steps)
+                .chain(line)
+                .chain(std::iter::repeat_n(false, steps))
                 .collect()
         }))
-        .chain(repeat(vec![false; width + steps * 2]).take(steps))
+        .chain(std::iter::repeat_n(vec![false; width + steps * 2], steps))
         .collect()
 }
 
@@ -131,10 +131,28 @@ fn solve_part_2(input: &str) -> usize {
     solve(input, 50)
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    println!("Part 1: {}", solve_part_1(input));
-    println!("Part 2: {}", solve_part_2(input));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(input)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
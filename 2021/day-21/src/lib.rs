@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::collections::HashMap;
 
 #[derive(Default)]
@@ -66,7 +67,6 @@ struct Results {
 }
 
 fn play_part_1(die: &mut DeterministicDie, situation: Situation) -> Situation {
-    let mut results = Results::default();
     let roll: u64 = [die.roll(), die.roll(), die.roll()].into_iter().sum();
     let player_1 = situation.players[0].clone();
     let player_2 = situation.players[1].clone();
@@ -83,7 +83,6 @@ fn play_part_1(die: &mut DeterministicDie, situation: Situation) -> Situation {
     };
 
     if next_score >= 1000 {
-        results.wins[0] += 1;
         return next_situation;
     }
 
@@ -141,7 +140,7 @@ fn solve_part_1(positions: (u64, u64)) -> u64 {
 }
 
 fn solve_part_2(positions: (u64, u64)) -> u64 {
-    let mut die = DiracDie::default();
+    let mut die = DiracDie;
     let mut cache = Cache::default();
     let results = play_part_2(&mut die, Situation::new(positions), &mut cache);
     results.wins.into_iter().max().unwrap()
@@ -158,11 +157,28 @@ fn parse(input: &str) -> (u64, u64) {
     (positions.next().unwrap(), positions.next().unwrap())
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let positions = parse(input);
-    println!("Part 1: {}", solve_part_1(positions));
-    println!("Part 2: {}", solve_part_2(positions));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (u64, u64);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_1(positions: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(*positions)
+    }
+
+    fn part_2(positions: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(*positions)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
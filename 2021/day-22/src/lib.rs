@@ -1,9 +1,10 @@
-use std::{fmt::Display, iter::repeat, str::FromStr};
+use std::{fmt::Display, str::FromStr};
 
+use aoc_common::{print_answers, Part, Solution};
 use regex::Regex;
 
 #[derive(Clone, Debug)]
-struct Range {
+pub struct Range {
     x_min: isize,
     x_max: isize,
     y_min: isize,
@@ -146,9 +147,9 @@ impl Display for Range {
 }
 
 fn solve(ranges: &[Range]) -> usize {
-    let mut reactor = Reactor::from_ranges(&ranges);
+    let mut reactor = Reactor::from_ranges(ranges);
     for range in ranges {
-        for tuple in iter_regions_indexes(&reactor, &range) {
+        for tuple in iter_regions_indexes(&reactor, range) {
             let region = get_region_mut(&mut reactor, tuple);
             region.is_lit = range.on;
         }
@@ -182,14 +183,12 @@ fn is_in_initialization(range: &Range) -> bool {
         range.z_max,
     ]
     .into_iter()
-    .all(|n| -50 <= n && n <= 50)
+    .all(|n| (-50..=50).contains(&n))
 }
 
 fn solve_part_1(ranges: &[Range]) -> usize {
     let filtered_ranges: Vec<Range> = ranges
-        .iter()
-        .cloned()
-        .filter(is_in_initialization)
+        .iter().filter(|&x| is_in_initialization(x)).cloned()
         .collect();
 
     solve(&filtered_ranges)
@@ -199,9 +198,26 @@ fn solve_part_2(ranges: &[Range]) -> usize {
     solve(ranges)
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let ranges = parse(input);
-    println!("Part 1: {}", solve_part_1(&ranges));
-    println!("Part 2: {}", solve_part_2(&ranges));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Range>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_1(ranges: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(ranges)
+    }
+
+    fn part_2(ranges: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(ranges)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
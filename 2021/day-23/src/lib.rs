@@ -1,9 +1,10 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::collections::{BinaryHeap, HashSet};
 
 type Amphipod = u8;
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-struct Burrow {
+pub struct Burrow {
     opens: [Option<Amphipod>; 7],
     rooms: [Vec<Option<Amphipod>>; 4],
 }
@@ -263,11 +264,28 @@ fn solve_part_2(mut burrow: Burrow) -> u64 {
     solve(burrow).unwrap()
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let burrow = parse(input);
-    println!("Part 1: {}", solve_part_1(burrow.clone()));
-    println!("Part 2: {}", solve_part_2(burrow));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Burrow;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_1(burrow: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(burrow.clone())
+    }
+
+    fn part_2(burrow: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(burrow.clone())
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::iter::once;
 
 #[derive(Debug)]
@@ -46,9 +47,9 @@ fn concat_number(
     after_right_digits: Vec<i64>,
 ) -> Vec<i64> {
     once(left_digit)
-        .chain(after_left_digits.into_iter())
+        .chain(after_left_digits)
         .chain(once(right_digit))
-        .chain(after_right_digits.into_iter())
+        .chain(after_right_digits)
         .collect()
 }
 
@@ -100,12 +101,28 @@ fn build_number(number: Vec<i64>) -> u64 {
     number.into_iter().fold(0u64, |n, d| n * 10 + d as u64)
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let groups = parse(input);
-    let results = do_something(Context { groups: &groups });
-    let max_number = build_number(results.max);
-    println!("Part 1: {}", max_number);
-    let min_number = build_number(results.min);
-    println!("Part 2: {}", min_number);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (u64, u64);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let groups = parse(input);
+        let results = do_something(Context { groups: &groups });
+        (build_number(results.max), build_number(results.min))
+    }
+
+    fn part_1(numbers: &Self::Input<'_>) -> Self::Answer1 {
+        numbers.0
+    }
+
+    fn part_2(numbers: &Self::Input<'_>) -> Self::Answer2 {
+        numbers.1
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
@@ -1,15 +1,13 @@
 use std::fmt::Display;
 use std::{collections::HashSet, str::FromStr};
 
+use aoc_common::{print_answers, NoPuzzle, Part, Solution};
 use itertools::chain;
-use nalgebra::point;
-use nalgebra::{vector, Point2, Vector2};
 
 type Point = (usize, usize);
-type Direction = Vector2<usize>;
 type Cucumber = Point;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Herd {
     character: char,
     direction: Point,
@@ -38,8 +36,8 @@ impl Herd {
     }
 }
 
-#[derive(Debug)]
-struct Ocean {
+#[derive(Clone, Debug)]
+pub struct Ocean {
     herds: Vec<Herd>,
     width: usize,
     height: usize,
@@ -164,9 +162,26 @@ fn solve(mut ocean: Ocean) -> usize {
     panic!()
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let ocean = Ocean::from_str(input).unwrap();
-    println!("This might take a while...");
-    println!("Result: {}", solve(ocean));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Ocean;
+    type Answer1 = usize;
+    type Answer2 = NoPuzzle;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Ocean::from_str(input).unwrap()
+    }
+
+    fn part_1(ocean: &Self::Input<'_>) -> Self::Answer1 {
+        solve(ocean.clone())
+    }
+
+    fn part_2(_ocean: &Self::Input<'_>) -> Self::Answer2 {
+        NoPuzzle
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
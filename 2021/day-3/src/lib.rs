@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::mem::swap;
 
 fn solve_part_1(input: &str) -> u64 {
@@ -80,10 +81,28 @@ fn solve_part_2(input: &str) -> u64 {
     oxygen_generator_rating * life_support_rating
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    println!("Part 1: {}", solve_part_1(input));
-    println!("Part 2: {}", solve_part_2(input));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = &'a str;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(input)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::{mem::swap, num::ParseIntError, str::FromStr};
 
 #[derive(Clone, Debug)]
@@ -19,7 +20,7 @@ impl FromStr for Cell {
 }
 
 #[derive(Clone, Debug)]
-struct Board {
+pub struct Board {
     cells: Vec<Cell>,
 }
 
@@ -70,7 +71,7 @@ impl Board {
     }
 }
 
-fn run(numbers: &[u64], boards: Vec<Board>, find_first: bool) -> u64 {
+fn play(numbers: &[u64], boards: Vec<Board>, find_first: bool) -> u64 {
     let mut current_boards: Vec<Board> = boards;
     let mut remaining_boards: Vec<Board> = Vec::with_capacity(current_boards.len());
 
@@ -112,12 +113,30 @@ fn parse(input: &str) -> (Vec<u64>, Vec<Board>) {
     (numbers, boards)
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let (numbers, boards) = parse(input);
-    let boards_cloned = boards.clone();
-    println!("Part 1: {}", run(&numbers, boards, true));
-    println!("Part 2: {}", run(&numbers, boards_cloned, false));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (Vec<u64>, Vec<Board>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        let (numbers, boards) = input;
+        play(numbers, boards.clone(), true)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        let (numbers, boards) = input;
+        play(numbers, boards.clone(), false)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -128,13 +147,13 @@ mod tests {
     fn example_part_1() {
         let input = include_str!("./example.txt");
         let (numbers, boards) = parse(input);
-        assert_eq!(run(&numbers, boards, true), 4512);
+        assert_eq!(play(&numbers, boards, true), 4512);
     }
 
     #[test]
     fn example_part_2() {
         let input = include_str!("./example.txt");
         let (numbers, boards) = parse(input);
-        assert_eq!(run(&numbers, boards, false), 1924);
+        assert_eq!(play(&numbers, boards, false), 1924);
     }
 }
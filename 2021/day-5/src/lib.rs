@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::{
     cmp::Ordering,
     fmt::Display,
@@ -13,7 +14,7 @@ struct Position {
 }
 
 #[derive(Debug)]
-enum ParsePositionError {
+pub enum ParsePositionError {
     SplitError,
     ParseIntError(ParseIntError),
 }
@@ -30,13 +31,13 @@ impl FromStr for Position {
 }
 
 #[derive(Debug)]
-struct Segment {
+pub struct Segment {
     start: Position,
     end: Position,
 }
 
 #[derive(Debug)]
-enum ParseSegmentError {
+pub enum ParseSegmentError {
     SplitError,
     ParsePositionError(ParsePositionError),
 }
@@ -128,7 +129,6 @@ impl Iterator for IterPositions {
 #[derive(Debug)]
 struct Grid {
     width: usize,
-    height: usize,
     cells: Vec<u64>,
 }
 
@@ -137,7 +137,6 @@ impl Grid {
         let cells = vec![0; width * height];
         Self {
             width,
-            height,
             cells,
         }
     }
@@ -228,11 +227,28 @@ fn solve(segments: &[Segment], keep_diagonals: bool) -> usize {
     intersection_count
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let segments = parse(input);
-    println!("Part 1: {}", solve(&segments, false));
-    println!("Part 2: {}", solve(&segments, true));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Segment>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_1(segments: &Self::Input<'_>) -> Self::Answer1 {
+        solve(segments, false)
+    }
+
+    fn part_2(segments: &Self::Input<'_>) -> Self::Answer2 {
+        solve(segments, true)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
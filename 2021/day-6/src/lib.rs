@@ -1,7 +1,8 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::{num::ParseIntError, str::FromStr};
 
 #[derive(Clone, Debug)]
-struct Aquarium {
+pub struct Aquarium {
     tanks: [u64; 9],
     tank_index: usize,
 }
@@ -43,11 +44,28 @@ fn solve(mut aquarium: Aquarium, days: u64) -> u64 {
     aquarium.tanks.iter().sum::<u64>()
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let aquarium = Aquarium::from_str(input).unwrap();
-    println!("Part 1: {}", solve(aquarium.clone(), 80));
-    println!("Part 2: {}", solve(aquarium, 256));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Aquarium;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Aquarium::from_str(input).unwrap()
+    }
+
+    fn part_1(aquarium: &Self::Input<'_>) -> Self::Answer1 {
+        solve(aquarium.clone(), 80)
+    }
+
+    fn part_2(aquarium: &Self::Input<'_>) -> Self::Answer2 {
+        solve(aquarium.clone(), 256)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
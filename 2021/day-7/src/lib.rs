@@ -1,3 +1,5 @@
+use aoc_common::{print_answers, Part, Solution};
+
 fn parse(input: &str) -> Vec<u64> {
     let mut crabs = input
         .trim()
@@ -19,11 +21,7 @@ where
 }
 
 fn get_distance_part_1(crab: u64, target: u64) -> u64 {
-    if crab < target {
-        target - crab
-    } else {
-        crab - target
-    }
+    target.abs_diff(crab)
 }
 
 fn solve_part_1(crabs: &[u64]) -> u64 {
@@ -33,24 +31,40 @@ fn solve_part_1(crabs: &[u64]) -> u64 {
 
 fn get_distance_part_2(crab: u64, target: u64) -> u64 {
     let distance = get_distance_part_1(crab, target);
-    return distance * (distance + 1) / 2;
+    distance * (distance + 1) / 2
 }
 
 fn solve_part_2(crabs: &[u64]) -> u64 {
     let min = crabs.iter().copied().min().unwrap();
     let max = crabs.iter().copied().max().unwrap();
     (min..=max)
-        .into_iter()
         .map(|target| compute_total_fuel(crabs, |crab| get_distance_part_2(crab, target)))
         .min()
         .unwrap()
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let crabs = parse(input);
-    println!("Part 1: {}", solve_part_1(&crabs));
-    println!("Part 2: {}", solve_part_2(&crabs));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_1(crabs: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(crabs)
+    }
+
+    fn part_2(crabs: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(crabs)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::{
     collections::{HashMap, HashSet},
     str::FromStr,
@@ -6,7 +7,7 @@ use std::{
 type Signal = Vec<u8>;
 
 #[derive(Debug)]
-struct Entry {
+pub struct Entry {
     patterns: Vec<Signal>,
     outputs: Vec<Signal>,
 }
@@ -120,11 +121,28 @@ fn solve_part_2(entries: &[Entry]) -> u64 {
     entries.iter().map(compute_entry_output).sum::<u64>()
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let entries = parse(input);
-    println!("Part 1: {}", solve_part_1(&entries));
-    println!("Part 2: {}", solve_part_2(&entries));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Entry>;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_1(entries: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(entries)
+    }
+
+    fn part_2(entries: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(entries)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::str::FromStr;
 
 #[derive(Debug)]
@@ -7,7 +8,7 @@ struct Position {
 }
 
 #[derive(Debug)]
-struct Map {
+pub struct Map {
     points: Vec<Vec<u8>>,
 }
 
@@ -180,11 +181,28 @@ fn solve_part_2(map: &Map) -> u64 {
         .unwrap()
 }
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let map = Map::from_str(input).unwrap();
-    println!("Part 1: {}", solve_part_1(&map));
-    println!("Part 2: {}", solve_part_2(&map));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Map;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Map::from_str(input).unwrap()
+    }
+
+    fn part_1(map: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(map)
+    }
+
+    fn part_2(map: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(map)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[cfg(test)]
@@ -0,0 +1,32 @@
+use aoc_common::{print_answers, Part, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let numbers: Vec<Vec<u64>> = input
+            .split("\n\n")
+            .map(|p| p.lines().map(|l| l.parse().unwrap()).collect())
+            .collect();
+
+        let mut sums: Vec<u64> = numbers.iter().map(|ns| ns.iter().sum()).collect();
+        sums.sort_by(|a, b| b.cmp(a));
+        sums
+    }
+
+    fn part_1(sums: &Self::Input<'_>) -> Self::Answer1 {
+        sums[0]
+    }
+
+    fn part_2(sums: &Self::Input<'_>) -> Self::Answer2 {
+        sums[0] + sums[1] + sums[2]
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
@@ -1,11 +1,32 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let instructions = parse_instructions(input);
-    let (sum_signal_strengths, displayed_screen) = run_instructions(instructions);
-    println!("Part 1: {}", sum_signal_strengths);
-    println!("Part 2: \n{}", displayed_screen);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (i64, String);
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        run_instructions(parse_instructions(input))
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        input.0
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        let (_, displayed_screen) = input;
+        match ocr::recognize(displayed_screen) {
+            Ok(letters) => letters,
+            Err(error) => format!("\n{}\n{}", displayed_screen, error),
+        }
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 struct Instruction {
@@ -25,7 +46,7 @@ fn run_instructions(instructions: Vec<Instruction>) -> (i64, String) {
         let current_cycle = completed_cycles + 1;
 
         if completed_cycles < 220 && current_cycle % 40 == 20 {
-            signal_strengths.push((current_cycle as i64) * register);
+            signal_strengths.push(current_cycle * register);
         }
 
         let current_row = (current_cycle - 1) / 40;
@@ -73,3 +94,18 @@ fn parse_instructions(input: &str) -> Vec<Instruction> {
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_part_2() {
+        let input = include_str!("./input.txt");
+        let (_, displayed_screen) = run_instructions(parse_instructions(input));
+        assert_eq!(
+            ocr::recognize(&displayed_screen),
+            Ok("FGCUZREC".to_string())
+        );
+    }
+}
@@ -1,25 +1,34 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::fmt::Debug;
 use crate::parser::parse_monkeys_and_worry_levels;
 use itertools::Itertools;
 
 mod parser;
 
-fn main() {
-    let input = include_str!("input.txt");
-    let monkeys_and_worry_levels = parse_monkeys_and_worry_levels(input);
-    let (monkeys, worry_levels, modulo_items) = create_modulo_items(monkeys_and_worry_levels);
+pub struct Puzzle;
 
-    let easy_monkey_business_level = compute_monkey_business_level_from_worry_levels(
-        &monkeys,
-        worry_levels);
+impl Solution for Puzzle {
+    type Input<'a> = (Vec<Monkey>, Vec<Vec<u64>>, Vec<Vec<ModuloItem>>);
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    println!("Part 1: {}", easy_monkey_business_level);
+    fn parse(input: &str) -> Self::Input<'_> {
+        create_modulo_items(parse_monkeys_and_worry_levels(input))
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        let (monkeys, worry_levels, _) = input;
+        compute_monkey_business_level_from_worry_levels(monkeys, worry_levels.clone())
+    }
 
-    let hard_monkey_business_level = compute_monkey_business_level_from_modulo_items(
-        &monkeys,
-        modulo_items);
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        let (monkeys, _, modulo_items) = input;
+        compute_monkey_business_level_from_modulo_items(monkeys, modulo_items.clone())
+    }
+}
 
-    println!("Part 2: {}", hard_monkey_business_level);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 macro_rules! item_log {
@@ -104,7 +113,7 @@ fn compute_generic_monkey_business_level<T: Debug>(
             }
         }
 
-        show_round(round, &monkeys, &number_inspections);
+        show_round(round, monkeys, &number_inspections);
     }
 
     best_inspections_product(number_inspections)
@@ -119,7 +128,7 @@ fn best_inspections_product(number_inspections: Vec<usize>) -> usize {
 
 #[allow(unused_variables)]
 fn show_round(round: usize, monkeys: &[Monkey], number_inspections: &[usize]) {
-    if round == 1 || round == 20 || round % 1000 == 0 {
+    if round == 1 || round == 20 || round.is_multiple_of(1000) {
         round_log!("== After round {} ==", round);
         for monkey_index in 0..monkeys.len() {
             round_log!(
@@ -205,13 +214,13 @@ fn create_modulo_items(monkeys_and_worry_levels: Vec<(Monkey, Vec<u64>)>) -> (Ve
 }
 
 #[derive(Debug, Clone)]
-struct ModuloItem {
+pub struct ModuloItem {
     // 'moduli' is the plural of 'modulo'. Live and learn.
     moduli: Vec<u64>,
 }
 
 #[derive(Debug, Clone)]
-struct Monkey {
+pub struct Monkey {
     #[allow(dead_code)]
     id: usize,
     operation: Operation,
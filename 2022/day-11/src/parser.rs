@@ -73,7 +73,7 @@ fn parse_monkey_operation(input: &str) -> IResult<&str, Operation> {
             nbr::alt(
                 (
                     nco::map(nb::tag("old"), |_| Operand::Old),
-                    nco::map(nc::u64, |n| Operand::Number(n)),
+                    nco::map(nc::u64, Operand::Number),
                 )
             ),
             nc::line_ending,
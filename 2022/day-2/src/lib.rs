@@ -0,0 +1,47 @@
+use aoc_common::{print_answers, Part, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<(u8, u8)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| (l.as_bytes()[0], l.as_bytes()[2]))
+            .collect()
+    }
+
+    fn part_1(rounds: &Self::Input<'_>) -> Self::Answer1 {
+        rounds
+            .iter()
+            .map(|r| {
+                let left_hand = r.0 - b'A';
+                let right_hand = r.1 - b'X';
+                let shape_score = right_hand + 1;
+                let outcome_score = (right_hand + 4 - left_hand) % 3 * 3;
+                (shape_score + outcome_score) as u64
+            })
+            .sum()
+    }
+
+    fn part_2(rounds: &Self::Input<'_>) -> Self::Answer2 {
+        rounds
+            .iter()
+            .map(|r| {
+                let outcome = r.1 - b'X';
+                let outcome_score = outcome * 3;
+                let left_hand = r.0 - b'A';
+                let right_hand = (left_hand + outcome + 2) % 3;
+                let shape_score = right_hand + 1;
+                (shape_score + outcome_score) as u64
+            })
+            .sum()
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
@@ -1,12 +1,20 @@
-fn main() {
-    let input = include_str!("./input.txt");
-    let lines: Vec<&str> = input.lines().collect();
-    let sum: u64 = lines
+use aoc_common::{print_answers, Part, Solution};
+
+fn priority(b: u8) -> u64 {
+    if b.is_ascii_lowercase() {
+        (b - b'a' + 1) as u64
+    } else {
+        (b - b'A' + 27) as u64
+    }
+}
+
+fn solve_part_1(lines: &[&str]) -> u64 {
+    lines
         .iter()
         .map(|l| {
             let (left_str, right_str) = l.split_at(l.len() / 2);
-            let mut left_bytes: Vec<u8> = left_str.as_bytes().iter().copied().collect();
-            let mut right_bytes: Vec<u8> = right_str.as_bytes().iter().copied().collect();
+            let mut left_bytes: Vec<u8> = left_str.as_bytes().to_vec();
+            let mut right_bytes: Vec<u8> = right_str.as_bytes().to_vec();
             left_bytes.sort();
             right_bytes.sort();
             let mut left_index = 0;
@@ -23,25 +31,18 @@ fn main() {
                 }
             }
         })
-        .map(|b| {
-            if b.is_ascii_lowercase() {
-                (b - b'a' + 1) as u64
-            } else {
-                (b - b'A' + 27) as u64
-            }
-        })
-        .sum();
-
-    println!("Part 1: {}", sum);
+        .map(priority)
+        .sum()
+}
 
-    let sum: u64 = lines
+fn solve_part_2(lines: &[&str]) -> u64 {
+    lines
         .chunks(3)
         .map(|g| {
-            dbg!(g);
             let mut bytes_array: [Vec<u8>; 3] = [
-                g[0].as_bytes().iter().copied().collect(),
-                g[1].as_bytes().iter().copied().collect(),
-                g[2].as_bytes().iter().copied().collect(),
+                g[0].as_bytes().to_vec(),
+                g[1].as_bytes().to_vec(),
+                g[2].as_bytes().to_vec(),
             ];
             bytes_array.iter_mut().for_each(|v| v.sort());
             let mut indexes: [usize; 3] = [0; 3];
@@ -74,14 +75,30 @@ fn main() {
                 return bytes[0];
             }
         })
-        .map(|b| {
-            if b.is_ascii_lowercase() {
-                (b - b'a' + 1) as u64
-            } else {
-                (b - b'A' + 27) as u64
-            }
-        })
-        .sum();
+        .map(priority)
+        .sum()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().collect()
+    }
+
+    fn part_1(lines: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(lines)
+    }
+
+    fn part_2(lines: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(lines)
+    }
+}
 
-    println!("Part 2: {}", sum);
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
@@ -0,0 +1,52 @@
+use aoc_common::{print_answers, Part, Solution};
+
+fn contains(outer: &[u64; 2], inner: &[u64; 2]) -> bool {
+    outer[0] <= inner[0] && inner[1] <= outer[1]
+}
+
+fn overlaps(left: &[u64; 2], right: &[u64; 2]) -> bool {
+    left[0] <= right[1] && right[0] <= left[1]
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<([u64; 2], [u64; 2])>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|l| {
+                let parts: Vec<u64> = l
+                    .split(['-', ','])
+                    .map(str::parse::<u64>)
+                    .map(Result::unwrap)
+                    .collect();
+
+                let left = [parts[0], parts[1]];
+                let right = [parts[2], parts[3]];
+                (left, right)
+            })
+            .collect()
+    }
+
+    fn part_1(pairs: &Self::Input<'_>) -> Self::Answer1 {
+        pairs
+            .iter()
+            .filter(|(left, right)| contains(left, right) || contains(right, left))
+            .count()
+    }
+
+    fn part_2(pairs: &Self::Input<'_>) -> Self::Answer2 {
+        pairs
+            .iter()
+            .filter(|(left, right)| overlaps(left, right))
+            .count()
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
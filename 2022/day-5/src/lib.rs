@@ -1,14 +1,15 @@
+use aoc_common::{print_answers, Part, Solution};
+
 type Stack = Vec<char>;
 
 #[derive(Debug, Clone)]
-struct Move {
+pub struct Move {
     count: usize,
     from: usize,
     to: usize,
 }
 
-fn parse_stacks_and_moves() -> (Vec<Stack>, Vec<Move>) {
-    let input = include_str!("./input.txt");
+fn parse_stacks_and_moves(input: &str) -> (Vec<Stack>, Vec<Move>) {
     let (stacks_lines, move_lines) = input
         .split_once("\n\n")
         .expect("could not separate stacks from moves");
@@ -41,7 +42,7 @@ fn parse_stacks_and_moves() -> (Vec<Stack>, Vec<Move>) {
     let moves = move_lines
         .lines()
         .map(|line| {
-            let mut line_split = line.split(|c| c == ' ').skip(1);
+            let mut line_split = line.split(' ').skip(1);
             let count_str = line_split.next().expect("expected `count` for move");
             let mut line_split = line_split.skip(1);
             let from_str = line_split.next().expect("expected `from` for move");
@@ -96,12 +97,28 @@ fn move_crates(mut stacks: Vec<Stack>, moves: &[Move], crane_behavior: CraneBeha
     first_crates
 }
 
-fn main() {
-    let (stacks, moves) = parse_stacks_and_moves();
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (Vec<Stack>, Vec<Move>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_stacks_and_moves(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        let (stacks, moves) = input;
+        move_crates(stacks.clone(), moves, CraneBehavior::ReverseOrder)
+    }
 
-    let crates_part_one = move_crates(stacks.clone(), &moves, CraneBehavior::ReverseOrder);
-    println!("Part 1: {crates_part_one}");
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        let (stacks, moves) = input;
+        move_crates(stacks.clone(), moves, CraneBehavior::RetainOrder)
+    }
+}
 
-    let crates_part_two = move_crates(stacks, &moves, CraneBehavior::RetainOrder);
-    println!("Part 2: {crates_part_two}");
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
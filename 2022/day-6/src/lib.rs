@@ -1,13 +1,27 @@
-fn main() {
-    let input = include_bytes!("./input.txt");
+use aoc_common::{print_answers, Part, Solution};
 
-    let start_of_packet_index =
-        find_marker(input, Marker::Packet).expect("expected start of packet marker to be found");
-    println!("Part 1: {start_of_packet_index}");
+pub struct Puzzle;
 
-    let start_of_message_index =
-        find_marker(input, Marker::Message).expect("expected start of message marker to be found");
-    println!("Part 2: {start_of_message_index}");
+impl Solution for Puzzle {
+    type Input<'a> = &'a [u8];
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.as_bytes()
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        find_marker(input, Marker::Packet).expect("expected start of packet marker to be found")
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        find_marker(input, Marker::Message).expect("expected start of message marker to be found")
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 enum Marker {
@@ -1,5 +1,7 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::collections::HashMap;
 
+#[allow(dead_code)]
 #[derive(Debug)]
 enum Entry<'a> {
     Dir(&'a str),
@@ -42,7 +44,7 @@ impl<'a> TryFrom<&'a str> for Command<'a> {
             Some(("cd", name)) => Ok(Command::Cd(name)),
             _ => {
                 let entries = lines
-                    .map(|line| Entry::try_from(line))
+                    .map(Entry::try_from)
                     .collect::<Result<Vec<_>, _>>()?;
                 Ok(Command::Ls(entries))
             }
@@ -54,7 +56,7 @@ fn iter_paths<'a>(current_path: &'a [&'a str]) -> impl Iterator<Item = String> +
     let path = "/".to_owned();
     std::iter::once(path.clone()).chain(current_path.iter().scan(path, |path, part| {
         path.push_str(part);
-        path.push_str("/");
+        path.push('/');
         Some(path.clone())
     }))
 }
@@ -93,41 +95,59 @@ fn build_directory_sizes(commands: Vec<Command<'_>>) -> HashMap<String, u64> {
     directory_sizes
 }
 
-fn main() -> Result<(), &'static str> {
-    let input = include_str!("./input.txt");
-    let commands = input
-        .split("$")
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty())
-        .map(|command| Command::try_from(command))
-        .collect::<Result<Vec<_>, _>>()?;
-
-    let directory_sizes = build_directory_sizes(commands);
-
-    let total_small_folders_size = directory_sizes
+fn solve_part_1(directory_sizes: &HashMap<String, u64>) -> u64 {
+    directory_sizes
         .values()
         .copied()
         .filter(|&size| size < 100000)
-        .sum::<u64>();
-
-    println!("Part 1: {total_small_folders_size}");
+        .sum()
+}
 
+fn solve_part_2(directory_sizes: &HashMap<String, u64>) -> u64 {
     let size_used = *directory_sizes
         .get("/")
-        .ok_or("root folder size not calculated")?;
+        .expect("root folder size not calculated");
 
     let system_size = 70000000;
     let needed_size = 30000000;
     let size_to_free = needed_size - (system_size - size_used);
 
-    let minimum_directory_size_to_delete = directory_sizes
+    directory_sizes
         .values()
         .copied()
         .filter(|&size| size >= size_to_free)
         .min()
-        .ok_or("minimum folder size to delete not found")?;
+        .expect("minimum folder size to delete not found")
+}
+
+pub struct Puzzle;
 
-    println!("Part 2: {minimum_directory_size_to_delete}");
+impl Solution for Puzzle {
+    type Input<'a> = HashMap<String, u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let commands = input
+            .split('$')
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(Command::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        build_directory_sizes(commands)
+    }
+
+    fn part_1(directory_sizes: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(directory_sizes)
+    }
+
+    fn part_2(directory_sizes: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(directory_sizes)
+    }
+}
 
-    Ok(())
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
@@ -1,14 +1,16 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let tree_grid = input
+fn parse_tree_grid(input: &str) -> Vec<Vec<u8>> {
+    input
         .lines()
         .map(str::trim)
         .map(str::as_bytes)
         .map(|bytes| bytes.iter().map(|byte| byte - b'0').collect::<Vec<_>>())
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+}
 
+fn solve_part_1(tree_grid: &[Vec<u8>]) -> usize {
     let number_rows = tree_grid.len();
     let number_cols = tree_grid[0].len();
 
@@ -28,7 +30,12 @@ fn main() {
         }
     }
 
-    println!("Part 1: {count}");
+    count
+}
+
+fn solve_part_2(tree_grid: &[Vec<u8>]) -> usize {
+    let number_rows = tree_grid.len();
+    let number_cols = tree_grid[0].len();
 
     let mut best: Option<usize> = None;
     for row_index in 1..number_rows-1 {
@@ -44,7 +51,31 @@ fn main() {
         }
     }
 
-    println!("Part 2: {}", best.unwrap());
+    best.unwrap()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Vec<u8>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_tree_grid(input)
+    }
+
+    fn part_1(tree_grid: &Self::Input<'_>) -> Self::Answer1 {
+        solve_part_1(tree_grid)
+    }
+
+    fn part_2(tree_grid: &Self::Input<'_>) -> Self::Answer2 {
+        solve_part_2(tree_grid)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn count_scenic_score<I: Iterator<Item = u8>>(current_tree: u8, mut iterator: I) -> usize {
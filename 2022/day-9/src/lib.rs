@@ -1,13 +1,31 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::collections::HashSet;
 
 type Position = (isize, isize);
 type Move = (usize, Position);
 
-fn main() {
-    let input = include_str!("./input.txt");
-    let head_moves = parse_moves(input);
-    println!("Part 1: {}", move_rope::<2>(&head_moves));
-    println!("Part 2: {}", move_rope::<10>(&head_moves));
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Move>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_moves(input)
+    }
+
+    fn part_1(head_moves: &Self::Input<'_>) -> Self::Answer1 {
+        move_rope::<2>(head_moves)
+    }
+
+    fn part_2(head_moves: &Self::Input<'_>) -> Self::Answer2 {
+        move_rope::<10>(head_moves)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn parse_moves(input: &str) -> Vec<Move> {
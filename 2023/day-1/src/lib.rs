@@ -1,9 +1,27 @@
-fn main() {
-    let input = include_str!("input.txt");
-    let sum_one = compute(input, get_digits_as_characters);
-    let sum_two = compute(input, get_digits_as_words);
-    println!("Part 1: {sum_one}");
-    println!("Part 2: {sum_two}");
+use aoc_common::{print_answers, Part, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = &'a str;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        compute(input, get_digits_as_characters)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        compute(input, get_digits_as_words)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn compute(input: &str, get_digits: fn(&str) -> Vec<u64>) -> u64 {
@@ -47,7 +65,7 @@ fn get_digits_as_words(line: &str) -> Vec<u64> {
         }
 
         let digit_option = DIGIT_WORDS.iter().enumerate().filter_map(|(index, digit_word)| {
-            line_rest.strip_prefix(digit_word).map(|line_rest| index as u64 + 1)
+            line_rest.strip_prefix(digit_word).map(|_line_rest| index as u64 + 1)
         }).next();
 
         if let Some(digit) = digit_option {
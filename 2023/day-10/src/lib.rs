@@ -1,19 +1,32 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
-
-fn main() {
-    let input = include_str!("input.txt");
-    let grid = parse_grid(input);
-    let start_position = find_start(&grid);
-    let tile_loop = find_loop(&grid, start_position);
-    let farthest = tile_loop.len() / 2;
-    println!("Part 1: {}", farthest);
-
-    let twice_area_count = compute_twice_area(&tile_loop);
-    let border_count = tile_loop.len() - 1;
-    let inside_count = (twice_area_count - border_count + 2) / 2;
-    println!("Part 2: {}", inside_count);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Position>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let grid = parse_grid(input);
+        let start_position = find_start(&grid);
+        find_loop(&grid, start_position)
+    }
+
+    fn part_1(tile_loop: &Self::Input<'_>) -> Self::Answer1 {
+        tile_loop.len() / 2
+    }
+
+    fn part_2(tile_loop: &Self::Input<'_>) -> Self::Answer2 {
+        let twice_area_count = compute_twice_area(tile_loop);
+        let border_count = tile_loop.len() - 1;
+        (twice_area_count - border_count + 2) / 2
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 type Position = (usize, usize);
@@ -84,16 +97,16 @@ fn are_connecting(from: Tile, direction: Direction, to: Tile) -> bool {
 }
 
 fn connects(pipe: Tile, direction: Direction) -> bool {
-    match (pipe, direction) {
-        (Tile::PipeVertical, Direction::North) | (Tile::PipeVertical, Direction::South) => true,
-        (Tile::PipeHorizontal, Direction::East) | (Tile::PipeHorizontal, Direction::West) => true,
-        (Tile::PipeNorthEast, Direction::North) | (Tile::PipeNorthEast, Direction::East) => true,
-        (Tile::PipeNorthWest, Direction::North) | (Tile::PipeNorthWest, Direction::West) => true,
-        (Tile::PipeSouthWest, Direction::South) | (Tile::PipeSouthWest, Direction::West) => true,
-        (Tile::PipeSouthEast, Direction::South) | (Tile::PipeSouthEast, Direction::East) => true,
-        (Tile::Start, _) => true,
-        _ => false,
-    }
+    matches!(
+        (pipe, direction),
+        (Tile::PipeVertical, Direction::North | Direction::South)
+            | (Tile::PipeHorizontal, Direction::East | Direction::West)
+            | (Tile::PipeNorthEast, Direction::North | Direction::East)
+            | (Tile::PipeNorthWest, Direction::North | Direction::West)
+            | (Tile::PipeSouthWest, Direction::South | Direction::West)
+            | (Tile::PipeSouthEast, Direction::South | Direction::East)
+            | (Tile::Start, _)
+    )
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -121,7 +134,7 @@ fn find_start(grid: &[Vec<Tile>]) -> Position {
         .flat_map(|(row_index, row)| {
             row.iter()
                 .enumerate()
-                .find(|(col_index, col)| **col == Tile::Start)
+                .find(|(_col_index, col)| **col == Tile::Start)
                 .map(|(col_index, _)| (row_index, col_index))
         })
         .next()
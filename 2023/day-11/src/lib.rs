@@ -1,12 +1,28 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
-fn main() {
-    let input = include_str!("input.txt");
-    let image = parse_image(input);
-    let shortest_distances_sum = sum_shortest_distances(&image, 2);
-    println!("Part 1: {}", shortest_distances_sum);
-    let shortest_distances_sum = sum_shortest_distances(&image, 1000000);
-    println!("Part 2: {}", shortest_distances_sum);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Image;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_image(input)
+    }
+
+    fn part_1(image: &Self::Input<'_>) -> Self::Answer1 {
+        sum_shortest_distances(image, 2)
+    }
+
+    fn part_2(image: &Self::Input<'_>) -> Self::Answer2 {
+        sum_shortest_distances(image, 1000000)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn sum_shortest_distances(image: &Image, displacement_factor: usize) -> usize {
@@ -80,7 +96,7 @@ const TILE_EMPTY: char = '.';
 const TILE_GALAXY: char = '#';
 
 #[derive(Debug)]
-struct Image {
+pub struct Image {
     galaxies: Vec<Position>,
     rows_displacements: Vec<usize>,
     cols_displacements: Vec<usize>,
@@ -1,13 +1,11 @@
 use core::panic;
 use std::collections::HashMap;
 
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
-fn main() {
-    let input = include_str!("input.txt");
-    let records = parse_records(input);
-
-    let total_possible_arrangements = records
+fn count_total_arrangements(records: &[Record]) -> usize {
+    records
         .iter()
         .map(|record| {
             let mut cache = Default::default();
@@ -15,15 +13,15 @@ fn main() {
                 &record.springs_conditions,
                 &record.contiguous_counts,
                 0,
-                &vec![],
+                &[],
                 &mut cache,
             )
         })
-        .sum::<usize>();
-
-    println!("Part 1: {total_possible_arrangements}");
+        .sum()
+}
 
-    let total_possible_arrangements = records
+fn count_total_unfolded_arrangements(records: &[Record]) -> usize {
+    records
         .iter()
         .map(|record| {
             let unfolded_springs_conditions = [
@@ -50,21 +48,45 @@ fn main() {
                 &unfolded_springs_conditions,
                 &unfolded_contiguous_counts,
                 0,
-                &vec![],
+                &[],
                 &mut cache,
             )
         })
-        .sum::<usize>();
+        .sum()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Record>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_records(input)
+    }
 
-    println!("Part 2: {total_possible_arrangements}");
+    fn part_1(records: &Self::Input<'_>) -> Self::Answer1 {
+        count_total_arrangements(records)
+    }
+
+    fn part_2(records: &Self::Input<'_>) -> Self::Answer2 {
+        count_total_unfolded_arrangements(records)
+    }
 }
 
-fn count_possible_arragements<'a, 'b, 'c>(
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
+}
+
+type Cache<'a> = HashMap<(&'a [Option<Condition>], &'a [usize], usize), usize>;
+
+fn count_possible_arragements<'a>(
     springs_conditions: &'a [Option<Condition>],
     contiguous_counts: &'a [usize],
     current_contiguous_count: usize,
-    branch: &'b [Condition],
-    cache: &'c mut HashMap<(&'a [Option<Condition>], &'a [usize], usize), usize>,
+    branch: &[Condition],
+    cache: &mut Cache<'a>,
 ) -> usize {
     let cache_key = (
         springs_conditions,
@@ -89,7 +111,7 @@ fn count_possible_arragements<'a, 'b, 'c>(
                         .collect_vec();
                     count_possible_arragements(
                         &springs_conditions[1..],
-                        &contiguous_counts[..],
+                        contiguous_counts,
                         current_contiguous_count,
                         &new_branch,
                         cache,
@@ -189,7 +211,7 @@ fn parse_records(input: &str) -> Vec<Record> {
 }
 
 #[derive(Debug, Clone)]
-struct Record {
+pub struct Record {
     springs_conditions: Vec<Option<Condition>>,
     contiguous_counts: Vec<usize>,
 }
@@ -1,12 +1,28 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
-fn main() {
-    let input = include_str!("input.txt");
-    let patterns = parse_patterns(input);
-    let summarized = summarize_patterns(&patterns, 0);
-    println!("Part 1: {}", summarized);
-    let summarized = summarize_patterns(&patterns, 1);
-    println!("Part 2: {}", summarized);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Pattern>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_patterns(input)
+    }
+
+    fn part_1(patterns: &Self::Input<'_>) -> Self::Answer1 {
+        summarize_patterns(patterns, 0)
+    }
+
+    fn part_2(patterns: &Self::Input<'_>) -> Self::Answer2 {
+        summarize_patterns(patterns, 1)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn summarize_patterns(patterns: &[Pattern], smudge_tolerance: usize) -> usize {
@@ -98,6 +114,6 @@ fn parse_patterns(input: &str) -> Vec<Pattern> {
 }
 
 #[derive(Debug, Clone)]
-struct Pattern {
+pub struct Pattern {
     rows: Vec<Vec<char>>,
 }
@@ -1,3 +1,4 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::collections::{HashSet};
 use itertools::Itertools;
 
@@ -5,17 +6,30 @@ const TILE_EMPTY: char = '.';
 const TILE_CUBE_ROCK: char = '#';
 const TILE_ROUND_ROCK: char = 'O';
 
-fn main() {
-    let input = include_str!("input.txt");
-    let grid = input.lines().map(|line| line.trim().chars().collect_vec()).collect_vec();
+pub struct Puzzle;
 
-    let mut grid_clone = grid.clone();
-    roll_rocks_north(&mut grid_clone);
-    let total_load = get_north_load(&grid_clone);
-    println!("Part 1: {}", total_load);
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Vec<char>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let final_load = find_load_for_cycle(grid);
-    println!("Part 2: {}", final_load);
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().map(|line| line.trim().chars().collect_vec()).collect_vec()
+    }
+
+    fn part_1(grid: &Self::Input<'_>) -> Self::Answer1 {
+        let mut grid = grid.clone();
+        roll_rocks_north(&mut grid);
+        get_north_load(&grid)
+    }
+
+    fn part_2(grid: &Self::Input<'_>) -> Self::Answer2 {
+        find_load_for_cycle(grid.clone())
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn find_load_for_cycle(mut grid: Vec<Vec<char>>) -> usize {
@@ -57,8 +71,8 @@ fn find_load_for_cycle(mut grid: Vec<Vec<char>>) -> usize {
 fn compute_equivalent_cycle_to_target(cycle: usize, start_repeats_cycle: usize, found_result: &CycleResult) -> usize {
     let target_cycle = 1000000000;
     let repeat_size = ((cycle + 1) - start_repeats_cycle) / 2;
-    let equivalent_cycle = (target_cycle - found_result.cycle) % repeat_size + found_result.cycle;
-    equivalent_cycle
+    
+    (target_cycle - found_result.cycle) % repeat_size + found_result.cycle
 }
 
 struct CycleResult {
@@ -88,7 +102,8 @@ fn rotate(old_grid: &[Vec<char>]) -> Vec<Vec<char>> {
     new_grid
 }
 
-fn roll_rocks_north(grid: &mut Vec<Vec<char>>) {
+#[allow(clippy::needless_range_loop)]
+fn roll_rocks_north(grid: &mut [Vec<char>]) {
     let number_rows = grid.len();
     let number_cols = grid[0].len();
 
@@ -112,27 +127,23 @@ fn roll_rocks_north(grid: &mut Vec<Vec<char>>) {
 }
 
 fn get_north_load(grid: &[Vec<char>]) -> usize {
-    let mut total_load = 0;
     let number_rows = grid.len();
-    let number_cols = grid[0].len();
-
-    for col in 0..number_cols {
-        for row in 0..number_rows {
-            if grid[row][col] == TILE_ROUND_ROCK {
-                total_load += number_rows - row;
-            }
-        }
-    }
 
-    total_load
+    grid.iter()
+        .enumerate()
+        .map(|(row, line)| {
+            let round_rocks = line.iter().filter(|&&tile| tile == TILE_ROUND_ROCK).count();
+            round_rocks * (number_rows - row)
+        })
+        .sum()
 }
 
 fn get_rounded_rocks(grid: &[Vec<char>]) -> HashSet<(usize, usize)> {
     let mut set = HashSet::default();
 
-    for row in 0..grid.len() {
-        for col in 0..grid[row].len() {
-            if grid[row][col] == TILE_ROUND_ROCK {
+    for (row, line) in grid.iter().enumerate() {
+        for (col, &tile) in line.iter().enumerate() {
+            if tile == TILE_ROUND_ROCK {
                 set.insert((row, col));
             }
         }
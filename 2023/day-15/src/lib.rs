@@ -1,11 +1,27 @@
-fn main() {
-    let input = include_str!("input.txt");
-    let hash = sum_hashes(input);
-    println!("Part 1: {hash}");
+use aoc_common::{print_answers, Part, Solution};
 
-    let filled_boxes = process_operations(input);
-    let total_focusing_power = sum_focusing_power(filled_boxes);
-    println!("Part 2: {}", total_focusing_power);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = &'a str;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        sum_hashes(input)
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        sum_focusing_power(process_operations(input))
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn sum_focusing_power(boxes: Vec<Box>) -> u64 {
@@ -1,17 +1,9 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 use std::collections::HashMap;
 use std::ops::Add;
 
-fn main() {
-    let input = include_str!("input.txt");
-    let grid = input
-        .lines()
-        .map(|line| line.trim().chars().collect_vec())
-        .collect_vec();
-
-    let energized_tiles_count = run_beams(&grid, Beam::new(Position::ZERO, Direction::RIGHT));
-    println!("Part 1: {}", energized_tiles_count);
-
+fn find_best_energized_tiles_count(grid: &[Vec<char>]) -> usize {
     let number_rows = grid.len() as isize;
     let number_cols = grid[0].len() as isize;
     let last_row = number_rows - 1;
@@ -37,11 +29,38 @@ fn main() {
 
     let (_, best_energized_tiles_count) = initial_beams
         .into_iter()
-        .map(|initial_beam| (initial_beam.clone(), run_beams(&grid, initial_beam)))
+        .map(|initial_beam| (initial_beam.clone(), run_beams(grid, initial_beam)))
         .max_by_key(|&(_, energized_tiles_count)| energized_tiles_count)
         .expect("best beam not found");
 
-    println!("Part 2: {}", best_energized_tiles_count);
+    best_energized_tiles_count
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Vec<char>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|line| line.trim().chars().collect_vec())
+            .collect_vec()
+    }
+
+    fn part_1(grid: &Self::Input<'_>) -> Self::Answer1 {
+        run_beams(grid, Beam::new(Position::ZERO, Direction::RIGHT))
+    }
+
+    fn part_2(grid: &Self::Input<'_>) -> Self::Answer2 {
+        find_best_energized_tiles_count(grid)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn run_beams(grid: &[Vec<char>], initial_beam: Beam) -> usize {
@@ -148,6 +167,7 @@ impl Beam {
     }
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Ord, Eq)]
 enum Direction {
     UP,
@@ -157,6 +177,7 @@ enum Direction {
 }
 
 impl Direction {
+    #[allow(clippy::wrong_self_convention)]
     fn to_position(&self) -> Position {
         match self {
             Direction::UP => Position::UP,
@@ -1,16 +1,33 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashSet};
 use itertools::{Itertools};
 
-fn main() {
-    let input = include_str!("input.txt");
-    let grid = input.lines().map(|line| line.trim().as_bytes().iter().map(|&byte| byte - b'0').collect_vec()).collect_vec();
+pub struct Puzzle;
 
-    let minimum_heat_loss = find_minimum_heat_loss(&grid, 0, 3);
-    println!("Part 1: {}", minimum_heat_loss);
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Vec<u8>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-    let minimum_heat_loss = find_minimum_heat_loss(&grid, 4, 10);
-    println!("Part 2: {}", minimum_heat_loss);
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|line| line.trim().as_bytes().iter().map(|&byte| byte - b'0').collect_vec())
+            .collect_vec()
+    }
+
+    fn part_1(grid: &Self::Input<'_>) -> Self::Answer1 {
+        find_minimum_heat_loss(grid, 0, 3)
+    }
+
+    fn part_2(grid: &Self::Input<'_>) -> Self::Answer2 {
+        find_minimum_heat_loss(grid, 4, 10)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn find_minimum_heat_loss(
@@ -1,18 +1,34 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::{Either, Itertools};
 use std::str::FromStr;
 
-fn main() {
-    let input = include_str!("input.txt");
-    let normal_trenches = parse_trenches(input);
-    let lagoon_area = compute_lagoon_area(&normal_trenches);
-    println!("Part 1: {}", lagoon_area);
-
-    let color_trenches = normal_trenches
-        .into_iter()
-        .map(Trench::into_color)
-        .collect_vec();
-    let lagoon_area = compute_lagoon_area(&color_trenches);
-    println!("Part 2: {}", lagoon_area);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Trench>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_trenches(input)
+    }
+
+    fn part_1(trenches: &Self::Input<'_>) -> Self::Answer1 {
+        compute_lagoon_area(trenches)
+    }
+
+    fn part_2(trenches: &Self::Input<'_>) -> Self::Answer2 {
+        let color_trenches = trenches
+            .iter()
+            .cloned()
+            .map(Trench::into_color)
+            .collect_vec();
+        compute_lagoon_area(&color_trenches)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn compute_lagoon_area(trenches: &[Trench]) -> isize {
@@ -66,7 +82,7 @@ fn parse_trenches(input: &str) -> Vec<Trench> {
 }
 
 #[derive(Debug, Clone)]
-struct Trench {
+pub struct Trench {
     direction: Direction,
     length: isize,
     color: String,
@@ -103,7 +119,7 @@ impl Direction {
     }
 
     fn from_hex(s: &str) -> Result<Self, <Self as FromStr>::Err> {
-        let c = s.parse::<char>().map_err(|e| Either::Left(e))?;
+        let c = s.parse::<char>().map_err(Either::Left)?;
         match c {
             '0' => Ok(Direction::Right),
             '1' => Ok(Direction::Down),
@@ -118,7 +134,7 @@ impl FromStr for Direction {
     type Err = Either<<char as FromStr>::Err, &'static str>;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let c = s.parse::<char>().map_err(|e| Either::Left(e))?;
+        let c = s.parse::<char>().map_err(Either::Left)?;
         match c {
             'R' => Ok(Direction::Right),
             'D' => Ok(Direction::Down),
@@ -1,13 +1,30 @@
-fn main() {
-    let input = include_str!("input.txt");
-    let games = parse_games(input);
-    let sum_ids = sum_ids_of_valid_games(&games);
-    println!("Part 1: {sum_ids}");
-    let sum_power_sets = sum_power_sets_of_games(&games);
-    println!("Part 2: {sum_power_sets}");
+use aoc_common::{print_answers, Part, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Game<'a>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_games(input)
+    }
+
+    fn part_1(games: &Self::Input<'_>) -> Self::Answer1 {
+        sum_ids_of_valid_games(games)
+    }
+
+    fn part_2(games: &Self::Input<'_>) -> Self::Answer2 {
+        sum_power_sets_of_games(games)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
-fn parse_games(input: &str) -> Vec<Game> {
+fn parse_games(input: &str) -> Vec<Game<'_>> {
     input
         .lines()
         .map(|line| {
@@ -25,7 +42,7 @@ fn parse_games(input: &str) -> Vec<Game> {
         .collect()
 }
 
-struct Game<'a> {
+pub struct Game<'a> {
     id: usize,
     reveals: Vec<Vec<Pull<'a>>>,
 }
@@ -1,21 +1,20 @@
-fn main() {
-    let input = include_str!("input.txt");
-    let grid: Vec<&[u8]> = input.lines().map(str::trim).map(str::as_bytes).collect();
+use aoc_common::{print_answers, Part, Solution};
 
+fn sum_parts_and_gear_ratios(grid: &[&[u8]]) -> (u64, u64) {
     let mut sum: u64 = 0;
     let mut gear_ratios: u64 = 0;
     for (row_index, row) in grid.iter().enumerate() {
         for (col_index, &byte) in row.iter().enumerate() {
             if byte.is_ascii_digit() || byte == b'.' { continue; }
 
-            let top_parts = find_row_parts(&grid, row_index - 1, col_index);
-            let middle_parts = find_row_parts(&grid, row_index, col_index);
-            let bottom_parts = find_row_parts(&grid, row_index + 1, col_index);
+            let top_parts = find_row_parts(grid, row_index - 1, col_index);
+            let middle_parts = find_row_parts(grid, row_index, col_index);
+            let bottom_parts = find_row_parts(grid, row_index + 1, col_index);
 
             let parts: Vec<u64> = top_parts.into_iter()
-                .chain(middle_parts.into_iter())
-                .chain(bottom_parts.into_iter())
-                .filter_map(|o| o)
+                .chain(middle_parts)
+                .chain(bottom_parts)
+                .flatten()
                 .map(|n| n.value)
                 .collect();
 
@@ -26,8 +25,32 @@ fn main() {
         }
     }
 
-    println!("Part 1: {}", sum);
-    println!("Part 2: {}", gear_ratios);
+    (sum, gear_ratios)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (u64, u64);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let grid: Vec<&[u8]> = input.lines().map(str::trim).map(str::as_bytes).collect();
+        sum_parts_and_gear_ratios(&grid)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        input.0
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        input.1
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 #[derive(Debug)]
@@ -37,14 +60,14 @@ struct Number {
 }
 
 fn find_row_parts(grid: &[&[u8]], row_index: usize, col_index: usize) -> [Option<Number>; 3] {
-    let left = find_part_number(&grid, row_index, col_index - 1);
-    let middle = if left.is_some() { None } else { find_part_number(&grid, row_index, col_index) };
+    let left = find_part_number(grid, row_index, col_index - 1);
+    let middle = if left.is_some() { None } else { find_part_number(grid, row_index, col_index) };
     let check_right = match (&left, &middle) {
         (Some(n), _) => { n.end_col_index < col_index + 1 }
         (_, Some(_)) => false,
         (None, None) => true,
     };
-    let right = if check_right { find_part_number(&grid, row_index, col_index + 1) } else { None };
+    let right = if check_right { find_part_number(grid, row_index, col_index + 1) } else { None };
     [left, middle, right]
 }
 
@@ -1,12 +1,28 @@
+use aoc_common::{print_answers, Part, Solution};
 use std::collections::{HashSet, VecDeque};
 
-fn main() {
-    let input = include_str!("input.txt");
-    let games = parse_games(input);
-    let sum_of_scores = compute_sum_of_points(&games);
-    println!("Part 1: {}", sum_of_scores);
-    let number_copies = compute_number_of_card_instances(&games);
-    println!("Part 2: {}", number_copies);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Game>;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_games(input)
+    }
+
+    fn part_1(games: &Self::Input<'_>) -> Self::Answer1 {
+        compute_sum_of_points(games)
+    }
+
+    fn part_2(games: &Self::Input<'_>) -> Self::Answer2 {
+        compute_number_of_card_instances(games)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn parse_games(input: &str) -> Vec<Game> {
@@ -49,7 +65,7 @@ fn parse_games(input: &str) -> Vec<Game> {
 }
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     #[allow(dead_code)]
     id: usize,
     winning_numbers: HashSet<u64>,
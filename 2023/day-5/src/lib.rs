@@ -1,30 +1,44 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
-fn main() {
-    let input = include_str!("input.txt");
-    let almanac = parse_almanac(input);
-
-    let normal_seeds = almanac
-        .seeds
-        .iter()
-        .map(|seed| (*seed..*seed + 1))
-        .collect_vec();
-
-    let minimum_location_for_normal_seeds = find_minimum_mapped_location(&almanac, normal_seeds);
-    println!("Part 1: {minimum_location_for_normal_seeds}");
-
-    let ranges_seeds = almanac
-        .seeds
-        .chunks(2)
-        .map(|chunk| {
-            let start = chunk[0];
-            let length = chunk[1];
-            start..start + length
-        })
-        .collect_vec();
-
-    let minimum_location_for_ranges_seeds = find_minimum_mapped_location(&almanac, ranges_seeds);
-    println!("Part 2: {minimum_location_for_ranges_seeds}");
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Almanac<'a>;
+    type Answer1 = Seed;
+    type Answer2 = Seed;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_almanac(input)
+    }
+
+    fn part_1(almanac: &Self::Input<'_>) -> Self::Answer1 {
+        let normal_seeds = almanac
+            .seeds
+            .iter()
+            .map(|seed| *seed..*seed + 1)
+            .collect_vec();
+
+        find_minimum_mapped_location(almanac, normal_seeds)
+    }
+
+    fn part_2(almanac: &Self::Input<'_>) -> Self::Answer2 {
+        let ranges_seeds = almanac
+            .seeds
+            .chunks(2)
+            .map(|chunk| {
+                let start = chunk[0];
+                let length = chunk[1];
+                start..start + length
+            })
+            .collect_vec();
+
+        find_minimum_mapped_location(almanac, ranges_seeds)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn find_minimum_mapped_location(almanac: &Almanac, initial_ranges_seeds: Vec<Range>) -> Seed {
@@ -73,7 +87,7 @@ fn find_minimum_mapped_location(almanac: &Almanac, initial_ranges_seeds: Vec<Ran
         .expect("minimum range location")
 }
 
-fn parse_almanac(input: &str) -> Almanac {
+fn parse_almanac(input: &str) -> Almanac<'_> {
     let (input, (_, seeds, _)) = nom::sequence::tuple::<_, _, nom::error::Error<_>, _>((
         nom::bytes::complete::tag("seeds: "),
         nom::multi::separated_list1(
@@ -113,7 +127,7 @@ fn parse_almanac(input: &str) -> Almanac {
             })
             .collect_vec();
 
-        mappings.sort_by(|lhs, rhs| lhs.source_start.cmp(&rhs.source_start));
+        mappings.sort_by_key(|lhs| lhs.source_start);
         Map { name, mappings }
     })
     .collect_vec();
@@ -122,7 +136,7 @@ fn parse_almanac(input: &str) -> Almanac {
 }
 
 #[derive(Debug, Clone)]
-struct Almanac<'a> {
+pub struct Almanac<'a> {
     seeds: Vec<Seed>,
     maps: Vec<Map<'a>>,
 }
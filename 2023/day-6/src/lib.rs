@@ -1,18 +1,31 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
-fn main() {
-    let input = include_str!("input.txt");
-    let races = parse_races(input);
-    
-    let result = races
-        .iter()
-        .map(count_possible_ways_of_winning)
-        .product::<u64>();
-    println!("Part 1: {}", result);
-    
-    let combined_race = combine_races(races);
-    let combined_result = count_possible_ways_of_winning(&combined_race);
-    println!("Part 2: {}", combined_result);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Race>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_races(input)
+    }
+
+    fn part_1(races: &Self::Input<'_>) -> Self::Answer1 {
+        races
+            .iter()
+            .map(count_possible_ways_of_winning)
+            .product()
+    }
+
+    fn part_2(races: &Self::Input<'_>) -> Self::Answer2 {
+        count_possible_ways_of_winning(&combine_races(races))
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn count_possible_ways_of_winning(race: &Race) -> u64 {
@@ -20,13 +33,13 @@ fn count_possible_ways_of_winning(race: &Race) -> u64 {
     let d = race.distance as f64;
     let distance_hold = (t - (t * t - 4.0 * d).sqrt()) / 2.0;
     let minimum_hold = distance_hold.floor() as u64 + 1;
-    let result = (race.time + 1) - 2 * minimum_hold;
-    result
+    
+    (race.time + 1) - 2 * minimum_hold
 }
 
-fn combine_races(races: Vec<Race>) -> Race {
+fn combine_races(races: &[Race]) -> Race {
     races
-        .into_iter()
+        .iter()
         .fold(Race::default(), |combined_race, current_race| {
             let combine = |lhs: u64, rhs: u64| {
                 lhs * 10u64.pow(rhs.ilog10() + 1) + rhs
@@ -52,13 +65,13 @@ fn parse_races(input: &str) -> Vec<Race> {
     let distances = parse_numbers(lines.next().expect("distance line"));
     times
         .into_iter()
-        .zip(distances.into_iter())
+        .zip(distances)
         .map(|(time, distance)| Race { time, distance })
         .collect_vec()
 }
 
 #[derive(Debug, Default)]
-struct Race {
+pub struct Race {
     time: u64,
     distance: u64,
 }
@@ -1,17 +1,30 @@
 use std::{collections::HashMap, fmt::Display, fmt::Write};
 
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
-use nom;
 
-fn main() {
-    let input = include_str!("input.txt");
-    let hands = parse_hands(input);
+pub struct Puzzle;
 
-    let total_winnings = compute_winnings(&hands, false);
-    println!("Part 1: {}", total_winnings);
+impl Solution for Puzzle {
+    type Input<'a> = Vec<Hand>;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-    let total_winnings = compute_winnings(&hands, true);
-    println!("Part 2: {}", total_winnings);
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_hands(input)
+    }
+
+    fn part_1(hands: &Self::Input<'_>) -> Self::Answer1 {
+        compute_winnings(hands, false)
+    }
+
+    fn part_2(hands: &Self::Input<'_>) -> Self::Answer2 {
+        compute_winnings(hands, true)
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn compute_winnings(hands: &[Hand], consider_jokers: bool) -> u64 {
@@ -57,7 +70,7 @@ fn parse_hands(input: &str) -> Vec<Hand> {
 type NomError<'a> = nom::error::Error<&'a str>;
 type NomResult<'a, T> = nom::IResult<&'a str, T, NomError<'a>>;
 
-fn parse_hand(input: &str) -> NomResult<Hand> {
+fn parse_hand(input: &str) -> NomResult<'_, Hand> {
     let (input, (cards, _, bid)) = nom::sequence::tuple((
         nom::multi::many1(parse_card),
         nom::character::complete::space1,
@@ -67,7 +80,7 @@ fn parse_hand(input: &str) -> NomResult<Hand> {
     Ok((input, Hand::new(cards, bid)))
 }
 
-fn parse_card(input: &str) -> NomResult<Card> {
+fn parse_card(input: &str) -> NomResult<'_, Card> {
     let (input, parsed) = nom::character::complete::one_of("AKQJT98765432")(input)?;
     let value = match parsed {
         'A' => 14,
@@ -82,7 +95,7 @@ fn parse_card(input: &str) -> NomResult<Card> {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Hand {
+pub struct Hand {
     cards: Vec<Card>,
     bid: u64,
 }
@@ -100,9 +113,7 @@ fn get_hand_type(hand: &Hand, consider_jokers: bool) -> HandType {
         }
     }
 
-    let card_counts = card_counts
-        .into_iter()
-        .map(|(_, count)| count)
+    let card_counts = card_counts.into_values()
         .sorted_by(|lhs, rhs| lhs.cmp(rhs).reverse())
         .collect_vec();
 
@@ -1,28 +1,44 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 use std::borrow::ToOwned;
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 
-fn main() {
-    let input = include_str!("input.txt");
-    let (instructions, nodes) = parse_input(input);
-
-    let default_number_steps = find_number_steps(
-        Label::default_start(),
-        &instructions,
-        &nodes,
-        &HashSet::from([Label::default_end()]),
-    );
-    println!("Part 1: {}", default_number_steps);
-
-    let ends = nodes.keys().filter(|l| l.is_end()).cloned().collect();
-    let combined_number_steps = nodes
-        .keys()
-        .filter(|l| l.is_start())
-        .map(|start| find_number_steps(start.clone(), &instructions, &nodes, &ends))
-        .reduce(num::integer::lcm)
-        .unwrap();
-    println!("Part 2: {}", combined_number_steps);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = (Vec<Instruction>, HashMap<Label, Node>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+        let (instructions, nodes) = input;
+        find_number_steps(
+            Label::default_start(),
+            instructions,
+            nodes,
+            &HashSet::from([Label::default_end()]),
+        )
+    }
+
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+        let (instructions, nodes) = input;
+        let ends = nodes.keys().filter(|l| l.is_end()).cloned().collect();
+        nodes
+            .keys()
+            .filter(|l| l.is_start())
+            .map(|start| find_number_steps(start.clone(), instructions, nodes, &ends))
+            .reduce(num::integer::lcm)
+            .unwrap()
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn find_number_steps(
@@ -93,14 +109,14 @@ fn parse_input(input: &str) -> (Vec<Instruction>, HashMap<Label, Node>) {
         .into_iter()
         .map(|tuple| {
             let (label, _, left, _, right, _) = tuple;
-            (label.clone(), Node { label, left, right })
+            (label, Node { left, right })
         })
         .collect();
 
     (instructions, nodes)
 }
 
-fn parse_label(input: &str) -> nom::IResult<&str, Label, NomError> {
+fn parse_label(input: &str) -> nom::IResult<&str, Label, NomError<'_>> {
     nom::character::complete::alphanumeric1(input).map(|r| {
         (
             r.0,
@@ -112,7 +128,7 @@ fn parse_label(input: &str) -> nom::IResult<&str, Label, NomError> {
 }
 
 #[derive(Debug, Clone, Copy, Hash, Ord, PartialOrd, Eq, PartialEq)]
-enum Instruction {
+pub enum Instruction {
     Left,
     Right,
 }
@@ -139,14 +155,13 @@ impl Ord for Visit {
 }
 
 #[derive(Debug, Clone)]
-struct Node {
-    label: Label,
+pub struct Node {
     left: Label,
     right: Label,
 }
 
 #[derive(Debug, Clone, Hash, Ord, PartialOrd, Eq, PartialEq)]
-struct Label {
+pub struct Label {
     value: String,
 }
 
@@ -158,7 +173,7 @@ impl Label {
     }
 
     fn is_start(&self) -> bool {
-        self.value.chars().last() == Some('A')
+        self.value.ends_with('A')
     }
 
     fn default_end() -> Label {
@@ -168,6 +183,6 @@ impl Label {
     }
 
     fn is_end(&self) -> bool {
-        self.value.chars().last() == Some('Z')
+        self.value.ends_with('Z')
     }
 }
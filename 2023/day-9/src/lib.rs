@@ -1,13 +1,29 @@
+use aoc_common::{print_answers, Part, Solution};
 use itertools::Itertools;
 
-fn main() {
-    let input = include_str!("input.txt");
-    let report = parse_report(input).unwrap().1;
-    let extrapolations = report.histories.iter().map(|h| extrapolate(&h.values)).collect_vec();
-    let summed_extrapolations = extrapolations.iter().map(|e| e.1).sum::<i64>();
-    println!("Part 1: {}", summed_extrapolations);
-    let summed_extrapolations = extrapolations.iter().map(|e| e.0).sum::<i64>();
-    println!("Part 2: {}", summed_extrapolations);
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input<'a> = Vec<(i64, i64)>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let report = parse_report(input).unwrap().1;
+        report.histories.iter().map(|h| extrapolate(&h.values)).collect_vec()
+    }
+
+    fn part_1(extrapolations: &Self::Input<'_>) -> Self::Answer1 {
+        extrapolations.iter().map(|e| e.1).sum()
+    }
+
+    fn part_2(extrapolations: &Self::Input<'_>) -> Self::Answer2 {
+        extrapolations.iter().map(|e| e.0).sum()
+    }
+}
+
+pub fn run(part: Part, input: &str, _args: &[String]) {
+    print_answers::<Puzzle>(input, part);
 }
 
 fn extrapolate(values: &[i64]) -> (i64, i64) {
@@ -25,7 +41,7 @@ fn extrapolate(values: &[i64]) -> (i64, i64) {
 type ParseError<'a> = nom::error::Error<&'a str>;
 type ParseResult<'a, I> = nom::IResult<&'a str, I, ParseError<'a>>;
 
-fn parse_report(input: &str) -> ParseResult<Report> {
+fn parse_report(input: &str) -> ParseResult<'_, Report> {
     let (input, all_values) = nom::multi::separated_list1(
         nom::character::complete::line_ending,
         nom::multi::separated_list1(
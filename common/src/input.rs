@@ -0,0 +1,178 @@
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+const SEARCH_DIRS: [&str; 3] = ["", "res", "src"];
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum InputSource {
+    #[default]
+    Puzzle,
+    Path(PathBuf),
+    Stdin,
+    Example(String),
+}
+
+impl InputSource {
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(arg))
+        }
+    }
+
+    fn candidates(&self, year: u16, day: u8, dir: &Path) -> Vec<PathBuf> {
+        match self {
+            InputSource::Puzzle => user_input_path(year, day)
+                .into_iter()
+                .chain(SEARCH_DIRS.iter().map(|sub| dir.join(sub).join("input.txt")))
+                .collect(),
+            InputSource::Path(path) => vec![path.clone()],
+            InputSource::Stdin => vec![],
+            InputSource::Example(name) => {
+                let names = [
+                    name.clone(),
+                    format!("example-{}.txt", name),
+                    format!("{}.txt", name),
+                ];
+                names
+                    .iter()
+                    .flat_map(|name| SEARCH_DIRS.iter().map(move |sub| dir.join(sub).join(name)))
+                    .collect()
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputErrorKind {
+    Missing(Vec<PathBuf>),
+    MissingExample(String, Vec<PathBuf>),
+    Unreadable(PathBuf, String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InputError {
+    pub year: u16,
+    pub day: u8,
+    pub kind: InputErrorKind,
+}
+
+fn write_tried(f: &mut Formatter<'_>, tried: &[PathBuf]) -> std::fmt::Result {
+    for path in tried {
+        write!(f, "\n  {}", path.display())?;
+    }
+    Ok(())
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {}: ", self.year, self.day)?;
+        match &self.kind {
+            InputErrorKind::Missing(tried) => {
+                write!(f, "no puzzle input found, tried:")?;
+                write_tried(f, tried)?;
+                if let Some(path) = tried.first() {
+                    write!(f, "\nsave your input as {}", path.display())?;
+                }
+                write!(f, " or pass --input <path>")
+            }
+            InputErrorKind::MissingExample(name, tried) => {
+                write!(f, "no example `{}` found, tried:", name)?;
+                write_tried(f, tried)
+            }
+            InputErrorKind::Unreadable(path, error) => {
+                write!(f, "cannot read {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn user_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("AOC_HOME") {
+        return Some(std::path::absolute(&dir).unwrap_or_else(|_| PathBuf::from(dir)));
+    }
+
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .map(|config| config.join("aoc"))
+}
+
+pub fn user_input_path(year: u16, day: u8) -> Option<PathBuf> {
+    user_dir().map(|dir| {
+        dir.join("inputs")
+            .join(year.to_string())
+            .join(format!("day-{}.txt", day))
+    })
+}
+
+pub fn load(source: &InputSource, year: u16, day: u8, dir: &Path) -> Result<String, InputError> {
+    let error = |kind| InputError { year, day, kind };
+
+    if *source == InputSource::Stdin {
+        let mut input = String::new();
+        return io::stdin()
+            .read_to_string(&mut input)
+            .map(|_| input)
+            .map_err(|e| error(InputErrorKind::Unreadable(PathBuf::from("-"), e.to_string())));
+    }
+
+    let tried = source.candidates(year, day, dir);
+    let path = match tried.iter().find(|path| path.is_file()) {
+        Some(path) => path,
+        None if matches!(source, InputSource::Path(_)) => {
+            let path = tried[0].clone();
+            return Err(error(InputErrorKind::Unreadable(path, "no such file".to_string())));
+        }
+        None => {
+            return Err(error(match source {
+                InputSource::Example(name) => InputErrorKind::MissingExample(name.clone(), tried),
+                _ => InputErrorKind::Missing(tried),
+            }))
+        }
+    };
+
+    fs::read_to_string(path)
+        .map_err(|e| error(InputErrorKind::Unreadable(path.clone(), e.to_string())))
+}
+
+pub fn puzzle_input(year: u16, day: u8, dir: impl AsRef<Path>) -> String {
+    load(&InputSource::Puzzle, year, day, dir.as_ref()).unwrap_or_else(|error| panic!("{}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../2019/day-5");
+        let example = fs::read_to_string(dir.join("res/example-2.txt")).unwrap();
+        for name in ["2", "example-2.txt", "res/example-2.txt"] {
+            let source = InputSource::Example(name.to_string());
+            assert_eq!(load(&source, 2019, 5, &dir), Ok(example.clone()));
+        }
+
+        let source = InputSource::Example("42".to_string());
+        let error = load(&source, 2019, 5, &dir).unwrap_err();
+        assert!(matches!(
+            error.kind,
+            InputErrorKind::MissingExample(ref name, ref tried)
+                if name == "42" && tried.len() == 9
+        ));
+        assert!(error.to_string().starts_with("2019 day 5: no example `42` found"));
+
+        let source = InputSource::from_arg("missing.txt");
+        assert!(matches!(
+            load(&source, 2019, 5, &dir).unwrap_err().kind,
+            InputErrorKind::Unreadable(..)
+        ));
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+    }
+}
@@ -0,0 +1,156 @@
+pub mod input;
+
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+    #[default]
+    Both,
+}
+
+impl Part {
+    pub fn includes(self, number: u8) -> bool {
+        matches!(
+            (self, number),
+            (Part::Both, 1 | 2) | (Part::One, 1) | (Part::Two, 2)
+        )
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+            Part::Both => write!(f, "both"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            "both" => Ok(Part::Both),
+            _ => Err(format!("unknown part `{}`, expected 1, 2 or both", s)),
+        }
+    }
+}
+
+pub struct NoPuzzle;
+
+impl Display for NoPuzzle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no puzzle")
+    }
+}
+
+pub trait Solution {
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Self::Input<'_>;
+    fn part_1(input: &Self::Input<'_>) -> Self::Answer1;
+    fn part_2(input: &Self::Input<'_>) -> Self::Answer2;
+}
+
+pub fn answers<S: Solution>(input: &str, part: Part) -> Vec<(u8, String)> {
+    let parsed = S::parse(input);
+    let mut answers = vec![];
+    if part.includes(1) {
+        answers.push((1, S::part_1(&parsed).to_string()));
+    }
+    if part.includes(2) {
+        answers.push((2, S::part_2(&parsed).to_string()));
+    }
+    answers
+}
+
+pub fn print_answers<S: Solution>(input: &str, part: Part) {
+    for (number, answer) in answers::<S>(input, part) {
+        if answer.contains('\n') {
+            println!("Part {}:\n{}", number, answer.trim_end());
+        } else {
+            println!("Part {}: {}", number, answer);
+        }
+    }
+}
+
+pub fn time_phases<S: Solution>(input: &str, part: Part) -> Vec<(&'static str, Duration)> {
+    let start = Instant::now();
+    let parsed = black_box(S::parse(black_box(input)));
+    let mut timings = vec![("parse", start.elapsed())];
+    if part.includes(1) {
+        let start = Instant::now();
+        black_box(S::part_1(&parsed));
+        timings.push(("part 1", start.elapsed()));
+    }
+    if part.includes(2) {
+        let start = Instant::now();
+        black_box(S::part_2(&parsed));
+        timings.push(("part 2", start.elapsed()));
+    }
+    timings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part() {
+        assert_eq!("2".parse(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+        assert!(Part::Both.includes(1) && Part::Both.includes(2));
+        assert!(Part::One.includes(1) && !Part::One.includes(2));
+        assert!(!Part::Two.includes(3));
+    }
+
+    struct Words;
+
+    impl Solution for Words {
+        type Input<'a> = Vec<&'a str>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(input: &str) -> Self::Input<'_> {
+            input.split_whitespace().collect()
+        }
+
+        fn part_1(input: &Self::Input<'_>) -> Self::Answer1 {
+            input.len()
+        }
+
+        fn part_2(input: &Self::Input<'_>) -> Self::Answer2 {
+            input.concat()
+        }
+    }
+
+    #[test]
+    fn test_answers() {
+        assert_eq!(
+            answers::<Words>("a bc d", Part::Both),
+            vec![(1, "3".to_string()), (2, "abcd".to_string())]
+        );
+        assert_eq!(answers::<Words>("a", Part::Two), vec![(2, "a".to_string())]);
+    }
+
+    #[test]
+    fn test_time_phases() {
+        let phases = time_phases::<Words>("a bc d", Part::Both)
+            .into_iter()
+            .map(|(phase, _)| phase)
+            .collect::<Vec<_>>();
+        assert_eq!(phases, vec!["parse", "part 1", "part 2"]);
+        assert_eq!(time_phases::<Words>("a", Part::One).len(), 2);
+    }
+}
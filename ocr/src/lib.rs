@@ -0,0 +1,334 @@
+use std::fmt::{Display, Formatter};
+
+type Glyph = (char, &'static [&'static str]);
+
+const SMALL_GLYPHS: [Glyph; 18] = [
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+const LARGE_GLYPHS: [Glyph; 15] = [
+    (
+        'A',
+        &[
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        &[
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        &[
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        &[
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        &[
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        &[
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        &[
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        &[
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OcrErrorKind {
+    UnsupportedHeight(usize),
+    UnknownGlyph(String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OcrError {
+    pub column: usize,
+    pub kind: OcrErrorKind,
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "column {}: ", self.column)?;
+        match &self.kind {
+            OcrErrorKind::UnsupportedHeight(height) => {
+                write!(f, "unsupported letter height {}, expected 6 or 10", height)
+            }
+            OcrErrorKind::UnknownGlyph(glyph) => {
+                write!(f, "unknown glyph\n{}", glyph)
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+type Column = u16;
+
+fn is_lit(pixel: char) -> bool {
+    pixel == '#' || pixel == '\u{2588}'
+}
+
+fn glyph_columns(rows: &[&str]) -> Vec<Column> {
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or_default();
+    let columns = (0..width)
+        .map(|col| {
+            rows.iter().enumerate().fold(0, |column, (row, line)| {
+                let lit = line.chars().nth(col).is_some_and(is_lit);
+                column | (lit as Column) << row
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let start = columns.iter().position(|column| *column != 0);
+    let end = columns.iter().rposition(|column| *column != 0);
+    match (start, end) {
+        (Some(start), Some(end)) => columns[start..=end].to_vec(),
+        _ => vec![],
+    }
+}
+
+fn render(columns: &[Column], height: usize) -> String {
+    (0..height)
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| if column >> row & 1 == 1 { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn recognize_pixels(pixels: &[Vec<bool>]) -> Result<String, OcrError> {
+    let first = pixels.iter().position(|row| row.contains(&true));
+    let last = pixels.iter().rposition(|row| row.contains(&true));
+    let rows = match (first, last) {
+        (Some(first), Some(last)) => &pixels[first..=last],
+        _ => return Ok(String::new()),
+    };
+
+    let glyphs: &[Glyph] = match rows.len() {
+        6 => &SMALL_GLYPHS,
+        10 => &LARGE_GLYPHS,
+        height => {
+            return Err(OcrError {
+                column: 0,
+                kind: OcrErrorKind::UnsupportedHeight(height),
+            })
+        }
+    };
+    let glyphs = glyphs
+        .iter()
+        .map(|(letter, rows)| (*letter, glyph_columns(rows)))
+        .collect::<Vec<_>>();
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let columns = (0..width)
+        .map(|col| {
+            rows.iter().enumerate().fold(0, |column, (row, line)| {
+                let lit = line.get(col).copied().unwrap_or_default();
+                column | (lit as Column) << row
+            })
+        })
+        .collect::<Vec<Column>>();
+
+    let mut text = String::new();
+    let mut col = 0;
+    while col < width {
+        if columns[col] == 0 {
+            col += 1;
+            continue;
+        }
+
+        let rest = &columns[col..];
+        let matched = glyphs
+            .iter()
+            .filter(|(_, glyph)| rest.starts_with(glyph))
+            .max_by_key(|(_, glyph)| glyph.len());
+
+        match matched {
+            Some((letter, glyph)) => {
+                text.push(*letter);
+                col += glyph.len();
+            }
+            None => {
+                let end = rest
+                    .iter()
+                    .position(|column| *column == 0)
+                    .unwrap_or(rest.len());
+                return Err(OcrError {
+                    column: col,
+                    kind: OcrErrorKind::UnknownGlyph(render(&rest[..end], rows.len())),
+                });
+            }
+        }
+    }
+
+    Ok(text)
+}
+
+pub fn recognize(image: &str) -> Result<String, OcrError> {
+    let pixels = image
+        .lines()
+        .map(|line| line.chars().map(is_lit).collect())
+        .collect::<Vec<_>>();
+
+    recognize_pixels(&pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(glyphs: &[Glyph], text: &str, gap: &str) -> String {
+        let height = glyphs[0].1.len();
+        (0..height)
+            .map(|row| {
+                text.chars()
+                    .map(|letter| {
+                        let (_, rows) = glyphs.iter().find(|(l, _)| *l == letter).unwrap();
+                        rows[row]
+                    })
+                    .collect::<Vec<_>>()
+                    .join(gap)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_small() {
+        let screen = "
+█    ███  ████   ██
+█    █  █ █       █
+█    █  █ ███     █
+█    ███  █       █
+█    █ █  █    █  █
+████ █  █ █     ██  ";
+        assert_eq!(recognize(screen), Ok("LRFJ".to_string()));
+
+        let letters = SMALL_GLYPHS.iter().map(|(l, _)| l).collect::<String>();
+        let image = image(&SMALL_GLYPHS, &letters, ".");
+        assert_eq!(recognize(&image), Ok(letters));
+    }
+
+    #[test]
+    fn test_large() {
+        let letters = LARGE_GLYPHS.iter().map(|(l, _)| l).collect::<String>();
+        let image = image(&LARGE_GLYPHS, &letters, "..");
+        assert_eq!(recognize(&image), Ok(letters));
+    }
+
+    #[test]
+    fn test_errors() {
+        let boxed = "####\n#..#\n#..#\n#..#\n#..#\n####";
+        let screen = "#.....####\n#.....#..#\n#.....#..#\n\
+                      #.....#..#\n#.....#..#\n####..####";
+        let error = recognize(screen).unwrap_err();
+        assert_eq!(error.column, 6);
+        assert_eq!(error.kind, OcrErrorKind::UnknownGlyph(boxed.to_string()));
+        assert!(error.to_string().starts_with("column 6: unknown glyph\n"));
+
+        assert_eq!(
+            recognize("#\n#\n#").unwrap_err().kind,
+            OcrErrorKind::UnsupportedHeight(3)
+        );
+    }
+}
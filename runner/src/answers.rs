@@ -0,0 +1,227 @@
+use aoc_common::input::user_dir;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub type Key = (u16, u8, u8);
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum AnswersErrorKind {
+    InvalidSection(String),
+    InvalidKey(String),
+    InvalidValue(String),
+    MissingSection,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct AnswersError {
+    pub line: usize,
+    pub kind: AnswersErrorKind,
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            AnswersErrorKind::InvalidSection(section) => {
+                write!(f, "invalid section '{}', expected [<year>.<day>]", section)
+            }
+            AnswersErrorKind::InvalidKey(key) => {
+                write!(f, "invalid key '{}', expected part1 or part2", key)
+            }
+            AnswersErrorKind::InvalidValue(value) => {
+                write!(f, "invalid value '{}'", value)
+            }
+            AnswersErrorKind::MissingSection => {
+                write!(f, "answer outside of a [<year>.<day>] section")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Answers(BTreeMap<Key, String>);
+
+impl Answers {
+    pub fn get(&self, key: Key) -> Option<&str> {
+        self.0.get(&key).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, key: Key, answer: String) {
+        self.0.insert(key, answer);
+    }
+
+    pub fn parse(content: &str) -> Result<Self, AnswersError> {
+        let mut answers = Answers::default();
+        let mut section = None;
+        for (index, line) in content.lines().enumerate() {
+            let error = |kind| AnswersError {
+                line: index + 1,
+                kind,
+            };
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let invalid = || error(AnswersErrorKind::InvalidSection(name.to_string()));
+                let (year, day) = name.trim().split_once('.').ok_or_else(invalid)?;
+                let year = year.parse().map_err(|_| invalid())?;
+                let day = day.parse().map_err(|_| invalid())?;
+                section = Some((year, day));
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error(AnswersErrorKind::InvalidKey(line.to_string())))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                key => return Err(error(AnswersErrorKind::InvalidKey(key.to_string()))),
+            };
+            let value = unquote(value.trim())
+                .ok_or_else(|| error(AnswersErrorKind::InvalidValue(value.trim().to_string())))?;
+            let (year, day) = section.ok_or_else(|| error(AnswersErrorKind::MissingSection))?;
+            answers.insert((year, day, part), value);
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut section = None;
+        for (&(year, day, part), answer) in &self.0 {
+            if section != Some((year, day)) {
+                if section.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[{}.{}]", year, day)?;
+                section = Some((year, day));
+            }
+            writeln!(f, "part{} = {}", part, quote(answer))?;
+        }
+        Ok(())
+    }
+}
+
+fn quote(answer: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in answer.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn unquote(value: &str) -> Option<String> {
+    let Some(inner) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) else {
+        return value
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '-')
+            .then(|| value.to_string())
+            .filter(|value| !value.is_empty());
+    };
+
+    let mut answer = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                'n' => answer.push('\n'),
+                '"' => answer.push('"'),
+                '\\' => answer.push('\\'),
+                _ => return None,
+            },
+            '"' => return None,
+            c => answer.push(c),
+        }
+    }
+    Some(answer)
+}
+
+pub fn path() -> Option<PathBuf> {
+    user_dir().map(|dir| dir.join("answers.toml"))
+}
+
+pub fn load(path: &Path) -> Result<Answers, String> {
+    match fs::read_to_string(path) {
+        Ok(content) => {
+            Answers::parse(&content).map_err(|error| format!("{}: {}", path.display(), error))
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(error) => Err(format!("cannot read {}: {}", path.display(), error)),
+    }
+}
+
+pub fn save(path: &Path, answers: &Answers) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, answers.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let content = "\
+# recorded answers
+[2019.1]
+part1 = 3311492
+part2 = \"4964376\"
+
+[2021.13]
+part2 = \"LRFJBJEH\"
+";
+        let answers = Answers::parse(content).unwrap();
+        assert_eq!(answers.get((2019, 1, 1)), Some("3311492"));
+        assert_eq!(answers.get((2019, 1, 2)), Some("4964376"));
+        assert_eq!(answers.get((2021, 13, 1)), None);
+        assert_eq!(answers.get((2021, 13, 2)), Some("LRFJBJEH"));
+
+        assert_eq!(
+            Answers::parse("part1 = 3"),
+            Err(AnswersError {
+                line: 1,
+                kind: AnswersErrorKind::MissingSection
+            })
+        );
+        assert_eq!(
+            Answers::parse("[2019]\npart1 = 3").unwrap_err().kind,
+            AnswersErrorKind::InvalidSection("2019".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[2019.1]\npart3 = 3").unwrap_err().kind,
+            AnswersErrorKind::InvalidKey("part3".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[2019.1]\npart1 = abc").unwrap_err().kind,
+            AnswersErrorKind::InvalidValue("abc".to_string())
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert((2020, 3, 2), "3847183340".to_string());
+        answers.insert((2020, 3, 1), "a \"quoted\"\\\nanswer".to_string());
+        answers.insert((2019, 25, 1), "537002052".to_string());
+        assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+    }
+}
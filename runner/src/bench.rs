@@ -0,0 +1,292 @@
+use crate::days::Day;
+use aoc_common::input::user_dir;
+use aoc_common::Part;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const HISTORY_LIMIT: usize = 100;
+const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Options {
+    pub warmup: usize,
+    pub samples: usize,
+    pub budget: Duration,
+    pub threshold: u32,
+    pub save: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            warmup: 1,
+            samples: 10,
+            budget: Duration::from_secs(10),
+            threshold: 10,
+            save: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub phase: String,
+    pub median: Duration,
+    pub min: Duration,
+    pub samples: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Run {
+    pub timestamp: u64,
+    pub measurements: Vec<Measurement>,
+}
+
+impl Run {
+    pub fn now() -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Run {
+            timestamp,
+            measurements: vec![],
+        }
+    }
+}
+
+fn format_record(timestamp: u64, m: &Measurement) -> String {
+    format!(
+        "{} {} {} {} {} {} {}",
+        timestamp,
+        m.year,
+        m.day,
+        m.median.as_nanos(),
+        m.min.as_nanos(),
+        m.samples,
+        m.phase
+    )
+}
+
+fn parse_record(line: &str) -> Option<(u64, Measurement)> {
+    let mut fields = line.splitn(7, ' ');
+    let mut field = || fields.next().filter(|field| !field.is_empty());
+    let timestamp = field()?.parse().ok()?;
+    let measurement = Measurement {
+        year: field()?.parse().ok()?,
+        day: field()?.parse().ok()?,
+        median: Duration::from_nanos(field()?.parse().ok()?),
+        min: Duration::from_nanos(field()?.parse().ok()?),
+        samples: field()?.parse().ok()?,
+        phase: field()?.to_string(),
+    };
+    Some((timestamp, measurement))
+}
+
+pub fn format_history(history: &[Run]) -> String {
+    let start = history.len().saturating_sub(HISTORY_LIMIT);
+    let mut content = String::from("# timestamp year day median_ns min_ns samples phase\n");
+    for run in &history[start..] {
+        for measurement in &run.measurements {
+            content.push_str(&format_record(run.timestamp, measurement));
+            content.push('\n');
+        }
+    }
+    content
+}
+
+pub fn parse_history(content: &str) -> Result<Vec<Run>, usize> {
+    let mut history: Vec<Run> = vec![];
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (timestamp, measurement) = parse_record(line).ok_or(index + 1)?;
+        match history.last_mut() {
+            Some(run)
+                if run.timestamp == timestamp
+                    && !run.measurements.iter().any(|m| same_phase(m, &measurement)) =>
+            {
+                run.measurements.push(measurement)
+            }
+            _ => history.push(Run {
+                timestamp,
+                measurements: vec![measurement],
+            }),
+        }
+    }
+    Ok(history)
+}
+
+pub fn measure(day: &Day, input: &str, part: Part, options: &Options) -> Vec<Measurement> {
+    for _ in 0..options.warmup {
+        (day.phases)(input, part);
+    }
+
+    let start = Instant::now();
+    let mut samples = vec![];
+    while samples.len() < options.samples.max(1) {
+        samples.push((day.phases)(input, part));
+        if start.elapsed() >= options.budget {
+            break;
+        }
+    }
+
+    samples[0]
+        .iter()
+        .enumerate()
+        .map(|(index, &(phase, _))| {
+            let mut durations = samples.iter().map(|s| s[index].1).collect::<Vec<_>>();
+            durations.sort();
+            Measurement {
+                year: day.year,
+                day: day.day,
+                phase: phase.to_string(),
+                median: durations[durations.len() / 2],
+                min: durations[0],
+                samples: durations.len(),
+            }
+        })
+        .collect()
+}
+
+fn same_phase(left: &Measurement, right: &Measurement) -> bool {
+    (left.year, left.day, &left.phase) == (right.year, right.day, &right.phase)
+}
+
+pub fn previous<'a>(history: &'a [Run], current: &Measurement) -> Option<&'a Measurement> {
+    history
+        .iter()
+        .rev()
+        .find_map(|run| run.measurements.iter().find(|m| same_phase(m, current)))
+}
+
+pub fn change(current: &Measurement, previous: &Measurement, threshold: u32) -> (f64, bool) {
+    let ratio = current.median.as_secs_f64() / previous.median.as_secs_f64().max(1e-9);
+    let slower = current.median.saturating_sub(previous.median);
+    let regression = ratio > 1.0 + threshold as f64 / 100.0 && slower > NOISE_FLOOR;
+    (ratio - 1.0, regression)
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+pub fn history_path() -> Option<PathBuf> {
+    user_dir().map(|dir| dir.join("bench.txt"))
+}
+
+pub fn load_history(path: &Path) -> Result<Vec<Run>, String> {
+    match fs::read_to_string(path) {
+        Ok(content) => parse_history(&content).map_err(|line| {
+            format!(
+                "{}: line {}: invalid benchmark record",
+                path.display(),
+                line
+            )
+        }),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(error) => Err(format!("cannot read {}: {}", path.display(), error)),
+    }
+}
+
+pub fn save_history(path: &Path, history: &[Run]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, format_history(history))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(phase: &str, millis: u64) -> Measurement {
+        Measurement {
+            year: 2020,
+            day: 15,
+            phase: phase.to_string(),
+            median: Duration::from_millis(millis),
+            min: Duration::from_millis(millis - 1),
+            samples: 5,
+        }
+    }
+
+    #[test]
+    fn test_regressions() {
+        let history = vec![
+            Run {
+                timestamp: 1,
+                measurements: vec![measurement("parse", 2), measurement("part 1", 100)],
+            },
+            Run {
+                timestamp: 2,
+                measurements: vec![measurement("part 1", 200)],
+            },
+        ];
+
+        let current = measurement("part 1", 230);
+        let last = previous(&history, &current).unwrap();
+        assert_eq!(last.median, Duration::from_millis(200));
+        let (ratio, regression) = change(&current, last, 10);
+        assert!((ratio - 0.15).abs() < 1e-9 && regression);
+        assert!(!change(&current, last, 20).1);
+
+        let current = measurement("parse", 3);
+        let (_, regression) = change(&current, previous(&history, &current).unwrap(), 10);
+        assert!(regression);
+
+        let mut current = measurement("parse", 2);
+        current.median = Duration::from_micros(2050);
+        assert!(!change(&current, previous(&history, &current).unwrap(), 1).1);
+        assert_eq!(previous(&history, &measurement("part 2", 5)), None);
+    }
+
+    #[test]
+    fn test_history_records() {
+        let history = vec![
+            Run {
+                timestamp: 1700000000,
+                measurements: vec![measurement("parse", 2), measurement("part 2", 1234)],
+            },
+            Run {
+                timestamp: 1700000100,
+                measurements: vec![measurement("part 1", 3)],
+            },
+        ];
+        let content = format_history(&history);
+        assert!(content.contains("\n1700000000 2020 15 1234000000 1233000000 5 part 2\n"));
+        assert_eq!(parse_history(&content), Ok(history));
+
+        let repeated = "1 2020 15 5 4 3 parse\n1 2020 15 6 4 3 parse\n";
+        assert_eq!(parse_history(repeated).map(|history| history.len()), Ok(2));
+
+        assert_eq!(
+            parse_history("# header\n\n1 2020 15 5 4 3 parse\n1 2020 x"),
+            Err(4)
+        );
+        assert_eq!(parse_history("1 2020 15 5 4 3"), Err(1));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(850)), "850ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_340)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(120_500)), "120.5ms");
+        assert_eq!(format_duration(Duration::from_millis(1234)), "1.23s");
+    }
+}
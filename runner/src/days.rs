@@ -0,0 +1,139 @@
+use aoc_common::Part;
+use std::time::Duration;
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub path: &'static str,
+    pub run: fn(Part, &str, &[String]),
+    pub answers: fn(&str, Part) -> Vec<(u8, String)>,
+    pub phases: fn(&str, Part) -> Vec<(&'static str, Duration)>,
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $krate:ident) => {
+        day!(
+            $year,
+            $day,
+            $krate,
+            concat!(stringify!($year), "/day-", stringify!($day))
+        )
+    };
+    ($year:literal, $day:literal, $krate:ident, $path:expr) => {
+        Day {
+            year: $year,
+            day: $day,
+            path: $path,
+            run: $krate::run,
+            answers: aoc_common::answers::<$krate::Puzzle>,
+            phases: aoc_common::time_phases::<$krate::Puzzle>,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(2019, 1, aoc_2019_day_1),
+    day!(2019, 2, aoc_2019_day_2),
+    day!(2019, 3, aoc_2019_day_3),
+    day!(2019, 4, aoc_2019_day_4),
+    day!(2019, 5, aoc_2019_day_5),
+    day!(2019, 6, aoc_2019_day_6),
+    day!(2019, 7, aoc_2019_day_7),
+    day!(2019, 8, aoc_2019_day_8),
+    day!(2019, 9, aoc_2019_day_9),
+    day!(2019, 10, aoc_2019_day_10),
+    day!(2019, 11, aoc_2019_day_11),
+    day!(2019, 12, aoc_2019_day_12),
+    day!(2019, 13, aoc_2019_day_13),
+    day!(2019, 14, aoc_2019_day_14),
+    day!(2019, 15, aoc_2019_day_15),
+    day!(2019, 16, aoc_2019_day_16),
+    day!(2019, 17, aoc_2019_day_17),
+    day!(2019, 18, aoc_2019_day_18),
+    day!(2019, 19, aoc_2019_day_19),
+    day!(2019, 20, aoc_2019_day_20),
+    day!(2019, 21, aoc_2019_day_21),
+    day!(2019, 22, aoc_2019_day_22),
+    day!(2019, 23, aoc_2019_day_23),
+    day!(2019, 24, aoc_2019_day_24),
+    day!(2019, 25, aoc_2019_day_25),
+    day!(2020, 1, aoc_2020_day_1),
+    day!(2020, 2, aoc_2020_day_2),
+    day!(2020, 3, aoc_2020_day_3),
+    day!(2020, 4, aoc_2020_day_4),
+    day!(2020, 5, aoc_2020_day_5),
+    day!(2020, 6, aoc_2020_day_6),
+    day!(2020, 7, aoc_2020_day_7),
+    day!(2020, 8, aoc_2020_day_8),
+    day!(2020, 9, aoc_2020_day_9),
+    day!(2020, 10, aoc_2020_day_10),
+    day!(2020, 11, aoc_2020_day_11),
+    day!(2020, 12, aoc_2020_day_12),
+    day!(2020, 13, aoc_2020_day_13),
+    day!(2020, 14, aoc_2020_day_14),
+    day!(2020, 15, aoc_2020_day_15),
+    day!(2020, 16, aoc_2020_day_16),
+    day!(2020, 17, aoc_2020_day_17),
+    day!(2020, 18, aoc_2020_day_18, "2020/day-18/rust"),
+    day!(2020, 19, aoc_2020_day_19),
+    day!(2020, 20, aoc_2020_day_20),
+    day!(2020, 21, aoc_2020_day_21),
+    day!(2020, 22, aoc_2020_day_22),
+    day!(2020, 23, aoc_2020_day_23),
+    day!(2020, 24, aoc_2020_day_24),
+    day!(2020, 25, aoc_2020_day_25),
+    day!(2021, 1, aoc_2021_day_1),
+    day!(2021, 2, aoc_2021_day_2),
+    day!(2021, 3, aoc_2021_day_3),
+    day!(2021, 4, aoc_2021_day_4),
+    day!(2021, 5, aoc_2021_day_5),
+    day!(2021, 6, aoc_2021_day_6),
+    day!(2021, 7, aoc_2021_day_7),
+    day!(2021, 8, aoc_2021_day_8),
+    day!(2021, 9, aoc_2021_day_9),
+    day!(2021, 10, aoc_2021_day_10),
+    day!(2021, 11, aoc_2021_day_11),
+    day!(2021, 12, aoc_2021_day_12),
+    day!(2021, 13, aoc_2021_day_13),
+    day!(2021, 14, aoc_2021_day_14),
+    day!(2021, 15, aoc_2021_day_15),
+    day!(2021, 16, aoc_2021_day_16),
+    day!(2021, 17, aoc_2021_day_17),
+    day!(2021, 18, aoc_2021_day_18),
+    day!(2021, 19, aoc_2021_day_19),
+    day!(2021, 20, aoc_2021_day_20),
+    day!(2021, 21, aoc_2021_day_21),
+    day!(2021, 22, aoc_2021_day_22),
+    day!(2021, 23, aoc_2021_day_23),
+    day!(2021, 24, aoc_2021_day_24),
+    day!(2021, 25, aoc_2021_day_25),
+    day!(2022, 1, aoc_2022_day_1),
+    day!(2022, 2, aoc_2022_day_2),
+    day!(2022, 3, aoc_2022_day_3),
+    day!(2022, 4, aoc_2022_day_4),
+    day!(2022, 5, aoc_2022_day_5),
+    day!(2022, 6, aoc_2022_day_6),
+    day!(2022, 7, aoc_2022_day_7),
+    day!(2022, 8, aoc_2022_day_8),
+    day!(2022, 9, aoc_2022_day_9),
+    day!(2022, 10, aoc_2022_day_10),
+    day!(2022, 11, aoc_2022_day_11),
+    day!(2023, 1, aoc_2023_day_1),
+    day!(2023, 2, aoc_2023_day_2),
+    day!(2023, 3, aoc_2023_day_3),
+    day!(2023, 4, aoc_2023_day_4),
+    day!(2023, 5, aoc_2023_day_5),
+    day!(2023, 6, aoc_2023_day_6),
+    day!(2023, 7, aoc_2023_day_7),
+    day!(2023, 8, aoc_2023_day_8),
+    day!(2023, 9, aoc_2023_day_9),
+    day!(2023, 10, aoc_2023_day_10),
+    day!(2023, 11, aoc_2023_day_11),
+    day!(2023, 12, aoc_2023_day_12),
+    day!(2023, 13, aoc_2023_day_13),
+    day!(2023, 14, aoc_2023_day_14),
+    day!(2023, 15, aoc_2023_day_15),
+    day!(2023, 16, aoc_2023_day_16),
+    day!(2023, 17, aoc_2023_day_17),
+    day!(2023, 18, aoc_2023_day_18),
+];
@@ -0,0 +1,554 @@
+mod answers;
+mod bench;
+mod days;
+
+use aoc_common::input::{self, InputError, InputSource};
+use aoc_common::Part;
+use days::{Day, DAYS};
+use std::collections::BTreeMap;
+use std::env;
+use std::panic;
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+const USAGE: &str = "\
+usage: aoc list [year]
+       aoc run all [--part 1|2|both] [--example <name>] [-- args...]
+       aoc run <year> [day] [--part 1|2|both] [--input <path>|-]
+                            [--example <name>] [-- args...]
+       aoc verify all|<year> [day] [--part 1|2|both] [--record]
+       aoc bench all|<year> [day] [--part 1|2|both] [--warmup <runs>]
+                            [--samples <runs>] [--budget <seconds>]
+                            [--threshold <percent>] [--no-save]
+
+Inputs are read from $AOC_HOME/inputs/<year>/day-<day>.txt (by default
+~/.config/aoc/inputs), falling back to the input.txt of the day.
+Expected answers are read from $AOC_HOME/answers.toml, which --record
+fills in with the answers that are still missing. Benchmarks are appended
+to $AOC_HOME/bench.txt and compared with the previous run.";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Selection {
+    All,
+    Year(u16),
+    Day(u16, u8),
+}
+
+impl Selection {
+    fn matches(self, day: &Day) -> bool {
+        match self {
+            Selection::All => true,
+            Selection::Year(year) => day.year == year,
+            Selection::Day(year, number) => day.year == year && day.day == number,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Command {
+    List(Option<u16>),
+    Run {
+        selection: Selection,
+        part: Part,
+        source: InputSource,
+        args: Vec<String>,
+    },
+    Verify {
+        selection: Selection,
+        part: Part,
+        record: bool,
+    },
+    Bench {
+        selection: Selection,
+        part: Part,
+        options: bench::Options,
+    },
+}
+
+fn parse_number<T: std::str::FromStr>(word: &str, what: &str) -> Result<T, String> {
+    word.parse()
+        .map_err(|_| format!("invalid {} `{}`", what, word))
+}
+
+fn parse_selection(words: &[String]) -> Result<Selection, String> {
+    match words {
+        [all] if all == "all" => Ok(Selection::All),
+        [year] => Ok(Selection::Year(parse_number(year, "year")?)),
+        [year, day] => Ok(Selection::Day(
+            parse_number(year, "year")?,
+            parse_number(day, "day")?,
+        )),
+        [] => Err("missing year".to_string()),
+        _ => Err(format!("unexpected argument `{}`", words[2])),
+    }
+}
+
+fn parse_command(args: &[String]) -> Result<Command, String> {
+    let (command, rest) = match args.split_first() {
+        Some((command, rest)) => (command.as_str(), rest),
+        None => return Err("missing command".to_string()),
+    };
+
+    match command {
+        "list" => match rest {
+            [] => Ok(Command::List(None)),
+            [year] => Ok(Command::List(Some(parse_number(year, "year")?))),
+            _ => Err(format!("unexpected argument `{}`", rest[1])),
+        },
+        "run" => {
+            let (rest, day_args) = match rest.iter().position(|arg| arg == "--") {
+                Some(index) => (&rest[..index], rest[index + 1..].to_vec()),
+                None => (rest, vec![]),
+            };
+
+            let mut part = Part::Both;
+            let mut source = InputSource::Puzzle;
+            let mut words = vec![];
+            let mut iter = rest.iter();
+            while let Some(arg) = iter.next() {
+                if arg == "--part" {
+                    let value = iter.next().ok_or("missing part after --part")?;
+                    part = value.parse()?;
+                } else if arg == "--input" {
+                    let value = iter.next().ok_or("missing path after --input")?;
+                    source = InputSource::from_arg(value);
+                } else if arg == "--example" {
+                    let value = iter.next().ok_or("missing name after --example")?;
+                    source = InputSource::Example(value.clone());
+                } else {
+                    words.push(arg.clone());
+                }
+            }
+
+            let selection = parse_selection(&words)?;
+            let single_input = matches!(source, InputSource::Path(_) | InputSource::Stdin);
+            if single_input && !matches!(selection, Selection::Day(..)) {
+                return Err("--input needs a single day".to_string());
+            }
+
+            Ok(Command::Run {
+                selection,
+                part,
+                source,
+                args: day_args,
+            })
+        }
+        "verify" => {
+            let mut part = Part::Both;
+            let mut record = false;
+            let mut words = vec![];
+            let mut iter = rest.iter();
+            while let Some(arg) = iter.next() {
+                if arg == "--part" {
+                    let value = iter.next().ok_or("missing part after --part")?;
+                    part = value.parse()?;
+                } else if arg == "--record" {
+                    record = true;
+                } else {
+                    words.push(arg.clone());
+                }
+            }
+
+            Ok(Command::Verify {
+                selection: parse_selection(&words)?,
+                part,
+                record,
+            })
+        }
+        "bench" => {
+            let mut part = Part::Both;
+            let mut options = bench::Options::default();
+            let mut words = vec![];
+            let mut iter = rest.iter();
+            while let Some(arg) = iter.next() {
+                let mut value =
+                    |what: &str| iter.next().ok_or(format!("missing {} after {}", what, arg));
+                match arg.as_str() {
+                    "--part" => part = value("part")?.parse()?,
+                    "--warmup" => options.warmup = parse_number(value("runs")?, "runs")?,
+                    "--samples" => options.samples = parse_number(value("runs")?, "runs")?,
+                    "--budget" => {
+                        let seconds = parse_number(value("seconds")?, "seconds")?;
+                        options.budget = Duration::from_secs(seconds);
+                    }
+                    "--threshold" => {
+                        options.threshold = parse_number(value("percent")?, "percent")?
+                    }
+                    "--no-save" => options.save = false,
+                    _ => words.push(arg.clone()),
+                }
+            }
+
+            Ok(Command::Bench {
+                selection: parse_selection(&words)?,
+                part,
+                options,
+            })
+        }
+        _ => Err(format!("unknown command `{}`", command)),
+    }
+}
+
+fn list(year: Option<u16>) {
+    let mut years: BTreeMap<u16, Vec<u8>> = BTreeMap::new();
+    for day in DAYS.iter().filter(|day| year.is_none_or(|y| y == day.year)) {
+        years.entry(day.year).or_default().push(day.day);
+    }
+
+    for (year, days) in years {
+        let days = days.iter().map(|day| day.to_string()).collect::<Vec<_>>();
+        println!("{}: {}", year, days.join(" "));
+    }
+}
+
+fn select(selection: Selection) -> Vec<&'static Day> {
+    let days = DAYS
+        .iter()
+        .filter(|day| selection.matches(day))
+        .collect::<Vec<_>>();
+
+    if days.is_empty() {
+        eprintln!("No solution matches {:?}", selection);
+    }
+    days
+}
+
+fn load_input(day: &Day, source: &InputSource) -> Result<String, InputError> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(day.path);
+    input::load(source, day.year, day.day, &dir)
+}
+
+fn run(selection: Selection, part: Part, source: &InputSource, args: &[String]) -> bool {
+    let days = select(selection);
+    if days.is_empty() {
+        return false;
+    }
+
+    let mut failed = vec![];
+    for day in &days {
+        if days.len() > 1 {
+            println!("== {} day {} ==", day.year, day.day);
+        }
+
+        let input = match load_input(day, source) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{}", error);
+                failed.push(format!("{} day {}", day.year, day.day));
+                continue;
+            }
+        };
+
+        if panic::catch_unwind(|| (day.run)(part, &input, args)).is_err() {
+            failed.push(format!("{} day {}", day.year, day.day));
+        }
+    }
+
+    if !failed.is_empty() {
+        eprintln!("Failed: {}", failed.join(", "));
+    }
+    failed.is_empty()
+}
+
+fn print_row(day: &Day, part: &str, status: &str, detail: &str) {
+    println!(
+        "{:<4} {:>3} {:>4}  {:<7}  {}",
+        day.year,
+        day.day,
+        part,
+        status,
+        detail.replace('\n', "\\n")
+    );
+}
+
+fn verify(selection: Selection, part: Part, record: bool) -> bool {
+    let Some(path) = answers::path() else {
+        eprintln!("Cannot locate the answers file, set AOC_HOME");
+        return false;
+    };
+    let mut answers = match answers::load(&path) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return false;
+        }
+    };
+
+    let days = select(selection);
+    if days.is_empty() {
+        return false;
+    }
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    println!(
+        "{:<4} {:>3} {:>4}  {:<7}  answer",
+        "year", "day", "part", "status"
+    );
+    for day in days {
+        let results = match load_input(day, &InputSource::Puzzle) {
+            Ok(input) => panic::catch_unwind(|| (day.answers)(&input, part)),
+            Err(error) => {
+                eprintln!("{}", error);
+                print_row(day, "-", "error", "no input");
+                failed += 1;
+                continue;
+            }
+        };
+
+        let Ok(results) = results else {
+            print_row(day, "-", "panic", "");
+            failed += 1;
+            continue;
+        };
+
+        for (number, answer) in results {
+            let key = (day.year, day.day, number);
+            let part = number.to_string();
+            match answers.get(key) {
+                Some(expected) if expected == answer => {
+                    print_row(day, &part, "pass", &answer);
+                    passed += 1;
+                }
+                Some(expected) => {
+                    let detail = format!("{}, expected {}", answer, expected);
+                    print_row(day, &part, "FAIL", &detail);
+                    failed += 1;
+                }
+                None => {
+                    print_row(day, &part, "missing", &answer);
+                    if record {
+                        answers.insert(key, answer);
+                    }
+                    missing += 1;
+                }
+            }
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+    if record && missing > 0 {
+        match answers::save(&path, &answers) {
+            Ok(()) => println!("Recorded {} answers in {}", missing, path.display()),
+            Err(error) => {
+                eprintln!("Cannot write {}: {}", path.display(), error);
+                return false;
+            }
+        }
+    }
+    failed == 0
+}
+
+fn bench(selection: Selection, part: Part, options: &bench::Options) -> bool {
+    let path = bench::history_path();
+    let mut history = match path.as_deref().map(bench::load_history) {
+        Some(Ok(history)) => history,
+        Some(Err(error)) => {
+            eprintln!("error: {}", error);
+            return false;
+        }
+        None => vec![],
+    };
+
+    let days = select(selection);
+    if days.is_empty() {
+        return false;
+    }
+
+    let mut run = bench::Run::now();
+    let (mut failed, mut regressions) = (vec![], 0);
+    println!(
+        "{:<4} {:>3}  {:<6}  {:>9}  {:>9}  {:>7}  change",
+        "year", "day", "phase", "median", "min", "samples"
+    );
+    for day in days {
+        let measurements = match load_input(day, &InputSource::Puzzle) {
+            Ok(input) => panic::catch_unwind(|| bench::measure(day, &input, part, options)),
+            Err(error) => {
+                eprintln!("{}", error);
+                failed.push(format!("{} day {}", day.year, day.day));
+                continue;
+            }
+        };
+
+        let Ok(measurements) = measurements else {
+            failed.push(format!("{} day {}", day.year, day.day));
+            continue;
+        };
+
+        for measurement in measurements {
+            let change = match bench::previous(&history, &measurement) {
+                Some(previous) => {
+                    let (ratio, regression) =
+                        bench::change(&measurement, previous, options.threshold);
+                    regressions += regression as usize;
+                    let flag = if regression { "  REGRESSION" } else { "" };
+                    format!("{:+.1}%{}", ratio * 100.0, flag)
+                }
+                None => "new".to_string(),
+            };
+            println!(
+                "{:<4} {:>3}  {:<6}  {:>9}  {:>9}  {:>7}  {}",
+                day.year,
+                day.day,
+                measurement.phase,
+                bench::format_duration(measurement.median),
+                bench::format_duration(measurement.min),
+                measurement.samples,
+                change
+            );
+            run.measurements.push(measurement);
+        }
+    }
+
+    let plural = if regressions == 1 { "" } else { "s" };
+    println!("\n{} regression{}", regressions, plural);
+    if !failed.is_empty() {
+        eprintln!("Failed: {}", failed.join(", "));
+    }
+
+    if let Some(path) = path.filter(|_| options.save && !run.measurements.is_empty()) {
+        history.push(run);
+        if let Err(error) = bench::save_history(&path, &history) {
+            eprintln!("Cannot write {}: {}", path.display(), error);
+            return false;
+        }
+        println!("Saved results in {}", path.display());
+    }
+    failed.is_empty() && regressions == 0
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let command = match parse_command(&args) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("error: {}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::List(year) => list(year),
+        Command::Run {
+            selection,
+            part,
+            source,
+            args,
+        } => {
+            if !run(selection, part, &source, &args) {
+                process::exit(1);
+            }
+        }
+        Command::Verify {
+            selection,
+            part,
+            record,
+        } => {
+            if !verify(selection, part, record) {
+                process::exit(1);
+            }
+        }
+        Command::Bench {
+            selection,
+            part,
+            options,
+        } => {
+            if !bench(selection, part, &options) {
+                process::exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(
+            parse_command(&words("run 2021 14 --part 2 -- --live")),
+            Ok(Command::Run {
+                selection: Selection::Day(2021, 14),
+                part: Part::Two,
+                source: InputSource::Puzzle,
+                args: words("--live"),
+            })
+        );
+        assert_eq!(
+            parse_command(&words("run all")),
+            Ok(Command::Run {
+                selection: Selection::All,
+                part: Part::Both,
+                source: InputSource::Puzzle,
+                args: vec![],
+            })
+        );
+        assert_eq!(
+            parse_command(&words("run 2019 5 --example 2")),
+            Ok(Command::Run {
+                selection: Selection::Day(2019, 5),
+                part: Part::Both,
+                source: InputSource::Example("2".to_string()),
+                args: vec![],
+            })
+        );
+        assert_eq!(
+            parse_command(&words("verify 2020 --record")),
+            Ok(Command::Verify {
+                selection: Selection::Year(2020),
+                part: Part::Both,
+                record: true,
+            })
+        );
+        assert_eq!(
+            parse_command(&words("bench 2020 15 --samples 3 --threshold 25 --no-save")),
+            Ok(Command::Bench {
+                selection: Selection::Day(2020, 15),
+                part: Part::Both,
+                options: bench::Options {
+                    samples: 3,
+                    threshold: 25,
+                    save: false,
+                    ..Default::default()
+                },
+            })
+        );
+        assert!(parse_command(&words("bench all --warmup")).is_err());
+        assert_eq!(
+            parse_command(&words("list 2019")),
+            Ok(Command::List(Some(2019)))
+        );
+        assert!(parse_command(&words("run 2019 --input -")).is_err());
+        assert!(parse_command(&words("run 2021 14 --part 3")).is_err());
+        assert!(parse_command(&words("run twenty")).is_err());
+        assert!(parse_command(&words("fly")).is_err());
+    }
+
+    #[test]
+    fn test_days() {
+        let mut keys = DAYS
+            .iter()
+            .map(|day| (day.year, day.day))
+            .collect::<Vec<_>>();
+        keys.dedup();
+        assert_eq!(keys.len(), DAYS.len());
+        assert!(DAYS.iter().all(|day| Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join(day.path)
+            .join("Cargo.toml")
+            .exists()));
+    }
+}